@@ -2,7 +2,7 @@
 
 use holdem_core::{
     canonize::{self, CanonicalHand},
-    card::{self, Card, Suit},
+    card::{self, Card},
     draws::{self, DrawType},
     equity::{self, PlayerHand, RangeEquityRequest, RangePlayer},
     evaluator,
@@ -28,10 +28,22 @@ pub struct EquityRequestInput {
     pub players: Vec<PlayerInput>,
     #[serde(default)]
     pub board: Vec<String>,
+    /// Structured alternative to `board`: the three flop cards
+    #[serde(default)]
+    pub flop: Option<[String; 3]>,
+    /// Structured alternative to `board`: the turn card
+    #[serde(default)]
+    pub turn: Option<String>,
+    /// Structured alternative to `board`: the river card
+    #[serde(default)]
+    pub river: Option<String>,
     #[serde(default)]
     pub dead_cards: Vec<String>,
     #[serde(default = "default_simulations")]
     pub num_simulations: u32,
+    /// Round equity percentages in the response to this many decimals
+    #[serde(default)]
+    pub precision: Option<u32>,
 }
 
 fn default_simulations() -> u32 {
@@ -39,14 +51,25 @@ fn default_simulations() -> u32 {
 }
 
 /// Equity result for frontend
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct EquityResultOutput {
     pub players: Vec<PlayerEquityOutput>,
     pub total_simulations: u64,
     pub elapsed_ms: f64,
 }
 
-#[derive(Debug, Serialize)]
+impl EquityResultOutput {
+    /// Copy with every player's rates rounded to `decimals` places
+    #[must_use]
+    pub fn round_to(&self, decimals: u32) -> Self {
+        Self {
+            players: self.players.iter().map(|p| p.round_to(decimals)).collect(),
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct PlayerEquityOutput {
     pub index: usize,
     pub hand_description: String,
@@ -56,6 +79,19 @@ pub struct PlayerEquityOutput {
     pub combos: usize,
 }
 
+impl PlayerEquityOutput {
+    /// Copy with `equity`, `win_rate` and `tie_rate` rounded to `decimals` places
+    #[must_use]
+    pub fn round_to(&self, decimals: u32) -> Self {
+        Self {
+            equity: equity::round_decimals(self.equity, decimals),
+            win_rate: equity::round_decimals(self.win_rate, decimals),
+            tie_rate: equity::round_decimals(self.tie_rate, decimals),
+            ..self.clone()
+        }
+    }
+}
+
 /// Parse card strings to Card objects
 fn parse_card_strings(strings: &[String]) -> Result<Vec<Card>, String> {
     strings
@@ -64,11 +100,36 @@ fn parse_card_strings(strings: &[String]) -> Result<Vec<Card>, String> {
         .collect()
 }
 
+/// Resolve the board from either the flat `board` list or the
+/// `flop`/`turn`/`river` fields (not both)
+fn resolve_board(request: &EquityRequestInput) -> Result<Vec<Card>, String> {
+    let flop = match &request.flop {
+        Some(flop) => {
+            let parsed = parse_card_strings(flop)?;
+            Some([parsed[0], parsed[1], parsed[2]])
+        }
+        None => None,
+    };
+    let parse_one = |s: &Option<String>| -> Result<Option<Card>, String> {
+        s.as_deref()
+            .map(|c| Card::parse(c).map_err(|e| e.to_string()))
+            .transpose()
+    };
+
+    equity::resolve_board(
+        &parse_card_strings(&request.board)?,
+        flop,
+        parse_one(&request.turn)?,
+        parse_one(&request.river)?,
+    )
+    .map_err(|e| e.to_string())
+}
+
 /// Calculate equity for multiple players
 #[tauri::command]
 pub fn calculate_equity(request: EquityRequestInput) -> Result<EquityResultOutput, String> {
-    // Parse board
-    let board = parse_card_strings(&request.board)?;
+    // Parse board (flat list or flop/turn/river)
+    let board = resolve_board(&request)?;
 
     // Parse dead cards
     let dead_cards = parse_card_strings(&request.dead_cards)?;
@@ -76,13 +137,18 @@ pub fn calculate_equity(request: EquityRequestInput) -> Result<EquityResultOutpu
     // Check if any player has a range
     let has_range_player = request.players.iter().any(|p| p.range.is_some());
 
-    if has_range_player {
+    let result = if has_range_player {
         // Use range-based calculation with enumeration
-        calculate_equity_with_ranges_impl(&request, board, dead_cards)
+        calculate_equity_with_ranges_impl(&request, board, dead_cards)?
     } else {
         // Use original calculation for specific cards and random only
-        calculate_equity_simple_impl(&request, board, dead_cards)
-    }
+        calculate_equity_simple_impl(&request, board, dead_cards)?
+    };
+
+    Ok(match request.precision {
+        Some(decimals) => result.round_to(decimals),
+        None => result,
+    })
 }
 
 /// Calculate equity using the new range enumeration algorithm
@@ -174,7 +240,6 @@ fn calculate_equity_simple_impl(
 ) -> Result<EquityResultOutput, String> {
     let mut players: Vec<PlayerHand> = Vec::new();
     let mut hand_descriptions: Vec<String> = Vec::new();
-    let mut combo_counts: Vec<usize> = Vec::new();
 
     for (i, player_input) in request.players.iter().enumerate() {
         if let Some(cards) = &player_input.cards {
@@ -188,12 +253,10 @@ fn calculate_equity_simple_impl(
                     ));
                 }
                 hand_descriptions.push(format!("{}{}", parsed[0], parsed[1]));
-                combo_counts.push(1);
                 players.push(PlayerHand::new(parsed));
             }
         } else if player_input.random {
             hand_descriptions.push("Random".to_string());
-            combo_counts.push(1326);
             players.push(PlayerHand::random());
         } else {
             return Err(format!(
@@ -227,7 +290,7 @@ fn calculate_equity_simple_impl(
                 equity: p.equity,
                 win_rate: p.win_rate,
                 tie_rate: p.tie_rate,
-                combos: combo_counts.get(i).copied().unwrap_or(1),
+                combos: p.combos,
             })
             .collect(),
         total_simulations: result.total_simulations,
@@ -270,16 +333,6 @@ pub struct DrawAnalysisOutput {
     pub is_combo_draw: bool,
 }
 
-/// Get suit symbol
-fn suit_symbol(suit: Suit) -> String {
-    match suit {
-        Suit::Hearts => "\u{2665}".to_string(),   // ♥
-        Suit::Diamonds => "\u{2666}".to_string(), // ♦
-        Suit::Clubs => "\u{2663}".to_string(),    // ♣
-        Suit::Spades => "\u{2660}".to_string(),   // ♠
-    }
-}
-
 /// Get draw type string
 fn draw_type_string(dt: DrawType) -> String {
     match dt {
@@ -325,7 +378,7 @@ pub fn analyze_draws(
             .iter()
             .map(|d| FlushDrawOutput {
                 suit: d.suit.to_char().to_string(),
-                suit_symbol: suit_symbol(d.suit),
+                suit_symbol: d.suit.to_symbol().to_string(),
                 cards_held: d.cards_held,
                 outs: d.outs.iter().map(ToString::to_string).collect(),
                 out_count: d.out_count(),
@@ -444,7 +497,7 @@ impl From<Card> for CardInfoOutput {
             notation: card.to_string(),
             rank: card.rank.to_char().to_string(),
             suit: card.suit.to_char().to_string(),
-            suit_symbol: suit_symbol(card.suit),
+            suit_symbol: card.suit.to_symbol().to_string(),
         }
     }
 }
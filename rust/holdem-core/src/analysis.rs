@@ -0,0 +1,371 @@
+//! Higher-level hand analysis built on top of equity calculation.
+//!
+//! These helpers answer "how does this hand do across many situations"
+//! questions (e.g. equity on every flop) by repeatedly calling the equity
+//! engine.
+
+use crate::canonize::{relabeled_indices, suit_permutations, DistinctFlopIter};
+use crate::card::{rotate_suits, Card, Suit, FULL_DECK};
+use crate::equity::{
+    calculate_equity, calculate_equity_with_ranges, EquityRequest, PlayerHand,
+    RangeEquityRequest, RangePlayer,
+};
+use crate::error::{HoldemError, HoldemResult};
+use crate::evaluator::{evaluate_hand, validate_postflop_board};
+use crate::range::{normalize_combo, CardDistribution};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+/// Suit permutations (as from `suit_permutations`) that leave the matchup
+/// unchanged.
+///
+/// Hero's cards and a specific villain's cards must each map onto
+/// themselves, so swaps that trade the two hands (`AhKs` vs `AsKh` under h↔s)
+/// don't count. For a range villain, the range's combo set must map onto
+/// itself.
+fn matchup_symmetries(hero: &[Card], villain: &RangePlayer) -> Vec<Vec<u8>> {
+    let fixes = |cards: &[Card], suits: &[u8]| {
+        relabeled_indices(cards, suits) == relabeled_indices(cards, &[0, 1, 2, 3])
+    };
+    let villain_combos: Option<HashSet<(Card, Card)>> = match villain {
+        RangePlayer::Range(dist) => {
+            Some(dist.hands().iter().copied().map(normalize_combo).collect())
+        }
+        RangePlayer::Specific(..) | RangePlayer::Random => None,
+    };
+
+    suit_permutations()
+        .filter(|suits| {
+            let mapping: [Suit; 4] = std::array::from_fn(|s| Suit::ALL[usize::from(suits[s])]);
+            fixes(hero, suits)
+                && match villain {
+                    RangePlayer::Specific(c1, c2) => fixes(&[*c1, *c2], suits),
+                    RangePlayer::Random | RangePlayer::Range(_) => true,
+                }
+                && villain_combos.as_ref().is_none_or(|combos| {
+                    combos.iter().all(|&(c1, c2)| {
+                        let rotated = rotate_suits(&[c1, c2], mapping);
+                        combos.contains(&normalize_combo((rotated[0], rotated[1])))
+                    })
+                })
+        })
+        .collect()
+}
+
+/// Group all flops avoiding `fixed` into classes that are equivalent under
+/// the given suit symmetries.
+///
+/// Splits each fully suit-isomorphic flop class (`DistinctFlopIter`) into
+/// the finer classes of the matchup. Returns one representative per class
+/// (the first flop of the class in deck order) with the number of real
+/// flops it stands for.
+fn flop_classes(fixed: &[Card], symmetries: &[Vec<u8>]) -> Vec<([Card; 3], usize)> {
+    // Canonical key -> (representative, weight), ordered by key for stable output
+    let mut classes: BTreeMap<Vec<u8>, ([Card; 3], usize)> = BTreeMap::new();
+
+    for distinct in DistinctFlopIter::new() {
+        let orbit: BTreeSet<Vec<u8>> =
+            suit_permutations().map(|suits| relabeled_indices(&distinct, &suits)).collect();
+        for indices in orbit {
+            let flop = [0, 1, 2].map(|i| FULL_DECK[usize::from(indices[i])]);
+            if flop.iter().any(|c| fixed.contains(c)) {
+                continue;
+            }
+            let key = symmetries
+                .iter()
+                .map(|suits| relabeled_indices(&flop, suits))
+                .min()
+                .unwrap_or(indices);
+            classes.entry(key).or_insert((flop, 0)).1 += 1;
+        }
+    }
+
+    classes.into_values().collect()
+}
+
+/// Compute hero's equity on every strategically distinct flop.
+///
+/// Flops that are suit-isomorphic for this matchup are merged into a single
+/// representative, and each entry carries the number of real flops it stands
+/// for. The weighted average of the returned equities is hero's preflop
+/// equity (up to sampling noise).
+///
+/// The villain can be a specific hand, a random hand, or a range. Specific
+/// and random villains use `calculate_equity` with `sims_per_flop`
+/// simulations per flop; ranges use `calculate_equity_with_ranges`. Flops
+/// that leave a range villain without a live combo are left out, and a
+/// range with one live combo is played as that specific hand.
+///
+/// # Errors
+/// Returns an error if hero's cards are duplicated or conflict with a
+/// specific villain, or if the equity engine rejects a flop.
+pub fn hand_across_flops(
+    hero: [Card; 2],
+    villain: &RangePlayer,
+    sims_per_flop: u32,
+    seed: Option<u64>,
+) -> HoldemResult<Vec<([Card; 3], f64, usize)>> {
+    if hero[0] == hero[1] {
+        return Err(HoldemError::DuplicateCard(hero[0].to_string()));
+    }
+
+    let mut fixed: Vec<Card> = hero.to_vec();
+    if let RangePlayer::Specific(c1, c2) = villain {
+        for card in [*c1, *c2] {
+            if fixed.contains(&card) {
+                return Err(HoldemError::DuplicateCard(card.to_string()));
+            }
+            fixed.push(card);
+        }
+    }
+
+    let symmetries = matchup_symmetries(&hero, villain);
+    let classes = flop_classes(&fixed, &symmetries);
+
+    let mut results = Vec::with_capacity(classes.len());
+    for (i, (flop, weight)) in classes.into_iter().enumerate() {
+        let flop_seed = seed.map(|s| s.wrapping_add(i as u64));
+
+        let heads_up = |villain: PlayerHand| -> HoldemResult<f64> {
+            let mut request =
+                EquityRequest::new(vec![PlayerHand::new(hero.to_vec()), villain], flop.to_vec())
+                    .with_simulations(sims_per_flop);
+            request.seed = flop_seed;
+            Ok(calculate_equity(&request)?.players[0].equity)
+        };
+
+        let equity = match villain {
+            RangePlayer::Range(dist) => {
+                // The flop can block every combo of a narrow range, and a
+                // range down to one live combo is played as that hand
+                let live: Vec<(Card, Card)> = dist
+                    .hands()
+                    .iter()
+                    .copied()
+                    .filter(|&(c1, c2)| {
+                        ![c1, c2].iter().any(|c| flop.contains(c) || hero.contains(c))
+                    })
+                    .collect();
+                match live.as_slice() {
+                    [] => continue,
+                    &[(c1, c2)] => heads_up(PlayerHand::new(vec![c1, c2]))?,
+                    _ => {
+                        let mut request = RangeEquityRequest::new(
+                            vec![RangePlayer::specific(hero[0], hero[1]), villain.clone()],
+                            flop.to_vec(),
+                        )
+                        .with_simulations(sims_per_flop);
+                        request.seed = flop_seed;
+                        calculate_equity_with_ranges(&request)?.players[0].equity
+                    }
+                }
+            }
+            RangePlayer::Specific(c1, c2) => heads_up(PlayerHand::new(vec![*c1, *c2]))?,
+            RangePlayer::Random => heads_up(PlayerHand::random())?,
+        };
+
+        results.push((flop, equity, weight));
+    }
+
+    Ok(results)
+}
+
+/// Probability that hero's hand is best right now, before any runout.
+///
+/// Compares hero's current made hand against every villain combo that does
+/// not conflict with hero or the board. Wins count 1, ties count half, and
+/// each combo is weighted by its range weight. This is the "way ahead / way
+/// behind" metric, as opposed to equity which also accounts for the runout.
+///
+/// # Errors
+/// Returns an error if the board is not 3-5 cards, if hero and the board
+/// share a card, or if every villain combo conflicts with known cards.
+pub fn prob_ahead_now(
+    hero: [Card; 2],
+    board: &[Card],
+    villain_range: &CardDistribution,
+) -> HoldemResult<f64> {
+    validate_postflop_board(board)?;
+
+    let mut known: HashSet<Card> = HashSet::new();
+    for &card in hero.iter().chain(board) {
+        if !known.insert(card) {
+            return Err(HoldemError::DuplicateCard(card.to_string()));
+        }
+    }
+
+    let mut hero_cards = hero.to_vec();
+    hero_cards.extend_from_slice(board);
+    let hero_rank = evaluate_hand(&hero_cards)?;
+
+    let mut ahead = 0.0;
+    let mut total_weight = 0.0;
+    for (i, &(c1, c2)) in villain_range.hands().iter().enumerate() {
+        if known.contains(&c1) || known.contains(&c2) {
+            continue;
+        }
+        let mut villain_cards = vec![c1, c2];
+        villain_cards.extend_from_slice(board);
+        let villain_rank = evaluate_hand(&villain_cards)?;
+
+        let weight = villain_range.weight(i);
+        total_weight += weight;
+        match hero_rank.cmp(&villain_rank) {
+            std::cmp::Ordering::Greater => ahead += weight,
+            std::cmp::Ordering::Equal => ahead += weight / 2.0,
+            std::cmp::Ordering::Less => {}
+        }
+    }
+
+    if total_weight <= 0.0 {
+        return Err(HoldemError::NoValidCombinations);
+    }
+    Ok(ahead / total_weight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::parse_cards;
+
+    fn cards(s: &str) -> Vec<Card> {
+        parse_cards(s).unwrap()
+    }
+
+    #[test]
+    fn test_flop_classes_cover_all_flops() {
+        // Suited hero hand: the three other suits are interchangeable
+        let fixed = cards("Ah Kh");
+        let symmetries = matchup_symmetries(&fixed, &RangePlayer::random());
+        assert_eq!(symmetries.len(), 6);
+
+        let classes = flop_classes(&fixed, &symmetries);
+        let total: usize = classes.iter().map(|(_, w)| w).sum();
+        assert_eq!(total, 19_600); // C(50, 3)
+        assert!(classes.len() < 19_600 / 4);
+
+        // No symmetry at all: every flop is its own class
+        let identity = vec![vec![0, 1, 2, 3]];
+        assert_eq!(flop_classes(&fixed, &identity).len(), 19_600);
+    }
+
+    #[test]
+    fn test_symmetries_keep_each_hand_separately() {
+        // h<->s maps {Ah, Ks, As, Kh} onto itself but trades the two hands,
+        // so only clubs and diamonds may be swapped
+        let hero = cards("Ah Ks");
+        let villain = RangePlayer::specific(Card::parse("As").unwrap(), Card::parse("Kh").unwrap());
+        let symmetries = matchup_symmetries(&hero, &villain);
+        assert_eq!(symmetries, vec![vec![0, 1, 2, 3], vec![1, 0, 2, 3]]);
+
+        let fixed = cards("Ah Ks As Kh");
+        let classes = flop_classes(&fixed, &symmetries);
+        assert_eq!(classes.iter().map(|(_, w)| w).sum::<usize>(), 17_296); // C(48, 3)
+        // A heart flop is not the same situation as a spade flop
+        let hearts = cards("2h 7h Qh");
+        let spades = cards("2s 7s Qs");
+        assert!(classes.iter().any(|(f, _)| f[..] == hearts[..]));
+        assert!(classes.iter().any(|(f, _)| f[..] == spades[..]));
+    }
+
+    #[test]
+    fn test_range_symmetry_respects_range() {
+        let hero = cards("Ah Kh");
+        // A full QQ range is suit-symmetric, so clubs/diamonds/spades stay
+        // interchangeable
+        let range = CardDistribution::from_range(&["QQ".to_string()], &[]).unwrap();
+        let symmetries = matchup_symmetries(&hero, &RangePlayer::range(range));
+        assert_eq!(symmetries.len(), 6);
+
+        let one_combo = CardDistribution::from_hand(
+            Card::parse("Qc").unwrap(),
+            Card::parse("Qd").unwrap(),
+        );
+        let symmetries = matchup_symmetries(&hero, &RangePlayer::range(one_combo));
+        // A single QcQd combo only allows swapping clubs and diamonds
+        assert_eq!(symmetries.len(), 2);
+    }
+
+    #[test]
+    fn test_hand_across_flops_weighted_average() {
+        let hero: [Card; 2] = cards("Ah Kh").try_into().unwrap();
+        let results = hand_across_flops(hero, &RangePlayer::random(), 20, Some(7)).unwrap();
+
+        let total_weight: usize = results.iter().map(|(_, _, w)| w).sum();
+        assert_eq!(total_weight, 19_600);
+
+        #[allow(clippy::cast_precision_loss)]
+        let average = results.iter().map(|(_, e, w)| e * *w as f64).sum::<f64>()
+            / total_weight as f64;
+
+        // AKs vs a random hand is ~67% preflop
+        assert!(average > 0.63 && average < 0.71, "weighted average {average}");
+    }
+
+    #[test]
+    fn test_hand_across_flops_one_combo_range() {
+        let hero: [Card; 2] = cards("Ah Kh").try_into().unwrap();
+        let queens =
+            CardDistribution::from_hand(Card::parse("Qc").unwrap(), Card::parse("Qd").unwrap());
+        let results = hand_across_flops(hero, &RangePlayer::range(queens), 1, Some(3)).unwrap();
+
+        // Flops holding the Qc or Qd are skipped, leaving C(48, 3)
+        let total_weight: usize = results.iter().map(|(_, _, w)| w).sum();
+        assert_eq!(total_weight, 17_296);
+        assert!(results.iter().all(|(flop, _, _)| {
+            flop.iter().all(|c| c.to_string() != "Qc" && c.to_string() != "Qd")
+        }));
+    }
+
+    #[test]
+    fn test_hand_across_flops_conflicting_villain() {
+        let hero: [Card; 2] = cards("Ah Kh").try_into().unwrap();
+        let villain = RangePlayer::specific(Card::parse("Ah").unwrap(), Card::parse("2c").unwrap());
+        assert!(matches!(
+            hand_across_flops(hero, &villain, 10, Some(1)),
+            Err(HoldemError::DuplicateCard(_))
+        ));
+    }
+
+    #[test]
+    fn test_prob_ahead_now_top_pair() {
+        let hero: [Card; 2] = cards("Ah Qc").try_into().unwrap();
+        let board = cards("Qs 8d 3c");
+        // 88 and 33 are sets (3 combos each); JJ/TT/99 and QJs are behind
+        let range = CardDistribution::from_range(
+            &["88", "33", "JJ", "TT", "99", "QJs"].map(String::from),
+            &[],
+        )
+        .unwrap();
+
+        let ahead = prob_ahead_now(hero, &board, &range).unwrap();
+        // Valid combos: 3 + 3 + 6 + 6 + 6 + 2 (QJs minus Qs, Qc) = 26, behind 6
+        assert!((ahead - 20.0 / 26.0).abs() < 1e-9, "ahead {ahead}");
+    }
+
+    #[test]
+    fn test_prob_ahead_now_ties_count_half() {
+        let hero: [Card; 2] = cards("Ah Qc").try_into().unwrap();
+        let board = cards("Qs 8d 3c");
+        let range =
+            CardDistribution::from_hand(Card::parse("Ad").unwrap(), Card::parse("Qd").unwrap());
+        assert!((prob_ahead_now(hero, &board, &range).unwrap() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_prob_ahead_now_errors() {
+        let hero: [Card; 2] = cards("Ah Qc").try_into().unwrap();
+        let range = CardDistribution::from_range(&["KK".to_string()], &[]).unwrap();
+        assert!(prob_ahead_now(hero, &cards("Qs 8d"), &range).is_err());
+        assert!(matches!(
+            prob_ahead_now(hero, &cards("Ah 8d 3c"), &range),
+            Err(HoldemError::DuplicateCard(_))
+        ));
+
+        let blocked =
+            CardDistribution::from_hand(Card::parse("Ah").unwrap(), Card::parse("Kd").unwrap());
+        assert!(matches!(
+            prob_ahead_now(hero, &cards("Qs 8d 3c"), &blocked),
+            Err(HoldemError::NoValidCombinations)
+        ));
+    }
+}
@@ -3,9 +3,10 @@
 //! Usage:
 //!   cargo run --release --bin precompute -- --simulations 1000000
 //!   cargo run --release --bin precompute -- --players 2 --simulations 100000
+//!   cargo run --release --bin precompute -- --players 2 --format csv
 
-use holdem_core::canonize::{get_all_canonical_hands, get_all_combos, CanonicalHand};
-use holdem_core::equity::{calculate_equity, EquityRequest, PlayerHand};
+use holdem_core::canonize::{get_all_canonical_hands, CanonicalHand};
+use holdem_core::equity::preflop_hand_equity;
 use std::collections::BTreeMap;
 use std::env;
 use std::fs;
@@ -24,6 +25,7 @@ fn main() {
     let mut simulations: u32 = 1_000_000;
     let mut players: Option<usize> = None;
     let mut output_dir: Option<String> = None;
+    let mut csv = false;
 
     let mut i = 1;
     while i < args.len() {
@@ -46,6 +48,10 @@ fn main() {
                     i += 1;
                 }
             }
+            "--format" | "-f" => {
+                csv = is_csv_format(args.get(i + 1).map_or("", String::as_str));
+                i += 1;
+            }
             "--help" | "-h" => {
                 print_help();
                 return;
@@ -76,7 +82,8 @@ fn main() {
     } else {
         println!("Players: 2-10 (all)");
     }
-    println!("Output: {}/preflop-equity-{{N}}.json", output_dir);
+    let extension = if csv { "csv" } else { "json" };
+    println!("Output: {output_dir}/preflop-equity-{{N}}.{extension}");
     #[cfg(feature = "parallel")]
     println!("Mode: Parallel (using all CPU cores)");
     #[cfg(not(feature = "parallel"))]
@@ -137,9 +144,13 @@ fn main() {
         let subtotal_elapsed = subtotal_start.elapsed();
 
         // Save to file
-        let filename = format!("{}/preflop-equity-{}.json", output_dir, num_players);
-        let json = serde_json::to_string_pretty(&player_results).expect("Failed to serialize JSON");
-        fs::write(&filename, &json).expect("Failed to write output file");
+        let filename = format!("{output_dir}/preflop-equity-{num_players}.{extension}");
+        let contents = if csv {
+            to_csv(&player_results, num_players)
+        } else {
+            serde_json::to_string_pretty(&player_results).expect("Failed to serialize JSON")
+        };
+        fs::write(&filename, &contents).expect("Failed to write output file");
 
         println!(
             "Completed in {} → Saved: {}",
@@ -156,85 +167,23 @@ fn main() {
     println!("========================================");
 }
 
-#[cfg(feature = "parallel")]
 fn compute_hand_equity(hand: &CanonicalHand, num_players: usize, simulations: u32) -> f64 {
-    use rayon::prelude::*;
-
-    // Get all actual card combinations for this hand
-    let combos = get_all_combos(hand);
-
-    if combos.is_empty() {
-        return 0.0;
-    }
-
-    let sims_per_combo = simulations / combos.len() as u32;
-
-    // Parallelize across combos within each hand
-    let total_equity: f64 = combos
-        .par_iter()
-        .map(|(card1, card2)| {
-            // Build player hands: hero + (num_players - 1) random opponents
-            let mut players = vec![PlayerHand::new(vec![*card1, *card2])];
-            for _ in 1..num_players {
-                players.push(PlayerHand::random());
-            }
-
-            let request = EquityRequest::new(players, vec![]).with_simulations(sims_per_combo);
-
-            match calculate_equity(&request) {
-                Ok(result) => {
-                    if !result.players.is_empty() {
-                        result.players[0].equity
-                    } else {
-                        0.0
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Error computing {}: {}", hand.notation(), e);
-                    0.0
-                }
-            }
-        })
-        .sum();
-
-    total_equity / combos.len() as f64
+    // Hero + (num_players - 1) random opponents
+    preflop_hand_equity(hand, num_players - 1, simulations, None).unwrap_or_else(|e| {
+        eprintln!("Error computing {}: {}", hand.notation(), e);
+        0.0
+    })
 }
 
-#[cfg(not(feature = "parallel"))]
-fn compute_hand_equity(hand: &CanonicalHand, num_players: usize, simulations: u32) -> f64 {
-    // Get all actual card combinations for this hand
-    let combos = get_all_combos(hand);
-
-    if combos.is_empty() {
-        return 0.0;
-    }
-
-    // Calculate equity for each combo and average
-    let mut total_equity = 0.0;
-    let sims_per_combo = simulations / combos.len() as u32;
-
-    for (card1, card2) in &combos {
-        // Build player hands: hero + (num_players - 1) random opponents
-        let mut players = vec![PlayerHand::new(vec![*card1, *card2])];
-        for _ in 1..num_players {
-            players.push(PlayerHand::random());
-        }
-
-        let request = EquityRequest::new(players, vec![]).with_simulations(sims_per_combo);
-
-        match calculate_equity(&request) {
-            Ok(result) => {
-                if !result.players.is_empty() {
-                    total_equity += result.players[0].equity;
-                }
-            }
-            Err(e) => {
-                eprintln!("Error computing {}: {}", hand.notation(), e);
-            }
-        }
-    }
-
-    total_equity / combos.len() as f64
+/// `hand,players,equity` rows, equity in percent
+fn to_csv(player_results: &BTreeMap<String, f64>, num_players: usize) -> String {
+    let mut csv = String::from("hand,players,equity\n");
+    csv.extend(
+        player_results
+            .iter()
+            .map(|(hand, equity_pct)| format!("{hand},{num_players},{equity_pct}\n")),
+    );
+    csv
 }
 
 fn format_number(n: u64) -> String {
@@ -259,6 +208,21 @@ fn format_duration(secs: u64) -> String {
     }
 }
 
+/// Whether `--format` selects CSV; anything but json or csv prints the help
+/// and exits
+fn is_csv_format(format: &str) -> bool {
+    if format.eq_ignore_ascii_case("csv") {
+        return true;
+    }
+    if format.eq_ignore_ascii_case("json") {
+        return false;
+    }
+    eprintln!("--format must be json or csv, got '{format}'");
+    eprintln!();
+    print_help();
+    std::process::exit(2);
+}
+
 fn print_help() {
     println!("Preflop Equity Precompute");
     println!();
@@ -269,9 +233,10 @@ fn print_help() {
     println!("  -s, --simulations N    Simulations per hand (default: 1,000,000)");
     println!("  -p, --players N        Only compute for N players (default: 2-10 all)");
     println!("  -o, --output DIR       Output directory (default: {})", DEFAULT_OUTPUT_DIR);
+    println!("  -f, --format FORMAT    json or csv (default: json)");
     println!("  -h, --help             Show this help");
     println!();
-    println!("Output files: preflop-equity-{{N}}.json (one per player count)");
+    println!("Output files: preflop-equity-{{N}}.json or .csv (one per player count)");
     println!();
     println!("Examples:");
     println!("  # Quick test (100k sims, 2 players only)");
@@ -282,4 +247,7 @@ fn print_help() {
     println!();
     println!("  # Custom output directory");
     println!("  cargo run --release --bin precompute -- -o ./output");
+    println!();
+    println!("  # CSV for spreadsheets (hand,players,equity rows)");
+    println!("  cargo run --release --bin precompute -- -p 2 -f csv");
 }
@@ -6,11 +6,15 @@
 //! - 78 suited hands (AKs, AQs, ..., 32s)
 //! - 78 offsuit hands (AKo, AQo, ..., 32o)
 
-use crate::card::{Card, Rank, Suit};
+use crate::card::{Card, Rank, Suit, FULL_DECK};
+use crate::preflop_data::{MAX_PLAYERS, MIN_PLAYERS, PREFLOP_EQUITY};
+use crate::range::CardDistribution;
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::str::FromStr;
+use std::sync::OnceLock;
 use thiserror::Error;
 
 /// A canonical (strategically equivalent) starting hand.
@@ -82,6 +86,58 @@ impl CanonicalHand {
         self.high_rank.value() - self.low_rank.value()
     }
 
+    /// Bill Chen's preflop score (e.g. AA = 20, AKs = 12, 72o = -1)
+    ///
+    /// High card points, doubled for pairs (minimum 5), +2 if suited, a
+    /// penalty for the gap between the cards, and +1 for connected or
+    /// one-gapped hands below a queen. Half points are rounded up.
+    #[must_use]
+    pub fn chen_score(&self) -> f64 {
+        let high_points = match self.high_rank {
+            Rank::Ace => 10.0,
+            Rank::King => 8.0,
+            Rank::Queen => 7.0,
+            Rank::Jack => 6.0,
+            rank => f64::from(rank.value()) / 2.0,
+        };
+
+        if self.is_pair() {
+            return (high_points * 2.0).max(5.0).ceil();
+        }
+
+        let mut score = high_points;
+        if self.suited {
+            score += 2.0;
+        }
+
+        // Number of ranks strictly between the two cards
+        let between = self.gap() - 1;
+        score -= match between {
+            0 => 0.0,
+            1 => 1.0,
+            2 => 2.0,
+            3 => 4.0,
+            _ => 5.0,
+        };
+        if between <= 1 && self.high_rank < Rank::Queen {
+            score += 1.0;
+        }
+
+        score.ceil()
+    }
+
+    /// Sklansky-Malmuth hand group (1 = strongest, 8 = weakest playable,
+    /// 9 = everything else)
+    #[must_use]
+    pub fn sklansky_group(&self) -> u8 {
+        let notation = self.notation();
+        SKLANSKY_GROUPS
+            .iter()
+            .zip(1..)
+            .find(|(hands, _)| hands.contains(&notation.as_str()))
+            .map_or(9, |(_, group)| group)
+    }
+
     /// Get notation string (e.g., "AKs", "QQ", "72o")
     #[must_use]
     pub fn notation(&self) -> String {
@@ -182,6 +238,27 @@ impl FromStr for CanonicalHand {
     }
 }
 
+/// Sklansky-Malmuth starting hand groups 1-8
+const SKLANSKY_GROUPS: [&[&str]; 8] = [
+    &["AA", "KK", "QQ", "JJ", "AKs"],
+    &["TT", "AQs", "AJs", "KQs", "AKo"],
+    &["99", "JTs", "QJs", "KJs", "ATs", "AQo"],
+    &["T9s", "KQo", "88", "QTs", "98s", "J9s", "AJo", "KTs"],
+    &[
+        "77", "87s", "Q9s", "T8s", "KJo", "QJo", "JTo", "76s", "97s", "A9s", "A8s", "A7s", "A6s",
+        "A5s", "A4s", "A3s", "A2s", "65s",
+    ],
+    &["66", "ATo", "55", "86s", "KTo", "QTo", "54s", "K9s", "J8s", "75s"],
+    &[
+        "44", "J9o", "64s", "T9o", "53s", "33", "98o", "43s", "22", "K8s", "K7s", "K6s", "K5s",
+        "K4s", "K3s", "K2s", "T7s", "Q8s",
+    ],
+    &[
+        "87o", "A9o", "Q9o", "76o", "42s", "32s", "96s", "85s", "J8o", "J7s", "65o", "54o", "74s",
+        "K9o", "T8o",
+    ],
+];
+
 /// Error when parsing or creating canonical hands
 #[derive(Debug, Clone, Error, PartialEq, Eq)]
 pub enum CanonizeError {
@@ -197,6 +274,8 @@ pub enum CanonizeError {
     PairCannotBeSuited,
     #[error("invalid hole cards count")]
     InvalidCardCount,
+    #[error("invalid preflop table: {0}")]
+    InvalidTable(String),
 }
 
 /// Convert two hole cards to their canonical form
@@ -268,6 +347,40 @@ pub fn get_combos_excluding(hand: &CanonicalHand, dead_cards: &[Card]) -> Vec<(C
         .collect()
 }
 
+/// Number of combos of `hand` that avoid the dead cards
+///
+/// Same as `get_combos_excluding(hand, dead).len()` without building the
+/// combos, for blocker counting ("how many AK are left with an ace out?").
+#[must_use]
+pub fn remaining_combos(hand: &CanonicalHand, dead: &[Card]) -> usize {
+    let live = |rank: Rank, suit: Suit| !dead.contains(&Card::new(rank, suit));
+    let high = Suit::ALL.iter().filter(|&&s| live(hand.high_rank, s)).count();
+
+    if hand.is_pair() {
+        return high * high.saturating_sub(1) / 2;
+    }
+    let suited = Suit::ALL
+        .iter()
+        .filter(|&&s| live(hand.high_rank, s) && live(hand.low_rank, s))
+        .count();
+    if hand.suited {
+        suited
+    } else {
+        let low = Suit::ALL.iter().filter(|&&s| live(hand.low_rank, s)).count();
+        high * low - suited
+    }
+}
+
+/// Count a distribution's combos per canonical hand
+#[must_use]
+pub fn combo_breakdown(range: &CardDistribution) -> HashMap<CanonicalHand, usize> {
+    let mut counts = HashMap::new();
+    for &(c1, c2) in range.hands() {
+        *counts.entry(canonize_hole_cards(&[c1, c2])).or_insert(0) += 1;
+    }
+    counts
+}
+
 /// Get all 169 canonical starting hands
 #[must_use]
 pub fn get_all_canonical_hands() -> Vec<CanonicalHand> {
@@ -298,12 +411,209 @@ pub fn get_all_canonical_hands() -> Vec<CanonicalHand> {
     hands
 }
 
+/// All 169 canonical hands, strongest first, by precomputed preflop equity
+///
+/// Uses the equity table compiled into the library for `num_players`
+/// (clamped to 2-10). Hands with equal equity keep
+/// `get_all_canonical_hands` order. Each ordering is built once, on first
+/// use.
+#[must_use]
+pub fn hand_strength_order(num_players: usize) -> &'static [CanonicalHand] {
+    static ORDERS: OnceLock<Vec<Vec<CanonicalHand>>> = OnceLock::new();
+    let orders = ORDERS.get_or_init(|| {
+        let hands = get_all_canonical_hands();
+        PREFLOP_EQUITY
+            .iter()
+            .map(|equities| {
+                let mut ranked: Vec<(CanonicalHand, f64)> =
+                    hands.iter().copied().zip(equities.iter().copied()).collect();
+                ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+                ranked.into_iter().map(|(hand, _)| hand).collect()
+            })
+            .collect()
+    });
+    &orders[num_players.clamp(MIN_PLAYERS, MAX_PLAYERS) - MIN_PLAYERS]
+}
+
 /// Check if two specific hole cards are strategically equivalent
 #[must_use]
 pub fn are_strategically_equivalent(hand1: &[Card; 2], hand2: &[Card; 2]) -> bool {
     canonize_hole_cards(hand1) == canonize_hole_cards(hand2)
 }
 
+/// Every relabeling of the four suits, as the new suit value for each old one
+pub(crate) fn suit_permutations() -> impl Iterator<Item = Vec<u8>> {
+    (0..4u8).permutations(4)
+}
+
+/// Card indices (`Card::to_index`) after relabeling suits with `suits`, ascending
+pub(crate) fn relabeled_indices(cards: &[Card], suits: &[u8]) -> Vec<u8> {
+    let mut indices: Vec<u8> = cards
+        .iter()
+        .map(|c| c.to_index() - c.suit as u8 + suits[c.suit as usize])
+        .collect();
+    indices.sort_unstable();
+    indices
+}
+
+/// Suit-independent key for hole cards on a board (see `canonicalize_situation`)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CanonKey {
+    /// Relabeled hole card indices (`Card::to_index`), ascending
+    pub hole: [u8; 2],
+    /// Relabeled board card indices, ascending
+    pub board: Vec<u8>,
+}
+
+/// Relabel suits so that suit-equivalent situations share one key
+///
+/// `AhKh` on `Qh Jh Th` and `AsKs` on `Qs Js Ts` give the same key, while
+/// `AhKs` on the same board does not. The order of the hole cards and of
+/// the board cards is ignored.
+#[must_use]
+pub fn canonicalize_situation(hole: (Card, Card), board: &[Card]) -> CanonKey {
+    suit_permutations()
+        .map(|suits| {
+            let hole = relabeled_indices(&[hole.0, hole.1], &suits);
+            CanonKey {
+                hole: [hole[0], hole[1]],
+                board: relabeled_indices(board, &suits),
+            }
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+/// One flop from each suit-isomorphism class (1,755 in total)
+///
+/// The representative of each class is its first flop in `FULL_DECK`
+/// combination order. Use `DistinctFlopIter` to avoid building the `Vec`.
+#[must_use]
+pub fn distinct_flops() -> Vec<[Card; 3]> {
+    DistinctFlopIter::new().collect()
+}
+
+/// Lazily yields the same flops as `distinct_flops`, in the same order
+///
+/// Walks every flop in `FULL_DECK` combination order and keeps those that
+/// are the smallest member of their suit-isomorphism class, so nothing but
+/// the current position is stored.
+#[derive(Clone, Debug)]
+pub struct DistinctFlopIter {
+    next: Option<[u8; 3]>,
+}
+
+impl DistinctFlopIter {
+    /// Start at the first flop
+    #[must_use]
+    pub fn new() -> Self {
+        Self { next: Some([0, 1, 2]) }
+    }
+
+    /// Pair each flop with how many real flops it stands for
+    ///
+    /// The weights sum to C(52,3) = 22,100, so a weighted average over these
+    /// flops equals the plain average over every flop.
+    pub fn weighted(self) -> impl Iterator<Item = ([Card; 3], usize)> {
+        self.map(|flop| {
+            let mut class: Vec<Vec<u8>> =
+                suit_permutations().map(|suits| relabeled_indices(&flop, &suits)).collect();
+            class.sort_unstable();
+            class.dedup();
+            (flop, class.len())
+        })
+    }
+}
+
+impl Default for DistinctFlopIter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for DistinctFlopIter {
+    type Item = [Card; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let [a, b, c] = self.next?;
+            self.next = if c < 51 {
+                Some([a, b, c + 1])
+            } else if b < 50 {
+                Some([a, b + 1, b + 2])
+            } else if a < 49 {
+                Some([a + 1, a + 2, a + 3])
+            } else {
+                None
+            };
+
+            let flop = [a, b, c].map(|i| FULL_DECK[usize::from(i)]);
+            let smallest = suit_permutations()
+                .map(|suits| relabeled_indices(&flop, &suits))
+                .min();
+            if smallest.as_deref() == Some(&[a, b, c][..]) {
+                return Some(flop);
+            }
+        }
+    }
+}
+
+/// Preflop equity per canonical hand and player count
+///
+/// Holds `precompute` output: equities of each hand against `N - 1` random
+/// opponents, in the units of the source (percent for the precompute JSON
+/// and `builtin`).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PreflopTable {
+    equities: HashMap<usize, HashMap<CanonicalHand, f64>>,
+}
+
+impl PreflopTable {
+    /// Parse a `{players -> {hand -> equity}}` JSON object
+    ///
+    /// # Errors
+    /// Returns an error if the JSON is malformed, a player count is not a
+    /// number, or a hand is not valid notation.
+    pub fn from_json(json: &str) -> Result<Self, CanonizeError> {
+        let raw: HashMap<String, HashMap<String, f64>> =
+            serde_json::from_str(json).map_err(|e| CanonizeError::InvalidTable(e.to_string()))?;
+
+        let mut equities = HashMap::with_capacity(raw.len());
+        for (players, hands) in raw {
+            let num_players: usize = players
+                .parse()
+                .map_err(|_| CanonizeError::InvalidTable(format!("player count {players}")))?;
+            let hands = hands
+                .into_iter()
+                .map(|(hand, equity)| Ok((CanonicalHand::parse(&hand)?, equity)))
+                .collect::<Result<_, CanonizeError>>()?;
+            equities.insert(num_players, hands);
+        }
+        Ok(Self { equities })
+    }
+
+    /// The table compiled into the library (2-10 players, percent)
+    #[must_use]
+    pub fn builtin() -> Self {
+        let hands = get_all_canonical_hands();
+        let equities = PREFLOP_EQUITY
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let by_hand = hands.iter().copied().zip(row.iter().copied()).collect();
+                (i + MIN_PLAYERS, by_hand)
+            })
+            .collect();
+        Self { equities }
+    }
+
+    /// Equity of `hand` at a table of `num_players`, if the table has it
+    #[must_use]
+    pub fn lookup(&self, hand: &CanonicalHand, num_players: usize) -> Option<f64> {
+        self.equities.get(&num_players)?.get(hand).copied()
+    }
+}
+
 /// Extended info for UI display
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CanonicalHandInfo {
@@ -335,6 +645,45 @@ mod tests {
     use super::*;
     use crate::card::parse_cards;
 
+    #[test]
+    fn test_canonicalize_situation() {
+        let key = |hole: &str, board: &str| {
+            let hole = parse_cards(hole).unwrap();
+            canonicalize_situation((hole[0], hole[1]), &parse_cards(board).unwrap())
+        };
+
+        assert_eq!(key("Ah Kh", "Qh Jh Th"), key("As Ks", "Qs Js Ts"));
+        assert_eq!(key("Ah Kh", "Qh Jh Th"), key("Kd Ad", "Td Qd Jd"));
+        assert_eq!(key("Ah Kc", "2h 7c 9d"), key("As Kd", "2s 7d 9h"));
+        assert_ne!(key("Ah Kh", "Qh Jh Th"), key("Ah Ks", "Qh Jh Th"));
+        assert_ne!(key("Ah Kh", "Qh Jh Th"), key("Ah Kh", "Qs Js Ts"));
+    }
+
+    #[test]
+    fn test_distinct_flops() {
+        let flops = distinct_flops();
+        assert_eq!(flops.len(), 1755);
+
+        // One monotone flop per set of three ranks: C(13,3) = 286
+        let monotone = flops.iter().filter(|f| f.iter().all(|c| c.suit == f[0].suit)).count();
+        assert_eq!(monotone, 286);
+        assert!(flops.iter().all(|f| f[0] != f[1] && f[1] != f[2] && f[0] != f[2]));
+    }
+
+    #[test]
+    fn test_distinct_flop_iter_weights() {
+        let weighted: Vec<([Card; 3], usize)> = DistinctFlopIter::new().weighted().collect();
+        assert_eq!(weighted.len(), 1755);
+        assert_eq!(weighted.iter().map(|&(_, w)| w).sum::<usize>(), 22100);
+
+        let flops: Vec<[Card; 3]> = weighted.iter().map(|&(f, _)| f).collect();
+        assert_eq!(flops, distinct_flops());
+
+        // 2c 2d 2h stands for all four trips of deuces
+        let trips = parse_cards("2c 2d 2h").unwrap();
+        assert_eq!(weighted.iter().find(|(f, _)| f[..] == trips[..]).map(|&(_, w)| w), Some(4));
+    }
+
     #[test]
     fn test_canonical_hand_pair() {
         let hand = CanonicalHand::new(Rank::Ace, Rank::Ace, false);
@@ -504,10 +853,125 @@ mod tests {
         assert!(!are_strategically_equivalent(&hand1, &hand3)); // suited vs offsuit
     }
 
+    #[test]
+    fn test_chen_score() {
+        let score = |s: &str| CanonicalHand::parse(s).unwrap().chen_score();
+        let assert_score = |s: &str, expected: f64| {
+            assert!((score(s) - expected).abs() < 1e-9, "{s}: {}", score(s));
+        };
+
+        // Published values
+        assert_score("AA", 20.0);
+        assert_score("KK", 16.0);
+        assert_score("AKs", 12.0);
+        assert_score("AKo", 10.0);
+        assert_score("JTs", 9.0);
+        assert_score("T9s", 8.0);
+        assert_score("55", 5.0);
+        assert_score("22", 5.0);
+        assert_score("72o", -1.0);
+
+        // AA scores highest and 72o lowest (ties allowed)
+        let all = get_all_canonical_hands();
+        assert!(all.iter().all(|h| h.chen_score() <= score("AA")));
+        assert!(all.iter().all(|h| h.chen_score() >= score("72o")));
+    }
+
+    #[test]
+    fn test_sklansky_group() {
+        let group = |s: &str| CanonicalHand::parse(s).unwrap().sklansky_group();
+
+        assert_eq!(group("AA"), 1);
+        assert_eq!(group("AKs"), 1);
+        assert_eq!(group("AKo"), 2);
+        assert_eq!(group("A5s"), 5);
+        assert_eq!(group("K2s"), 7);
+        assert_eq!(group("T8o"), 8);
+        assert_eq!(group("72o"), 9);
+
+        // Every listed hand parses and belongs to exactly one group
+        let listed: Vec<&str> = SKLANSKY_GROUPS.iter().flat_map(|g| g.iter().copied()).collect();
+        let unique: HashSet<&str> = listed.iter().copied().collect();
+        assert_eq!(listed.len(), unique.len());
+        assert!(listed.iter().all(|h| CanonicalHand::parse(h).is_ok()));
+    }
+
     #[test]
     fn test_total_combos() {
         let hands = get_all_canonical_hands();
         let total: usize = hands.iter().map(|h| h.num_combos()).sum();
         assert_eq!(total, 1326); // C(52, 2) = 1326
     }
+
+    #[test]
+    fn test_remaining_combos() {
+        let aks = CanonicalHand::parse("AKs").unwrap();
+        assert_eq!(remaining_combos(&aks, &parse_cards("Ah").unwrap()), 3);
+
+        let aa = CanonicalHand::parse("AA").unwrap();
+        assert_eq!(remaining_combos(&aa, &parse_cards("Ah As").unwrap()), 1);
+
+        // Matches the allocating version for every hand
+        let dead = parse_cards("Ah Kd 7c 7s").unwrap();
+        for hand in get_all_canonical_hands() {
+            assert_eq!(
+                remaining_combos(&hand, &dead),
+                get_combos_excluding(&hand, &dead).len(),
+                "{hand}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_combo_breakdown() {
+        let tokens = ["AA".to_string(), "AKs".to_string()];
+        let range = CardDistribution::from_range(&tokens, &parse_cards("Ah").unwrap()).unwrap();
+        let counts = combo_breakdown(&range);
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&CanonicalHand::parse("AA").unwrap()], 3);
+        assert_eq!(counts[&CanonicalHand::parse("AKs").unwrap()], 3);
+    }
+
+    #[test]
+    fn test_preflop_table_from_json() {
+        let json = r#"{"2": {"AA": 85.2, "72o": 34.5}, "3": {"AA": 73.4}}"#;
+        let table = PreflopTable::from_json(json).unwrap();
+        let aa = CanonicalHand::parse("AA").unwrap();
+
+        assert_eq!(table.lookup(&aa, 2), Some(85.2));
+        assert_eq!(table.lookup(&aa, 3), Some(73.4));
+        assert_eq!(table.lookup(&aa, 4), None);
+        assert_eq!(table.lookup(&CanonicalHand::parse("KK").unwrap(), 2), None);
+
+        assert!(PreflopTable::from_json(r#"{"2": {"AAx": 1.0}}"#).is_err());
+        assert!(PreflopTable::from_json(r#"{"two": {"AA": 1.0}}"#).is_err());
+        assert!(PreflopTable::from_json("[]").is_err());
+    }
+
+    #[test]
+    fn test_preflop_table_builtin() {
+        let table = PreflopTable::builtin();
+        let aa = CanonicalHand::parse("AA").unwrap();
+        assert_eq!(table.lookup(&aa, 2), Some(85.2));
+        assert_eq!(table.lookup(&CanonicalHand::parse("32o").unwrap(), 2), Some(32.3));
+        assert!(table.lookup(&aa, 10).is_some());
+        assert_eq!(table.lookup(&aa, 11), None);
+    }
+
+    #[test]
+    fn test_hand_strength_order() {
+        let order = hand_strength_order(2);
+        assert_eq!(order.len(), 169);
+        assert_eq!(order[0].to_string(), "AA");
+        // Heads-up 32o is last, with 72o just above it
+        assert_eq!(order[168].to_string(), "32o");
+        let seven_deuce = order.iter().position(|h| h.to_string() == "72o").unwrap();
+        assert!(seven_deuce >= 160, "72o ranked {seven_deuce}");
+        assert_eq!(order.iter().collect::<HashSet<_>>().len(), 169);
+
+        assert_eq!(hand_strength_order(6)[0].to_string(), "AA");
+        // Out-of-range player counts use the nearest table
+        assert_eq!(hand_strength_order(1), order);
+        assert_eq!(hand_strength_order(20), hand_strength_order(10));
+    }
 }
@@ -45,6 +45,11 @@ impl Rank {
         Rank::Ace,
     ];
 
+    /// Iterate over all ranks in ascending order
+    pub fn iter() -> impl Iterator<Item = Rank> {
+        Self::ALL.into_iter()
+    }
+
     /// Create rank from numeric value (2-14)
     #[must_use]
     pub fn from_value(v: u8) -> Option<Self> {
@@ -120,6 +125,33 @@ impl fmt::Display for Rank {
     }
 }
 
+impl FromStr for Rank {
+    type Err = ParseError;
+
+    /// Parse a single rank character, or "10" for a ten
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "10" {
+            return Ok(Rank::Ten);
+        }
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => Err(ParseError::Empty),
+            (Some(c), None) => Rank::from_char(c).ok_or(ParseError::InvalidRank(c)),
+            _ => Err(ParseError::InvalidFormat(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<u8> for Rank {
+    type Error = ParseError;
+
+    /// Convert a numeric value (2-14)
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        Rank::from_value(v).ok_or(ParseError::InvalidRankValue(v))
+    }
+}
+
 /// Card suit
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -134,6 +166,11 @@ impl Suit {
     /// All suits
     pub const ALL: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
 
+    /// Iterate over all suits in `ALL` order
+    pub fn iter() -> impl Iterator<Item = Suit> {
+        Self::ALL.into_iter()
+    }
+
     /// Parse from character ('c', 'd', 'h', 's' or Unicode symbols)
     #[must_use]
     pub fn from_char(c: char) -> Option<Self> {
@@ -167,6 +204,18 @@ impl Suit {
             Suit::Spades => '♠',
         }
     }
+
+    /// Whether the suit is printed in red (hearts and diamonds)
+    #[must_use]
+    pub const fn is_red(self) -> bool {
+        matches!(self, Suit::Hearts | Suit::Diamonds)
+    }
+
+    /// Display color of the suit: `"red"` or `"black"`
+    #[must_use]
+    pub const fn color(self) -> &'static str {
+        if self.is_red() { "red" } else { "black" }
+    }
 }
 
 impl fmt::Display for Suit {
@@ -175,6 +224,29 @@ impl fmt::Display for Suit {
     }
 }
 
+impl FromStr for Suit {
+    type Err = ParseError;
+
+    /// Parse a single suit letter or Unicode symbol
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => Err(ParseError::Empty),
+            (Some(c), None) => Suit::try_from(c),
+            _ => Err(ParseError::InvalidFormat(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<char> for Suit {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Suit::from_char(c).ok_or(ParseError::InvalidSuit(c))
+    }
+}
+
 /// A playing card with rank and suit
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Card {
@@ -189,6 +261,17 @@ impl Card {
         Self { rank, suit }
     }
 
+    /// Iterate over all 52 cards in index order (same order as `FULL_DECK`)
+    pub fn all() -> impl Iterator<Item = Card> {
+        FULL_DECK.into_iter()
+    }
+
+    /// The card of the same rank in `suit`
+    #[must_use]
+    pub const fn with_suit(self, suit: Suit) -> Self {
+        Self::new(self.rank, suit)
+    }
+
     /// Convert to 0-51 index
     /// Formula: (rank - 2) * 4 + suit
     #[must_use]
@@ -247,6 +330,17 @@ impl Card {
     pub fn pretty(self) -> String {
         format!("{}{}", self.rank.to_char(), self.suit.to_symbol())
     }
+
+    /// Like `pretty`, with red suits wrapped in ANSI color codes when `color`
+    /// is true, for terminal output
+    #[must_use]
+    pub fn pretty_colored(self, color: bool) -> String {
+        if color && self.suit.is_red() {
+            format!("\x1b[31m{}\x1b[0m", self.pretty())
+        } else {
+            self.pretty()
+        }
+    }
 }
 
 impl fmt::Display for Card {
@@ -286,46 +380,132 @@ pub enum ParseError {
     InvalidRank(char),
     #[error("invalid suit character: {0}")]
     InvalidSuit(char),
+    #[error("invalid rank value: {0} (expected 2-14)")]
+    InvalidRankValue(u8),
 }
 
-/// Parse multiple cards from a string
-/// Supports formats: "Ah Kh", "AhKh", "Ah, Kh"
-pub fn parse_cards(s: &str) -> Result<Vec<Card>, ParseError> {
-    let s = s.trim();
-    if s.is_empty() {
-        return Ok(Vec::new());
+/// Serde helpers that (de)serialize a `Card` as its compact string form ("Ah")
+/// instead of the default `{rank, suit}` object.
+///
+/// Use with `#[serde(with = "holdem_core::card::serde_str")]` on a `Card`
+/// field, or `serde_str::vec` on a `Vec<Card>` field. Deserialization goes
+/// through [`Card::parse`], so "10h" and Unicode suits are accepted.
+pub mod serde_str {
+    use super::Card;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize a card as its display string
+    ///
+    /// # Errors
+    /// Returns the serializer's error if writing the string fails.
+    pub fn serialize<S: Serializer>(card: &Card, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(card)
     }
 
-    // Try splitting by whitespace or comma first
-    let parts: Vec<&str> = s.split([' ', ',', '\t']).filter(|p| !p.is_empty()).collect();
+    /// Deserialize a card from a string such as "Ah" or "10h"
+    ///
+    /// # Errors
+    /// Returns an error if the input is not a string or not a valid card.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Card, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Card::parse(&s).map_err(serde::de::Error::custom)
+    }
 
-    if parts.len() > 1 {
-        // Multiple parts separated by delimiter
-        parts.into_iter().map(Card::parse).collect()
-    } else {
-        // Try parsing as concatenated cards (e.g., "AhKh")
-        let chars: Vec<char> = s.chars().collect();
-        let mut cards = Vec::new();
-        let mut i = 0;
-
-        while i < chars.len() {
-            // Check for "10x" format
-            if i + 2 < chars.len() && chars[i] == '1' && chars[i + 1] == '0' {
-                let suit = Suit::from_char(chars[i + 2]).ok_or(ParseError::InvalidSuit(chars[i + 2]))?;
-                cards.push(Card::new(Rank::Ten, suit));
-                i += 3;
-            } else if i + 1 < chars.len() {
-                let rank = Rank::from_char(chars[i]).ok_or(ParseError::InvalidRank(chars[i]))?;
-                let suit = Suit::from_char(chars[i + 1]).ok_or(ParseError::InvalidSuit(chars[i + 1]))?;
-                cards.push(Card::new(rank, suit));
-                i += 2;
-            } else {
-                return Err(ParseError::InvalidFormat(s.to_string()));
+    /// Same as the parent module, for `Vec<Card>` fields
+    pub mod vec {
+        use super::Card;
+        use serde::ser::SerializeSeq;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        /// Serialize cards as a list of display strings
+        ///
+        /// # Errors
+        /// Returns the serializer's error if writing the sequence fails.
+        pub fn serialize<S: Serializer>(cards: &[Card], serializer: S) -> Result<S::Ok, S::Error> {
+            let mut seq = serializer.serialize_seq(Some(cards.len()))?;
+            for card in cards {
+                seq.serialize_element(&card.to_string())?;
             }
+            seq.end()
+        }
+
+        /// Deserialize cards from a list of strings
+        ///
+        /// # Errors
+        /// Returns an error if the input is not a list of valid card strings.
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<Card>, D::Error> {
+            Vec::<String>::deserialize(deserializer)?
+                .iter()
+                .map(|s| Card::parse(s).map_err(serde::de::Error::custom))
+                .collect()
         }
+    }
+}
+
+/// Parse multiple cards from a string
+/// Supports formats: `Ah Kh`, `AhKh`, `Ah, Kh`, with ASCII or Unicode suits
+/// (`A♥K♠`, `10♦`, `Ah K♠`). Each delimited part may hold several
+/// concatenated cards, and emoji variation selectors after a suit are ignored.
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, ParseError> {
+    let mut cards = Vec::new();
+    for part in s.split(|c: char| c == ',' || c.is_whitespace()).filter(|p| !p.is_empty()) {
+        parse_concatenated(part, &mut cards)?;
+    }
+    Ok(cards)
+}
+
+/// Parse concatenated cards (e.g., `AhKh` or `10♦J♦`) into `cards`
+fn parse_concatenated(part: &str, cards: &mut Vec<Card>) -> Result<(), ParseError> {
+    // Work on chars, not bytes: Unicode suits are multi-byte
+    let chars: Vec<char> = part.chars().filter(|&c| c != '\u{FE0F}').collect();
+    let mut i = 0;
 
-        Ok(cards)
+    while i < chars.len() {
+        // Check for "10x" format
+        let (rank, rank_len) = if chars[i] == '1' && chars.get(i + 1) == Some(&'0') {
+            (Rank::Ten, 2)
+        } else {
+            let rank = Rank::from_char(chars[i]).ok_or(ParseError::InvalidRank(chars[i]))?;
+            (rank, 1)
+        };
+        let &suit_char =
+            chars.get(i + rank_len).ok_or_else(|| ParseError::InvalidFormat(part.to_string()))?;
+        let suit = Suit::from_char(suit_char).ok_or(ParseError::InvalidSuit(suit_char))?;
+        cards.push(Card::new(rank, suit));
+        i += rank_len + 1;
     }
+
+    Ok(())
+}
+
+/// The most common suit among `cards` and how many cards have it
+///
+/// Ties go to the first suit in `Suit::ALL` order. Returns `None` for an
+/// empty slice.
+#[must_use]
+pub fn dominant_suit(cards: &[Card]) -> Option<(Suit, usize)> {
+    let mut counts = [0usize; 4];
+    for card in cards {
+        counts[card.suit as usize] += 1;
+    }
+    Suit::ALL
+        .into_iter()
+        .zip(counts)
+        .rev()
+        .max_by_key(|&(_, count)| count)
+        .filter(|&(_, count)| count > 0)
+}
+
+/// Relabel suits, keeping ranks: each card's suit `s` becomes `mapping[s as usize]`
+///
+/// With a permutation of `Suit::ALL` the result is a suit-isomorphic
+/// situation, e.g. swapping hearts and spades turns `AhKh` into `AsKs`.
+/// Mappings that send two suits to the same one can produce duplicates.
+#[must_use]
+pub fn rotate_suits(cards: &[Card], mapping: [Suit; 4]) -> Vec<Card> {
+    cards.iter().map(|c| c.with_suit(mapping[c.suit as usize])).collect()
 }
 
 /// Format cards as string
@@ -334,10 +514,93 @@ pub fn format_cards(cards: &[Card]) -> String {
     cards.iter().map(ToString::to_string).collect::<Vec<_>>().join(" ")
 }
 
+/// A set of cards stored as a 52-bit mask, one bit per `Card::to_index`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    /// Create an empty set
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// The raw bitmask (bit `i` set means `Card::from_index(i)` is present)
+    #[must_use]
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Add a card
+    pub fn insert(&mut self, card: Card) {
+        self.0 |= 1 << card.to_index();
+    }
+
+    /// Remove a card
+    pub fn remove(&mut self, card: Card) {
+        self.0 &= !(1 << card.to_index());
+    }
+
+    /// Check if a card is in the set
+    #[must_use]
+    pub const fn contains(self, card: Card) -> bool {
+        self.0 & (1 << card.to_index()) != 0
+    }
+
+    /// Number of cards in the set
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Check if the set is empty
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for card in iter {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+/// A full Hold'em deal: hole cards, board and burn cards
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HoldemDeal {
+    /// Two hole cards per player, in seat order
+    pub hole_cards: Vec<[Card; 2]>,
+    /// Flop cards
+    pub flop: [Card; 3],
+    /// Turn card
+    pub turn: Card,
+    /// River card
+    pub river: Card,
+    /// Burn cards, in the order they were burned
+    pub burned: Vec<Card>,
+}
+
+impl HoldemDeal {
+    /// All five board cards
+    #[must_use]
+    pub fn board(&self) -> Vec<Card> {
+        let mut board = self.flop.to_vec();
+        board.push(self.turn);
+        board.push(self.river);
+        board
+    }
+}
+
 /// A deck of 52 playing cards
 pub struct Deck {
     cards: Vec<Card>,
     removed: HashSet<Card>,
+    burned: Vec<Card>,
     rng: StdRng,
 }
 
@@ -352,12 +615,56 @@ impl Deck {
         let mut deck = Self {
             cards: Self::full_deck(),
             removed: HashSet::new(),
+            burned: Vec::new(),
             rng,
         };
         deck.shuffle();
         deck
     }
 
+    /// Create a shuffled deck with `removed` already taken out
+    ///
+    /// The cards are tracked like those passed to `remove`, so `reset`
+    /// keeps them out of the deck.
+    ///
+    /// # Errors
+    /// Returns an error if a card is listed twice.
+    pub fn from_removed(removed: &[Card], seed: Option<u64>) -> HoldemResult<Self> {
+        let mut removed_set = HashSet::with_capacity(removed.len());
+        for &card in removed {
+            if !removed_set.insert(card) {
+                return Err(HoldemError::DuplicateCard(card.to_string()));
+            }
+        }
+
+        let rng = match seed {
+            Some(s) => StdRng::seed_from_u64(s),
+            None => StdRng::from_os_rng(),
+        };
+        let mut deck = Self {
+            cards: Vec::new(),
+            removed: removed_set,
+            burned: Vec::new(),
+            rng,
+        };
+        deck.reset();
+        Ok(deck)
+    }
+
+    /// Create a deck in `FULL_DECK` order without shuffling
+    ///
+    /// Useful for tests and staged scenarios; `shuffle` and `reset` still
+    /// shuffle with a randomly seeded RNG.
+    #[must_use]
+    pub fn unshuffled() -> Self {
+        Self {
+            cards: FULL_DECK.to_vec(),
+            removed: HashSet::new(),
+            burned: Vec::new(),
+            rng: StdRng::from_os_rng(),
+        }
+    }
+
     /// Get all 52 cards in order
     #[must_use]
     pub fn full_deck() -> Vec<Card> {
@@ -370,9 +677,10 @@ impl Deck {
         cards
     }
 
-    /// Reset deck to full 52 cards
+    /// Reset deck to full 52 cards (minus removed cards)
     pub fn reset(&mut self) {
         self.cards = Self::full_deck();
+        self.burned.clear();
         if !self.removed.is_empty() {
             self.cards.retain(|c| !self.removed.contains(c));
         }
@@ -406,6 +714,94 @@ impl Deck {
         self.deal(1).map(|mut v| v.pop().unwrap())
     }
 
+    /// Burn n cards from the top of the deck
+    ///
+    /// Burned cards are out of play and are reported by `burned`.
+    ///
+    /// # Errors
+    /// Returns an error if there are not enough cards remaining.
+    pub fn burn(&mut self, n: usize) -> HoldemResult<()> {
+        let cards = self.deal(n)?;
+        self.burned.extend(cards);
+        Ok(())
+    }
+
+    /// Cards burned since the last reset
+    #[must_use]
+    pub fn burned(&self) -> &[Card] {
+        &self.burned
+    }
+
+    /// Deal a complete hand the way a dealer would
+    ///
+    /// Hole cards go out one at a time around the table (two passes), then
+    /// burn + flop, burn + turn, burn + river.
+    ///
+    /// # Errors
+    /// Returns an error if there are fewer than 2 players or not enough
+    /// cards remaining. Nothing is dealt in that case.
+    pub fn deal_holdem(&mut self, num_players: usize) -> HoldemResult<HoldemDeal> {
+        if num_players < 2 {
+            return Err(HoldemError::NotEnoughPlayers(2));
+        }
+        let needed = 2 * num_players + 8;
+        if needed > self.cards.len() {
+            return Err(HoldemError::InsufficientCards {
+                requested: needed,
+                available: self.cards.len(),
+            });
+        }
+
+        let first_pass = self.deal(num_players)?;
+        let second_pass = self.deal(num_players)?;
+        let hole_cards = first_pass
+            .into_iter()
+            .zip(second_pass)
+            .map(|(c1, c2)| [c1, c2])
+            .collect();
+
+        let burned_before = self.burned.len();
+        self.burn(1)?;
+        let flop = self.deal(3)?;
+        self.burn(1)?;
+        let turn = self.deal_one()?;
+        self.burn(1)?;
+        let river = self.deal_one()?;
+
+        Ok(HoldemDeal {
+            hole_cards,
+            flop: [flop[0], flop[1], flop[2]],
+            turn,
+            river,
+            burned: self.burned[burned_before..].to_vec(),
+        })
+    }
+
+    /// Deal the named cards, in the given order
+    ///
+    /// The cards are taken out of the deck wherever they are, so a staged
+    /// scenario can hand out exactly the cards it needs.
+    ///
+    /// # Errors
+    /// Returns an error if a card is not in the deck (or is named twice).
+    /// Nothing is dealt in that case.
+    pub fn deal_specific(&mut self, cards: &[Card]) -> HoldemResult<Vec<Card>> {
+        for (i, card) in cards.iter().enumerate() {
+            if cards[..i].contains(card) {
+                return Err(HoldemError::DuplicateCard(card.to_string()));
+            }
+            if !self.cards.contains(card) {
+                if self.removed.contains(card) {
+                    return Err(HoldemError::CardAlreadyRemoved(card.to_string()));
+                }
+                return Err(HoldemError::CardNotInDeck(card.to_string()));
+            }
+        }
+
+        self.cards.retain(|c| !cards.contains(c));
+        Ok(cards.to_vec())
+    }
+
     /// Remove specific cards from the deck
     ///
     /// # Errors
@@ -451,6 +847,12 @@ impl Deck {
         &self.cards
     }
 
+    /// Remaining cards as a `CardSet` bitmask
+    #[must_use]
+    pub fn remaining_mask(&self) -> CardSet {
+        self.cards.iter().copied().collect()
+    }
+
     /// Peek at the top n cards
     ///
     /// # Errors
@@ -510,6 +912,35 @@ pub const FULL_DECK: [Card; 52] = {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_dominant_suit() {
+        let cards = parse_cards("Ah 9h 4h Kh 2c").unwrap();
+        assert_eq!(dominant_suit(&cards), Some((Suit::Hearts, 4)));
+
+        let tied = parse_cards("As Ks Qd Jd").unwrap();
+        assert_eq!(dominant_suit(&tied), Some((Suit::Diamonds, 2)));
+
+        assert_eq!(dominant_suit(&[]), None);
+    }
+
+    #[test]
+    fn test_rotate_suits() {
+        use crate::evaluator::evaluate_hand;
+
+        let ah = Card::new(Rank::Ace, Suit::Hearts);
+        assert_eq!(ah.with_suit(Suit::Clubs), Card::new(Rank::Ace, Suit::Clubs));
+
+        let hearts = parse_cards("Ah Kh Qh Jh Th").unwrap();
+        let swap = [Suit::Clubs, Suit::Diamonds, Suit::Spades, Suit::Hearts];
+        let spades = rotate_suits(&hearts, swap);
+        assert_eq!(spades, parse_cards("As Ks Qs Js Ts").unwrap());
+        assert_eq!(evaluate_hand(&spades).unwrap(), evaluate_hand(&hearts).unwrap());
+        assert_eq!(rotate_suits(&spades, swap), hearts);
+
+        let mixed = parse_cards("Ac Kd 7h 7s 2c").unwrap();
+        assert_eq!(rotate_suits(&mixed, Suit::ALL), mixed);
+    }
+
     #[test]
     fn test_rank_value() {
         assert_eq!(Rank::Two.value(), 2);
@@ -533,6 +964,42 @@ mod tests {
         assert_eq!(Suit::from_char('x'), None);
     }
 
+    #[test]
+    fn test_rank_suit_from_str_and_try_from() {
+        assert_eq!("A".parse::<Rank>(), Ok(Rank::Ace));
+        assert_eq!("t".parse::<Rank>(), Ok(Rank::Ten));
+        assert_eq!("10".parse::<Rank>(), Ok(Rank::Ten));
+        assert_eq!(Rank::try_from(14u8), Ok(Rank::Ace));
+        assert_eq!(Rank::try_from(2u8), Ok(Rank::Two));
+        assert_eq!("".parse::<Rank>(), Err(ParseError::Empty));
+        assert_eq!("X".parse::<Rank>(), Err(ParseError::InvalidRank('X')));
+        assert_eq!("AK".parse::<Rank>(), Err(ParseError::InvalidFormat("AK".to_string())));
+        assert_eq!(Rank::try_from(1u8), Err(ParseError::InvalidRankValue(1)));
+        assert_eq!(Rank::try_from(15u8), Err(ParseError::InvalidRankValue(15)));
+
+        assert_eq!("s".parse::<Suit>(), Ok(Suit::Spades));
+        assert_eq!("♦".parse::<Suit>(), Ok(Suit::Diamonds));
+        assert_eq!(Suit::try_from('C'), Ok(Suit::Clubs));
+        assert_eq!(Suit::try_from('x'), Err(ParseError::InvalidSuit('x')));
+        assert_eq!("hs".parse::<Suit>(), Err(ParseError::InvalidFormat("hs".to_string())));
+    }
+
+    #[test]
+    fn test_suit_colors() {
+        assert!(Suit::Hearts.is_red() && Suit::Diamonds.is_red());
+        assert!(!Suit::Clubs.is_red() && !Suit::Spades.is_red());
+        assert_eq!(Suit::Hearts.color(), "red");
+        assert_eq!(Suit::Diamonds.color(), "red");
+        assert_eq!(Suit::Clubs.color(), "black");
+        assert_eq!(Suit::Spades.color(), "black");
+
+        let heart = Card::parse("Ah").unwrap();
+        let spade = Card::parse("As").unwrap();
+        assert_eq!(heart.pretty_colored(true), "\x1b[31mA♥\x1b[0m");
+        assert_eq!(heart.pretty_colored(false), heart.pretty());
+        assert_eq!(spade.pretty_colored(true), "A♠");
+    }
+
     #[test]
     fn test_card_index_roundtrip() {
         for i in 0..52 {
@@ -564,6 +1031,73 @@ mod tests {
         assert_eq!(cards.len(), 2);
     }
 
+    #[test]
+    fn test_parse_cards_unicode_suits() {
+        let ace_hearts = Card::new(Rank::Ace, Suit::Hearts);
+        let king_spades = Card::new(Rank::King, Suit::Spades);
+        let ten_diamonds = Card::new(Rank::Ten, Suit::Diamonds);
+
+        assert_eq!(parse_cards("A♥K♠").unwrap(), vec![ace_hearts, king_spades]);
+        assert_eq!(parse_cards("10♦").unwrap(), vec![ten_diamonds]);
+        assert_eq!(parse_cards("Ah K♠").unwrap(), vec![ace_hearts, king_spades]);
+        assert_eq!(
+            parse_cards("10♦A♥, K♠").unwrap(),
+            vec![ten_diamonds, ace_hearts, king_spades]
+        );
+        // Emoji presentation selectors are skipped
+        assert_eq!(
+            parse_cards("A\u{2665}\u{FE0F}K\u{2660}\u{FE0F}").unwrap(),
+            vec![ace_hearts, king_spades]
+        );
+
+        assert_eq!(parse_cards("A♥K"), Err(ParseError::InvalidFormat("A♥K".to_string())));
+        assert_eq!(parse_cards("A♥ K★"), Err(ParseError::InvalidSuit('★')));
+        assert_eq!(parse_cards("1♦"), Err(ParseError::InvalidRank('1')));
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct CardHolder {
+        #[serde(with = "serde_str")]
+        card: Card,
+        #[serde(with = "serde_str::vec")]
+        board: Vec<Card>,
+    }
+
+    #[test]
+    fn test_serde_str_roundtrip() {
+        let holder = CardHolder {
+            card: Card::new(Rank::Ace, Suit::Hearts),
+            board: parse_cards("Ts 9d 2c").unwrap(),
+        };
+
+        let json = serde_json::to_string(&holder).unwrap();
+        assert_eq!(json, r#"{"card":"Ah","board":["Ts","9d","2c"]}"#);
+
+        let back: CardHolder = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, holder);
+    }
+
+    #[test]
+    fn test_serde_str_accepts_ten_form() {
+        let json = r#"{"card":"10h","board":["10c","K♠"]}"#;
+        let holder: CardHolder = serde_json::from_str(json).unwrap();
+        assert_eq!(holder.card, Card::new(Rank::Ten, Suit::Hearts));
+        assert_eq!(
+            holder.board,
+            vec![Card::new(Rank::Ten, Suit::Clubs), Card::new(Rank::King, Suit::Spades)]
+        );
+
+        // Re-serializes in the canonical "T" form
+        let json = serde_json::to_string(&holder).unwrap();
+        assert_eq!(json, r#"{"card":"Th","board":["Tc","Ks"]}"#);
+    }
+
+    #[test]
+    fn test_serde_str_rejects_invalid() {
+        let result: Result<CardHolder, _> = serde_json::from_str(r#"{"card":"Xh","board":[]}"#);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_deck_basics() {
         let mut deck = Deck::new(Some(42));
@@ -590,6 +1124,133 @@ mod tests {
         assert!(!deck.contains(kh));
     }
 
+    #[test]
+    fn test_deck_from_removed() {
+        let removed = parse_cards("Ah Kh 2c").unwrap();
+        let mut deck = Deck::from_removed(&removed, Some(7)).unwrap();
+        assert_eq!(deck.len(), 49);
+        assert!(removed.iter().all(|&c| !deck.contains(c)));
+
+        deck.deal(10).unwrap();
+        deck.reset();
+        assert_eq!(deck.len(), 49);
+        assert!(removed.iter().all(|&c| !deck.contains(c)));
+
+        // Same seed, same order
+        let again = Deck::from_removed(&removed, Some(7)).unwrap();
+        assert_eq!(Deck::from_removed(&removed, Some(7)).unwrap().remaining(), again.remaining());
+
+        assert!(matches!(
+            Deck::from_removed(&parse_cards("Ah Ah").unwrap(), None),
+            Err(HoldemError::DuplicateCard(_))
+        ));
+    }
+
+    #[test]
+    fn test_deck_unshuffled_deals_in_order() {
+        let mut deck = Deck::unshuffled();
+        assert_eq!(deck.remaining(), &FULL_DECK[..]);
+
+        assert_eq!(deck.deal(3).unwrap(), parse_cards("2c 2d 2h").unwrap());
+        assert_eq!(deck.deal_one().unwrap(), Card::new(Rank::Two, Suit::Spades));
+        assert_eq!(deck.peek(1).unwrap(), &[Card::new(Rank::Three, Suit::Clubs)]);
+        assert_eq!(deck.len(), 48);
+    }
+
+    #[test]
+    fn test_deck_deal_specific() {
+        let mut deck = Deck::unshuffled();
+        let wanted = parse_cards("Ah Kh 2c").unwrap();
+
+        assert_eq!(deck.deal_specific(&wanted).unwrap(), wanted);
+        assert_eq!(deck.len(), 49);
+        assert!(wanted.iter().all(|&c| !deck.contains(c)));
+        // Remaining cards keep their order
+        assert_eq!(deck.deal(2).unwrap(), parse_cards("2d 2h").unwrap());
+
+        // Already dealt: error and nothing else is taken
+        let before = deck.len();
+        assert!(matches!(
+            deck.deal_specific(&parse_cards("Qs Ah").unwrap()),
+            Err(HoldemError::CardNotInDeck(_))
+        ));
+        assert!(matches!(
+            deck.deal_specific(&parse_cards("Qs Qs").unwrap()),
+            Err(HoldemError::DuplicateCard(_))
+        ));
+        assert_eq!(deck.len(), before);
+        assert!(deck.contains(Card::new(Rank::Queen, Suit::Spades)));
+    }
+
+    #[test]
+    fn test_deck_burn() {
+        let mut deck = Deck::unshuffled();
+        deck.burn(2).unwrap();
+        assert_eq!(deck.burned(), parse_cards("2c 2d").unwrap().as_slice());
+        assert_eq!(deck.len(), 50);
+        assert!(deck.burn(51).is_err());
+
+        deck.reset();
+        assert!(deck.burned().is_empty());
+        assert_eq!(deck.len(), 52);
+    }
+
+    #[test]
+    fn test_deck_deal_holdem() {
+        let mut deck = Deck::unshuffled();
+        let deal = deck.deal_holdem(2).unwrap();
+
+        // 4 hole cards + 3 burns + 5 board cards
+        assert_eq!(deck.len(), 52 - 12);
+        assert_eq!(deal.hole_cards.len(), 2);
+        assert_eq!(deal.burned.len(), 3);
+        assert_eq!(deck.burned(), deal.burned.as_slice());
+
+        // Unshuffled order: seat 1 gets the 1st and 3rd cards
+        assert_eq!(deal.hole_cards[0], [FULL_DECK[0], FULL_DECK[2]]);
+        assert_eq!(deal.hole_cards[1], [FULL_DECK[1], FULL_DECK[3]]);
+        assert_eq!(deal.burned, vec![FULL_DECK[4], FULL_DECK[8], FULL_DECK[10]]);
+        assert_eq!(deal.flop, [FULL_DECK[5], FULL_DECK[6], FULL_DECK[7]]);
+        assert_eq!(deal.turn, FULL_DECK[9]);
+        assert_eq!(deal.river, FULL_DECK[11]);
+        assert_eq!(deal.board().len(), 5);
+
+        // Every dealt card is distinct and gone from the deck
+        let mut all: Vec<Card> = deal.hole_cards.iter().flatten().copied().collect();
+        all.extend(deal.board());
+        all.extend(&deal.burned);
+        assert_eq!(all.iter().collect::<HashSet<_>>().len(), 12);
+        assert!(all.iter().all(|&c| !deck.contains(c)));
+    }
+
+    #[test]
+    fn test_deck_deal_holdem_errors() {
+        let mut deck = Deck::new(Some(1));
+        assert!(matches!(deck.deal_holdem(1), Err(HoldemError::NotEnoughPlayers(2))));
+        // 23 players need 54 cards
+        assert!(matches!(
+            deck.deal_holdem(23),
+            Err(HoldemError::InsufficientCards { requested: 54, available: 52 })
+        ));
+        assert_eq!(deck.len(), 52);
+    }
+
+    #[test]
+    fn test_deck_remaining_mask() {
+        let mut deck = Deck::new(Some(3));
+        assert_eq!(deck.remaining_mask().bits(), (1u64 << 52) - 1);
+
+        let dealt = deck.deal(5).unwrap();
+        let removed = deck.remaining()[0];
+        deck.remove(&[removed]).unwrap();
+        let mask = deck.remaining_mask();
+        assert_eq!(mask.bits().count_ones() as usize, deck.len());
+        assert_eq!(mask.len(), deck.len());
+        assert!(!mask.contains(removed));
+        assert!(dealt.iter().all(|&c| !mask.contains(c)));
+        assert!(deck.remaining().iter().all(|&c| mask.contains(c)));
+    }
+
     #[test]
     fn test_full_deck_const() {
         assert_eq!(FULL_DECK.len(), 52);
@@ -597,4 +1258,13 @@ mod tests {
         assert_eq!(FULL_DECK[0], Card::new(Rank::Two, Suit::Clubs));
         assert_eq!(FULL_DECK[51], Card::new(Rank::Ace, Suit::Spades));
     }
+
+    #[test]
+    fn test_card_all_matches_full_deck() {
+        assert_eq!(Card::all().count(), 52);
+        assert!(Card::all().eq(FULL_DECK));
+        assert!(Card::all().enumerate().all(|(i, c)| usize::from(c.to_index()) == i));
+        assert_eq!(Rank::iter().count(), 13);
+        assert_eq!(Suit::iter().collect::<Vec<_>>(), Suit::ALL);
+    }
 }
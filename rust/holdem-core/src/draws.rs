@@ -2,10 +2,13 @@
 //!
 //! Analyzes hole cards + board to identify drawing hands and their outs.
 
-use crate::card::{Card, Rank, Suit, FULL_DECK};
+use crate::card::{dominant_suit, Card, Rank, Suit, FULL_DECK};
 use crate::error::{HoldemError, HoldemResult};
-use crate::evaluator::{evaluate_hand, HandType};
+use crate::evaluator::{evaluate_hand, validate_postflop_board, HandRank, HandType};
+use crate::range::CardDistribution;
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
 
 /// Types of draws
@@ -13,7 +16,8 @@ use std::collections::{HashMap, HashSet};
 pub enum DrawType {
     /// 4 cards to a flush (9 outs)
     FlushDraw,
-    /// 3 cards to a flush (backdoor, need 2 running cards)
+    /// 3 cards to a flush on the flop (backdoor, need 2 running cards), or
+    /// suited hole cards preflop (see `preflop_flush_potential`)
     BackdoorFlush,
     /// Open-ended straight draw (8 outs)
     OpenEnded,
@@ -69,6 +73,42 @@ impl FlushDraw {
             DrawType::BackdoorFlush
         }
     }
+
+    /// Hand type this draw is drawing to
+    #[must_use]
+    pub fn completed_hand_type(&self) -> HandType {
+        HandType::Flush
+    }
+
+    /// The hand made by each out, for the given hole cards and board
+    ///
+    /// An out can make more than a flush, e.g. a straight flush. The flag
+    /// is set when the made hand is the nuts on the board plus that out.
+    /// See `hand_with_out` for how hole cards are used.
+    ///
+    /// # Errors
+    /// Returns an error if hole cards, board and out are not 5-7 cards (for
+    /// Omaha, if the board plus out has fewer than 3 cards).
+    pub fn out_hands(
+        &self,
+        hole_cards: &[Card],
+        board: &[Card],
+    ) -> HoldemResult<Vec<(Card, HandRank, bool)>> {
+        out_hands(&self.outs, hole_cards, board)
+    }
+
+    /// Probability of completing the flush with `cards_to_come` more cards
+    ///
+    /// A flush draw needs one of its outs, a backdoor flush two, and suited
+    /// hole cards preflop three. See `unseen_cards` for the deck size assumed.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn completion_probability(&self, cards_to_come: u8) -> f64 {
+        let unseen = unseen_cards(cards_to_come);
+        let outs = self.outs.len() as u32;
+        let needed = 5u32.saturating_sub(self.cards_held as u32);
+        prob_at_least(unseen, outs, u32::from(cards_to_come), needed)
+    }
 }
 
 /// A straight draw
@@ -92,6 +132,166 @@ impl StraightDraw {
     pub fn out_count(&self) -> usize {
         self.outs.len()
     }
+
+    /// Hand type this draw is drawing to
+    #[must_use]
+    pub fn completed_hand_type(&self) -> HandType {
+        HandType::Straight
+    }
+
+    /// The hand made by each out, for the given hole cards and board
+    ///
+    /// An out of the draw's suit can make a flush or straight flush instead.
+    /// The flag is set when the made hand is the nuts on the board plus that
+    /// out. See `hand_with_out` for how hole cards are used.
+    ///
+    /// # Errors
+    /// Returns an error if hole cards, board and out are not 5-7 cards (for
+    /// Omaha, if the board plus out has fewer than 3 cards).
+    pub fn out_hands(
+        &self,
+        hole_cards: &[Card],
+        board: &[Card],
+    ) -> HoldemResult<Vec<(Card, HandRank, bool)>> {
+        out_hands(&self.outs, hole_cards, board)
+    }
+
+    /// Probability of completing the straight with `cards_to_come` more cards
+    ///
+    /// One-card draws need any of their outs. A backdoor straight needs one
+    /// card of each of its two `needed_ranks`, all four of each assumed live.
+    /// See `unseen_cards` for the deck size assumed.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn completion_probability(&self, cards_to_come: u8) -> f64 {
+        let unseen = unseen_cards(cards_to_come);
+        let k = u32::from(cards_to_come);
+        if self.draw_type != DrawType::BackdoorStraight {
+            return prob_at_least(unseen, self.outs.len() as u32, k, 1);
+        }
+        if k < 2 {
+            return 0.0;
+        }
+        // Inclusion-exclusion over missing either rank
+        let total = choose(unseen, k);
+        1.0 - (2.0 * choose(unseen - 4, k) - choose(unseen - 8, k)) / total
+    }
+}
+
+/// Outs that improve a made hand to a stronger one
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImprovementDraw {
+    /// Hand hero holds now
+    pub current: HandType,
+    /// Hand the outs make
+    pub improved: HandType,
+    /// Specific out cards
+    pub outs: Vec<Card>,
+}
+
+impl ImprovementDraw {
+    /// Get number of outs
+    #[must_use]
+    pub fn out_count(&self) -> usize {
+        self.outs.len()
+    }
+}
+
+/// Evaluate each out added to the board, flagging outs that make the nuts
+fn out_hands(
+    outs: &[Card],
+    hole_cards: &[Card],
+    board: &[Card],
+) -> HoldemResult<Vec<(Card, HandRank, bool)>> {
+    outs.iter()
+        .map(|&out| {
+            let rank = hand_with_out(hole_cards, board, out)?;
+            let is_nut = rank == nut_with_out(hole_cards.len(), board, out)?;
+            Ok((out, rank, is_nut))
+        })
+        .collect()
+}
+
+/// Best hand once `out` is added to the board
+///
+/// Two hole cards play as in Hold'em. With more (Omaha), the hand must use
+/// exactly two hole cards and three board cards.
+fn hand_with_out(hole_cards: &[Card], board: &[Card], out: Card) -> HoldemResult<HandRank> {
+    let mut board = board.to_vec();
+    board.push(out);
+    if hole_cards.len() <= 2 {
+        return evaluate_hand(&[hole_cards, &board].concat());
+    }
+    omaha_hand(hole_cards, &board)
+}
+
+/// The nuts once `out` is added to the board, under the same rules as
+/// `hand_with_out` for a player holding `hole_count` cards
+///
+/// Only the board and the out are treated as known, as in `evaluator::nuts`.
+fn nut_with_out(hole_count: usize, board: &[Card], out: Card) -> HoldemResult<HandRank> {
+    let mut board = board.to_vec();
+    board.push(out);
+    if hole_count <= 2 {
+        return crate::evaluator::nuts(&board);
+    }
+
+    let live: Vec<Card> = FULL_DECK.iter().filter(|c| !board.contains(c)).copied().collect();
+    let mut best: Option<HandRank> = None;
+    for (h1, h2) in live.iter().copied().tuple_combinations() {
+        let rank = omaha_hand(&[h1, h2], &board)?;
+        if best.as_ref().is_none_or(|current| rank > *current) {
+            best = Some(rank);
+        }
+    }
+    Ok(best.expect("at least 45 live cards remain"))
+}
+
+/// Best hand using exactly two of `hole_cards` and three board cards
+fn omaha_hand(hole_cards: &[Card], board: &[Card]) -> HoldemResult<HandRank> {
+    let mut best: Option<HandRank> = None;
+    for (h1, h2) in hole_cards.iter().copied().tuple_combinations() {
+        for (a, b, c) in board.iter().copied().tuple_combinations() {
+            let rank = evaluate_hand(&[h1, h2, a, b, c])?;
+            if best.as_ref().is_none_or(|current| rank > *current) {
+                best = Some(rank);
+            }
+        }
+    }
+    best.ok_or(HoldemError::InvalidCardCount {
+        expected: "at least 3 board cards with the out",
+        got: board.len(),
+    })
+}
+
+/// Unseen cards when only hero's hole cards and the board are known
+///
+/// With `cards_to_come` left the board has `5 - cards_to_come` cards, so
+/// 47 cards are unseen on the flop and 46 on the turn. Dead cards are not
+/// removed from this count.
+fn unseen_cards(cards_to_come: u8) -> u32 {
+    45 + u32::from(cards_to_come.min(5))
+}
+
+/// Binomial coefficient C(n, k) as a float
+fn choose(n: u32, k: u32) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    (0..k).fold(1.0, |acc, i| acc * f64::from(n - i) / f64::from(i + 1))
+}
+
+/// Probability that `draws` cards from `unseen` include at least `needed`
+/// of the `outs` (hypergeometric)
+fn prob_at_least(unseen: u32, outs: u32, draws: u32, needed: u32) -> f64 {
+    if draws < needed || outs < needed {
+        return 0.0;
+    }
+    let total = choose(unseen, draws);
+    let below: f64 = (0..needed)
+        .map(|hits| choose(outs, hits) * choose(unseen - outs, draws - hits))
+        .sum();
+    1.0 - below / total
 }
 
 /// Complete draw analysis result
@@ -113,9 +313,49 @@ pub struct DrawAnalysis {
     pub total_outs: usize,
     /// All out cards combined
     pub all_outs: Vec<Card>,
+    /// Made-hand improvements (trips, two pair, full house, quads); only
+    /// filled in by `analyze_draws_full`
+    #[serde(default)]
+    pub improvement_draws: Vec<ImprovementDraw>,
+    /// Unique outs across draws and improvements (not double-counted)
+    #[serde(default)]
+    pub total_equity_outs: usize,
 }
 
 impl DrawAnalysis {
+    /// Assemble an analysis, collecting the unique outs of every draw
+    fn from_draws(
+        hole_cards: &[Card],
+        board: &[Card],
+        (has_flush, has_straight): (bool, bool),
+        flush_draws: Vec<FlushDraw>,
+        straight_draws: Vec<StraightDraw>,
+    ) -> Self {
+        // Collect all unique outs
+        let mut all_outs_set: HashSet<Card> = HashSet::new();
+        for draw in &flush_draws {
+            all_outs_set.extend(draw.outs.iter());
+        }
+        for draw in &straight_draws {
+            all_outs_set.extend(draw.outs.iter());
+        }
+        let all_outs: Vec<Card> = all_outs_set.into_iter().collect();
+        let total_outs = all_outs.len();
+
+        Self {
+            hole_cards: hole_cards.to_vec(),
+            board: board.to_vec(),
+            has_flush,
+            has_straight,
+            flush_draws,
+            straight_draws,
+            total_outs,
+            all_outs,
+            improvement_draws: Vec::new(),
+            total_equity_outs: total_outs,
+        }
+    }
+
     /// Check if any draw exists
     #[must_use]
     pub fn has_draw(&self) -> bool {
@@ -155,86 +395,85 @@ fn build_rank_mask(cards: &[Card]) -> u16 {
     mask
 }
 
+/// Rank value for a bit of the rank mask (bit 0 is the low ace)
+fn rank_of_bit(bit: u8) -> u8 {
+    if bit == 0 { 14 } else { bit + 1 }
+}
+
 /// Count bits set in a 16-bit value
 #[allow(clippy::cast_possible_truncation)]
 fn count_bits(n: u16) -> u8 {
     n.count_ones() as u8
 }
 
+/// Position of the lowest set bit in a 16-bit value (16 if none is set)
+#[allow(clippy::cast_possible_truncation)]
+fn lowest_bit(n: u16) -> u8 {
+    n.trailing_zeros() as u8
+}
+
 /// Analyze flush draws
 fn analyze_flush_draws(
     hole_cards: &[Card],
     board: &[Card],
     dead_cards: &HashSet<Card>,
 ) -> Vec<FlushDraw> {
-    let mut draws = Vec::new();
     let all_cards: Vec<Card> = hole_cards.iter().chain(board.iter()).copied().collect();
 
-    // Group by suit
-    let mut by_suit: HashMap<Suit, Vec<Card>> = HashMap::new();
-    for &card in &all_cards {
-        by_suit.entry(card.suit).or_default().push(card);
+    // A regular flush draw needs 4 cards. A backdoor needs 3 on the flop
+    // (never on the turn, when only one card is left), and preflop suited
+    // hole cards count as backdoor potential for `preflop_flush_potential`.
+    // Two suits can't both qualify
+    // from at most 7 cards, so only the dominant suit needs checking.
+    let Some((suit, count)) = dominant_suit(&all_cards) else {
+        return Vec::new();
+    };
+    let qualifies = match board.len() {
+        0 => count == 2,
+        3 => count >= 3,
+        _ => count >= 4,
+    };
+    if !qualifies {
+        return Vec::new();
     }
 
-    // Check each suit
-    for (suit, cards) in by_suit {
-        let count = cards.len();
-
-        // Need at least 3 for backdoor or 4 for regular flush draw
-        if count < 3 {
-            continue;
-        }
-
-        // Backdoor only valid on flop (3 board cards)
-        if count == 3 && board.len() != 3 {
-            continue;
-        }
-
-        // Find outs (remaining cards of this suit)
-        let outs: Vec<Card> = FULL_DECK
-            .iter()
-            .filter(|c| c.suit == suit && !all_cards.contains(c) && !dead_cards.contains(c))
-            .copied()
-            .collect();
-
-        // Check if hero has the nut flush draw:
-        // Hero holds the highest suited card among all cards that could be
-        // held by opponents (i.e., not on board, not dead)
-        let hero_suited: Vec<Card> = hole_cards
-            .iter()
-            .filter(|c| c.suit == suit)
-            .copied()
-            .collect();
-        let hero_highest = hero_suited.iter().map(|c| c.rank as u8).max().unwrap_or(0);
+    // Find outs (remaining cards of this suit)
+    let outs: Vec<Card> = FULL_DECK
+        .iter()
+        .filter(|c| c.suit == suit && !all_cards.contains(c) && !dead_cards.contains(c))
+        .copied()
+        .collect();
 
-        // Check if any higher card of this suit could be held by opponents
-        // (not in hero's hand, not on board, not dead)
-        let all_known: HashSet<Card> = all_cards
-            .iter()
-            .copied()
-            .chain(dead_cards.iter().copied())
-            .collect();
-        let mut is_nut = true;
-        for rank in Rank::ALL {
-            if (rank as u8) > hero_highest {
-                let higher_card = Card::new(rank, suit);
-                if !all_known.contains(&higher_card) {
-                    // A higher card is still live - could be held by opponent
-                    is_nut = false;
-                    break;
-                }
-            }
-        }
+    let all_known: HashSet<Card> = all_cards
+        .iter()
+        .copied()
+        .chain(dead_cards.iter().copied())
+        .collect();
+
+    vec![FlushDraw {
+        suit,
+        cards_held: count,
+        outs,
+        is_nut: holds_nut_flush_card(hole_cards, suit, &all_known),
+    }]
+}
 
-        draws.push(FlushDraw {
-            suit,
-            cards_held: count,
-            outs,
-            is_nut,
-        });
-    }
+/// Whether hero holds the highest card of `suit` that is not already known
+///
+/// Any higher card of the suit that is not in hero's hand, on the board or
+/// dead could be held by an opponent.
+fn holds_nut_flush_card(hole_cards: &[Card], suit: Suit, all_known: &HashSet<Card>) -> bool {
+    let hero_highest = hole_cards
+        .iter()
+        .filter(|c| c.suit == suit)
+        .map(|c| c.rank as u8)
+        .max()
+        .unwrap_or(0);
 
-    draws
+    Rank::ALL
+        .into_iter()
+        .filter(|&rank| (rank as u8) > hero_highest)
+        .all(|rank| all_known.contains(&Card::new(rank, suit)))
 }
 
 /// Analyze straight draws using bitmask
@@ -242,15 +481,36 @@ fn analyze_straight_draws(
     hole_cards: &[Card],
     board: &[Card],
     dead_cards: &HashSet<Card>,
+) -> Vec<StraightDraw> {
+    let draws = straight_window_draws(hole_cards, board, dead_cards, false);
+    consolidate_straight_draws(draws, board.len() < 5)
+}
+
+/// Straight draws for each 5-rank window, before consolidation
+///
+/// With `hole_must_play` (Omaha), a window only counts if every hole card
+/// has a distinct rank inside it, so the straight uses all of them.
+fn straight_window_draws(
+    hole_cards: &[Card],
+    board: &[Card],
+    dead_cards: &HashSet<Card>,
+    hole_must_play: bool,
 ) -> Vec<StraightDraw> {
     let mut draws = Vec::new();
     let all_cards: Vec<Card> = hole_cards.iter().chain(board.iter()).copied().collect();
     let mask = build_rank_mask(&all_cards);
+    let hole_mask = build_rank_mask(hole_cards);
+    let hole_plays = |window_mask: u16| {
+        !hole_must_play || usize::from(count_bits(hole_mask & window_mask)) == hole_cards.len()
+    };
 
     // Check all possible 5-card windows
     // Window starting positions: 0 (A-5) through 9 (T-A)
-    for start in 0..=9 {
+    for start in 0..=9u8 {
         let window_mask: u16 = 0b11111 << start;
+        if !hole_plays(window_mask) {
+            continue;
+        }
         let present = mask & window_mask;
         let present_count = count_bits(present);
 
@@ -261,72 +521,44 @@ fn analyze_straight_draws(
 
         if present_count == 4 {
             // One gap - either OESD or gutshot
-            let missing_mask = window_mask & !mask;
-            let missing_bit = missing_mask.trailing_zeros() as u8;
+            let missing_bit = lowest_bit(window_mask & !mask);
 
             // Calculate high card of this straight
-            let high_card = if start == 0 { 5 } else { start as u8 + 5 };
+            let high_card = if start == 0 { 5 } else { start + 5 };
 
             // Check if it's nut straight (Broadway: T-A)
             let is_nut = high_card == 14;
 
-            // Find needed rank
-            let needed_rank = if missing_bit == 0 {
-                14 // Ace (low position)
+            // Four in a row is open-ended only if a card can complete it on
+            // both sides: A-2-3-4 (needs a 5) and J-Q-K-A (needs a ten) are
+            // one-way draws. An open run is reported once, from the window
+            // where the gap is at the top, with the low completing rank too.
+            let (draw_type, needed_ranks) = if missing_bit == start + 4 && start > 0 {
+                (
+                    DrawType::OpenEnded,
+                    vec![rank_of_bit(start - 1), rank_of_bit(missing_bit)],
+                )
+            } else if missing_bit == start && start < 9 {
+                continue;
             } else {
-                missing_bit + 1
+                (DrawType::Gutshot, vec![rank_of_bit(missing_bit)])
             };
 
-            // Get outs (all 4 suits of needed rank)
+            // Get outs (all 4 suits of each needed rank)
             let outs: Vec<Card> = FULL_DECK
                 .iter()
                 .filter(|c| {
-                    c.rank.value() == needed_rank && !all_cards.contains(c) && !dead_cards.contains(c)
+                    needed_ranks.contains(&c.rank.value())
+                        && !all_cards.contains(c)
+                        && !dead_cards.contains(c)
                 })
                 .copied()
                 .collect();
 
-            // Determine draw type
-            let draw_type = if missing_bit == 0 || missing_bit == (start as u8 + 4) {
-                // Gap at the edge - gutshot
-                DrawType::Gutshot
-            } else {
-                // Check for OESD: need gaps at both edges of a 4-card run
-                // Check if we can also complete a straight with another card
-                let has_open_end_low = start > 0 && (mask & (1 << (start - 1))) == 0;
-                let has_open_end_high = start < 9 && (mask & (1 << (start + 5))) == 0;
-
-                if has_open_end_low || has_open_end_high {
-                    // This is part of an OESD if we have 4 consecutive cards
-                    // Check if the 4 present cards are consecutive
-                    let mut consecutive = true;
-                    let mut prev_bit: Option<u8> = None;
-                    for bit in 0..5 {
-                        if (present >> (start + bit)) & 1 == 1 {
-                            if let Some(p) = prev_bit {
-                                if bit != p + 1 {
-                                    consecutive = false;
-                                    break;
-                                }
-                            }
-                            prev_bit = Some(bit);
-                        }
-                    }
-
-                    if consecutive {
-                        DrawType::OpenEnded
-                    } else {
-                        DrawType::Gutshot
-                    }
-                } else {
-                    DrawType::Gutshot
-                }
-            };
-
             if !outs.is_empty() {
                 draws.push(StraightDraw {
                     draw_type,
-                    needed_ranks: vec![needed_rank],
+                    needed_ranks,
                     outs,
                     high_card,
                     is_nut,
@@ -335,66 +567,13 @@ fn analyze_straight_draws(
         }
     }
 
-    // Check for double gutshot (6-card window with 4 cards, 2 internal gaps)
-    // Only meaningful when more cards are to come (not on river)
-    if board.len() < 5 {
-    for start in 0..=8 {
-        let window_mask: u16 = 0b111111 << start;
-        let present = mask & window_mask;
-        let present_count = count_bits(present);
-
-        if present_count == 4 {
-            let missing_mask = window_mask & !mask;
-            let gaps: Vec<u8> = (0..6)
-                .filter(|&i| (missing_mask >> (start + i)) & 1 == 1)
-                .collect();
-
-            // Both gaps must be internal (not at position 0 or 5)
-            if gaps.len() == 2 && gaps[0] > 0 && gaps[1] < 5 {
-                let needed_ranks: Vec<u8> = gaps
-                    .iter()
-                    .map(|&g| {
-                        let bit = start as u8 + g;
-                        if bit == 0 {
-                            14
-                        } else {
-                            bit + 1
-                        }
-                    })
-                    .collect();
-
-                // Find all outs
-                let outs: Vec<Card> = FULL_DECK
-                    .iter()
-                    .filter(|c| {
-                        needed_ranks.contains(&c.rank.value())
-                            && !all_cards.contains(c)
-                            && !dead_cards.contains(c)
-                    })
-                    .copied()
-                    .collect();
-
-                let high_card = if start == 0 { 6 } else { start as u8 + 6 };
-                let is_nut = high_card == 14;
-
-                if !outs.is_empty() {
-                    draws.push(StraightDraw {
-                        draw_type: DrawType::DoubleGutshot,
-                        needed_ranks,
-                        outs,
-                        high_card,
-                        is_nut,
-                    });
-                }
-            }
-        }
-    }
-    }
-
     // Check for backdoor straights (only on flop)
     if board.len() == 3 {
         for start in 0..=9 {
             let window_mask: u16 = 0b11111 << start;
+            if !hole_plays(window_mask) {
+                continue;
+            }
             let present = mask & window_mask;
             let present_count = count_bits(present);
 
@@ -407,17 +586,8 @@ fn analyze_straight_draws(
                     .collect();
 
                 if gaps.len() == 2 {
-                    let needed_ranks: Vec<u8> = gaps
-                        .iter()
-                        .map(|&g| {
-                            let bit = start as u8 + g;
-                            if bit == 0 {
-                                14
-                            } else {
-                                bit + 1
-                            }
-                        })
-                        .collect();
+                    let needed_ranks: Vec<u8> =
+                        gaps.iter().map(|&g| rank_of_bit(start as u8 + g)).collect();
 
                     let high_card = if start == 0 { 5 } else { start as u8 + 5 };
                     let is_nut = high_card == 14;
@@ -435,21 +605,62 @@ fn analyze_straight_draws(
         }
     }
 
-    // Deduplicate: keep the best draw for each high_card
-    let mut best_draws: HashMap<u8, StraightDraw> = HashMap::new();
+    draws
+}
+
+/// Merge per-window straight draws so each needed rank is reported once
+///
+/// An open-ended draw claims both of its ranks. Gutshots to distinct ranks
+/// that are not part of an open-ended draw combine into one double gutshot
+/// while cards are still to come. Backdoor straights are kept one per high
+/// card, unless a one-card draw already makes that straight. The result is
+/// ordered by high card, highest first.
+fn consolidate_straight_draws(draws: Vec<StraightDraw>, cards_to_come: bool) -> Vec<StraightDraw> {
+    let mut open_ended = Vec::new();
+    let mut gutshots = Vec::new();
+    let mut backdoors: HashMap<u8, StraightDraw> = HashMap::new();
     for draw in draws {
-        let key = draw.high_card;
-        if let Some(existing) = best_draws.get(&key) {
-            // Prefer by: out count desc, then draw type priority
-            if draw.outs.len() > existing.outs.len() {
-                best_draws.insert(key, draw);
+        match draw.draw_type {
+            DrawType::OpenEnded => open_ended.push(draw),
+            DrawType::BackdoorStraight => {
+                backdoors.entry(draw.high_card).or_insert(draw);
             }
-        } else {
-            best_draws.insert(key, draw);
+            _ => gutshots.push(draw),
         }
     }
 
-    best_draws.into_values().collect()
+    let one_card_highs: HashSet<u8> =
+        open_ended.iter().chain(&gutshots).map(|d| d.high_card).collect();
+    backdoors.retain(|high, _| !one_card_highs.contains(high));
+
+    let mut covered: HashSet<u8> = open_ended
+        .iter()
+        .flat_map(|d| d.needed_ranks.iter().copied())
+        .collect();
+
+    // Keep the highest straight for each remaining needed rank
+    gutshots.sort_by_key(|d| Reverse(d.high_card));
+    gutshots.retain(|d| d.needed_ranks.iter().any(|&r| covered.insert(r)));
+
+    let mut result = open_ended;
+    if result.is_empty() && cards_to_come && gutshots.len() >= 2 {
+        let mut needed_ranks: Vec<u8> =
+            gutshots.iter().flat_map(|d| d.needed_ranks.clone()).collect();
+        needed_ranks.sort_unstable();
+        result.push(StraightDraw {
+            draw_type: DrawType::DoubleGutshot,
+            needed_ranks,
+            outs: gutshots.iter().flat_map(|d| d.outs.clone()).collect(),
+            high_card: gutshots[0].high_card,
+            is_nut: gutshots.iter().any(|d| d.is_nut),
+        });
+    } else {
+        result.extend(gutshots);
+    }
+
+    result.extend(backdoors.into_values());
+    result.sort_by_key(|d| Reverse(d.high_card));
+    result
 }
 
 /// Analyze draws for given hole cards and board
@@ -488,8 +699,9 @@ pub fn analyze_draws(hole_cards: &[Card], board: &[Card], dead_cards: &[Card]) -
         (false, false)
     };
 
-    // Analyze draws (only if we don't already have the made hand)
-    let flush_draws = if has_flush {
+    // Analyze draws (only if we don't already have the made hand). Preflop
+    // flush potential is opt-in via `preflop_flush_potential`.
+    let flush_draws = if has_flush || board.is_empty() {
         Vec::new()
     } else {
         analyze_flush_draws(hole_cards, board, &dead_set)
@@ -501,27 +713,210 @@ pub fn analyze_draws(hole_cards: &[Card], board: &[Card], dead_cards: &[Card]) -
         analyze_straight_draws(hole_cards, board, &dead_set)
     };
 
-    // Collect all unique outs
-    let mut all_outs_set: HashSet<Card> = HashSet::new();
-    for draw in &flush_draws {
-        all_outs_set.extend(draw.outs.iter());
+    Ok(DrawAnalysis::from_draws(
+        hole_cards,
+        board,
+        (has_flush, has_straight),
+        flush_draws,
+        straight_draws,
+    ))
+}
+
+/// Backdoor flush potential of suited hole cards before the flop
+///
+/// `analyze_draws` reports no flush draws preflop, so this is the opt-in for
+/// callers that want it: suited hole cards give a `BackdoorFlush` needing
+/// three more of the suit, with the live cards of that suit as outs. Offsuit
+/// hole cards give `None`.
+///
+/// # Errors
+/// Returns an error if `hole_cards.len() != 2`
+pub fn preflop_flush_potential(
+    hole_cards: &[Card],
+    dead_cards: &[Card],
+) -> HoldemResult<Option<FlushDraw>> {
+    if hole_cards.len() != 2 {
+        return Err(HoldemError::InvalidCardCount {
+            expected: "2",
+            got: hole_cards.len(),
+        });
+    }
+    let dead_set: HashSet<Card> = dead_cards.iter().copied().collect();
+    Ok(analyze_flush_draws(hole_cards, &[], &dead_set).pop())
+}
+
+/// Analyze draws plus outs that improve an already made hand
+///
+/// On top of `analyze_draws`, each unseen live card is added to the board.
+/// It is an improvement out when hero already has a pair or better and the
+/// card makes two pair, trips, a full house or quads, either by matching a
+/// hole card's rank or, from two pair or trips, by filling up. Cards that
+/// only pair the board for a weaker hand, and cards that make a straight or
+/// flush (counted by the draws), are not improvement outs. Improvements are
+/// grouped by the hand they make, weakest first; `total_equity_outs` counts
+/// each card once across draws and improvements.
+///
+/// # Errors
+/// Returns an error if:
+/// - `hole_cards.len() != 2`
+/// - `board.len() > 5`
+pub fn analyze_draws_full(
+    hole_cards: &[Card],
+    board: &[Card],
+    dead_cards: &[Card],
+) -> HoldemResult<DrawAnalysis> {
+    let mut analysis = analyze_draws(hole_cards, board, dead_cards)?;
+    if !(3..=4).contains(&board.len()) {
+        return Ok(analysis);
+    }
+
+    let mut cards: Vec<Card> = hole_cards.iter().chain(board).copied().collect();
+    let current = evaluate_hand(&cards)?.hand_type;
+    if current < HandType::OnePair {
+        return Ok(analysis);
+    }
+
+    let mut improvements: Vec<ImprovementDraw> = Vec::new();
+    for &card in &FULL_DECK {
+        if cards.contains(&card) || dead_cards.contains(&card) {
+            continue;
+        }
+        cards.push(card);
+        let improved = evaluate_hand(&cards)?.hand_type;
+        cards.pop();
+
+        let made_hand = matches!(
+            improved,
+            HandType::TwoPair | HandType::ThreeOfAKind | HandType::FullHouse | HandType::FourOfAKind
+        );
+        let uses_hole_rank = hole_cards.iter().any(|c| c.rank == card.rank);
+        let fills_up = current >= HandType::TwoPair && improved >= HandType::FullHouse;
+        if improved <= current || !made_hand || !(uses_hole_rank || fills_up) {
+            continue;
+        }
+
+        match improvements.iter_mut().find(|d| d.improved == improved) {
+            Some(draw) => draw.outs.push(card),
+            None => improvements.push(ImprovementDraw {
+                current,
+                improved,
+                outs: vec![card],
+            }),
+        }
+    }
+    improvements.sort_by_key(|d| d.improved);
+
+    let mut equity_outs: HashSet<Card> = analysis.all_outs.iter().copied().collect();
+    for draw in &improvements {
+        equity_outs.extend(draw.outs.iter().copied());
+    }
+    analysis.total_equity_outs = equity_outs.len();
+    analysis.improvement_draws = improvements;
+    Ok(analysis)
+}
+
+/// Analyze Omaha draws, where a hand uses exactly two hole cards
+///
+/// A flush draw needs two hole cards of the suit, so a single suited hole
+/// card never counts, and each suit held twice is checked (double-suited
+/// hands can have two draws). Straight draws are found for every two-card
+/// combination and merged, so the outs are those of any combination.
+/// Made flushes and straights also use exactly two hole cards.
+///
+/// # Errors
+/// Returns an error if `board.len() > 5`.
+pub fn analyze_draws_omaha(
+    hole: &[Card; 4],
+    board: &[Card],
+    dead_cards: &[Card],
+) -> HoldemResult<DrawAnalysis> {
+    if board.len() > 5 {
+        return Err(HoldemError::BoardTooLarge(board.len()));
+    }
+
+    let pairs: Vec<[Card; 2]> =
+        hole.iter().copied().tuple_combinations().map(|(a, b)| [a, b]).collect();
+
+    let (mut has_flush, mut has_straight) = (false, false);
+    if board.len() >= 3 {
+        for pair in &pairs {
+            for (a, b, c) in board.iter().copied().tuple_combinations() {
+                let hand_type = evaluate_hand(&[pair[0], pair[1], a, b, c])?.hand_type;
+                has_flush |= matches!(
+                    hand_type,
+                    HandType::Flush | HandType::StraightFlush | HandType::RoyalFlush
+                );
+                has_straight |= matches!(
+                    hand_type,
+                    HandType::Straight | HandType::StraightFlush | HandType::RoyalFlush
+                );
+            }
+        }
     }
-    for draw in &straight_draws {
-        all_outs_set.extend(draw.outs.iter());
+
+    let all_known: HashSet<Card> =
+        hole.iter().chain(board).chain(dead_cards).copied().collect();
+
+    let mut flush_draws = Vec::new();
+    if !has_flush {
+        for suit in Suit::ALL {
+            let held = hole.iter().filter(|c| c.suit == suit).count();
+            let on_board = board.iter().filter(|c| c.suit == suit).count();
+            // Only two hole cards can play, and three suited board cards with
+            // two suited hole cards would already be a flush
+            let qualifies = held >= 2
+                && match board.len() {
+                    0 => true,
+                    3 => on_board >= 1,
+                    _ => on_board >= 2,
+                };
+            if !qualifies {
+                continue;
+            }
+            flush_draws.push(FlushDraw {
+                suit,
+                cards_held: 2 + on_board,
+                outs: FULL_DECK
+                    .iter()
+                    .filter(|c| c.suit == suit && !all_known.contains(c))
+                    .copied()
+                    .collect(),
+                is_nut: holds_nut_flush_card(hole, suit, &all_known),
+            });
+        }
     }
-    let all_outs: Vec<Card> = all_outs_set.into_iter().collect();
-    let total_outs = all_outs.len();
 
-    Ok(DrawAnalysis {
-        hole_cards: hole_cards.to_vec(),
-        board: board.to_vec(),
-        has_flush,
-        has_straight,
+    let mut straight_draws = Vec::new();
+    if !has_straight {
+        let mut window_draws: Vec<StraightDraw> = Vec::new();
+        for pair in &pairs {
+            // The other two hole cards are out of the deck but cannot play
+            let unavailable: HashSet<Card> = dead_cards
+                .iter()
+                .chain(hole.iter().filter(|c| !pair.contains(c)))
+                .copied()
+                .collect();
+            for draw in straight_window_draws(pair, board, &unavailable, true) {
+                let duplicate = window_draws.iter().any(|d| {
+                    d.draw_type == draw.draw_type
+                        && d.high_card == draw.high_card
+                        && d.needed_ranks == draw.needed_ranks
+                });
+                if !duplicate {
+                    window_draws.push(draw);
+                }
+            }
+        }
+        straight_draws = consolidate_straight_draws(window_draws, board.len() < 5);
+    }
+
+    Ok(DrawAnalysis::from_draws(
+        hole,
+        board,
+        (has_flush, has_straight),
         flush_draws,
         straight_draws,
-        total_outs,
-        all_outs,
-    })
+    ))
 }
 
 /// Count flush outs (convenience function)
@@ -587,6 +982,190 @@ pub fn get_primary_draw(hole_cards: &[Card], board: &[Card]) -> HoldemResult<Opt
     Ok(None)
 }
 
+/// Probability that villain outdraws hero on the river after hero completes
+/// a draw on the turn.
+///
+/// Hero's one-card draws (flush draws, open-enders and gutshots) on the flop
+/// define the turn cards that count as "hitting". For every villain combo and
+/// every such turn card where hero is then strictly ahead, all river cards
+/// are enumerated; the result is the weighted fraction of those runouts in
+/// which villain finishes with the better hand.
+///
+/// Only the flop is supported: on the turn a draw completes on the river,
+/// leaving no card for villain to redraw with, so a 4-card board is
+/// rejected rather than reported as zero risk.
+///
+/// # Errors
+/// Returns an error if hero does not have 2 cards, the board is not a flop,
+/// cards are duplicated, or hero is never ahead after hitting (no draw, or
+/// no compatible villain combo).
+pub fn redraw_risk(
+    hero: &[Card],
+    flop: &[Card],
+    villain_range: &CardDistribution,
+) -> HoldemResult<f64> {
+    if flop.len() != 3 {
+        return Err(HoldemError::InvalidCardCount {
+            expected: "3 (flop)",
+            got: flop.len(),
+        });
+    }
+    let analysis = analyze_draws(hero, flop, &[])?;
+
+    let mut known: HashSet<Card> = HashSet::new();
+    for &card in hero.iter().chain(flop) {
+        if !known.insert(card) {
+            return Err(HoldemError::DuplicateCard(card.to_string()));
+        }
+    }
+
+    // Turn cards that complete a one-card draw
+    let mut hits: HashSet<Card> = HashSet::new();
+    for draw in &analysis.flush_draws {
+        if draw.draw_type() == DrawType::FlushDraw {
+            hits.extend(draw.outs.iter().copied());
+        }
+    }
+    for draw in &analysis.straight_draws {
+        if matches!(
+            draw.draw_type,
+            DrawType::OpenEnded | DrawType::Gutshot | DrawType::DoubleGutshot
+        ) {
+            hits.extend(draw.outs.iter().copied());
+        }
+    }
+
+    let mut outdrawn = 0.0;
+    let mut total = 0.0;
+
+    for (i, &(v1, v2)) in villain_range.hands().iter().enumerate() {
+        if known.contains(&v1) || known.contains(&v2) {
+            continue;
+        }
+        let weight = villain_range.weight(i);
+
+        for &turn in &hits {
+            if turn == v1 || turn == v2 {
+                continue;
+            }
+            let mut hero_cards: Vec<Card> = hero.iter().chain(flop).copied().collect();
+            hero_cards.push(turn);
+            let mut villain_cards = vec![v1, v2];
+            villain_cards.extend_from_slice(flop);
+            villain_cards.push(turn);
+
+            if evaluate_hand(&hero_cards)? <= evaluate_hand(&villain_cards)? {
+                continue;
+            }
+
+            for &river in &FULL_DECK {
+                if known.contains(&river) || river == turn || river == v1 || river == v2 {
+                    continue;
+                }
+                hero_cards.push(river);
+                villain_cards.push(river);
+                if evaluate_hand(&villain_cards)? > evaluate_hand(&hero_cards)? {
+                    outdrawn += weight;
+                }
+                total += weight;
+                hero_cards.pop();
+                villain_cards.pop();
+            }
+        }
+    }
+
+    if total <= 0.0 {
+        return Err(HoldemError::NoValidCombinations);
+    }
+    Ok(outdrawn / total)
+}
+
+/// Next cards that improve hero's showdown against a known villain hand
+///
+/// Every unseen card is added to the board and both hands re-evaluated. A
+/// card is an out when it lifts hero from behind to a tie or the lead, or
+/// from a tie to the lead. Unlike draw outs this accounts for villain's
+/// hand: a flush card that also fills villain's boat is not an out.
+/// Outs come back in `FULL_DECK` order.
+///
+/// # Errors
+/// Returns an error if the board is not a flop or turn, or a card appears
+/// twice.
+pub fn outs_vs(
+    hero: (Card, Card),
+    villain: (Card, Card),
+    board: &[Card],
+) -> HoldemResult<Vec<Card>> {
+    if !(3..=4).contains(&board.len()) {
+        return Err(HoldemError::InvalidCardCount {
+            expected: "3-4 (board)",
+            got: board.len(),
+        });
+    }
+    let mut known: HashSet<Card> = HashSet::new();
+    for &card in [hero.0, hero.1, villain.0, villain.1].iter().chain(board) {
+        if !known.insert(card) {
+            return Err(HoldemError::DuplicateCard(card.to_string()));
+        }
+    }
+
+    let mut hero_cards: Vec<Card> = [hero.0, hero.1].iter().chain(board).copied().collect();
+    let mut villain_cards: Vec<Card> =
+        [villain.0, villain.1].iter().chain(board).copied().collect();
+    let before = evaluate_hand(&hero_cards)?.cmp(&evaluate_hand(&villain_cards)?);
+
+    let mut outs = Vec::new();
+    for &card in &FULL_DECK {
+        if known.contains(&card) {
+            continue;
+        }
+        hero_cards.push(card);
+        villain_cards.push(card);
+        if evaluate_hand(&hero_cards)?.cmp(&evaluate_hand(&villain_cards)?) > before {
+            outs.push(card);
+        }
+        hero_cards.pop();
+        villain_cards.pop();
+    }
+    Ok(outs)
+}
+
+/// Hole-card pairs that make a straight and pairs that make a flush with `board`
+///
+/// Works on 3-5 card boards: every pair of unseen cards is checked for five
+/// ranks in a row (wheel included) and for five cards of one suit. A
+/// straight flush appears in both lists. Pairs come out in `FULL_DECK`
+/// order, so the lists' lengths are the combo counts ("12 straight combos").
+///
+/// # Errors
+/// Returns an error if the board is not 3-5 cards or has duplicates.
+#[allow(clippy::type_complexity)]
+pub fn completing_combos(board: &[Card]) -> HoldemResult<(Vec<(Card, Card)>, Vec<(Card, Card)>)> {
+    validate_postflop_board(board)?;
+
+    let live: Vec<Card> = FULL_DECK.iter().filter(|c| !board.contains(c)).copied().collect();
+    let board_mask = build_rank_mask(board);
+    let mut straights = Vec::new();
+    let mut flushes = Vec::new();
+
+    for (i, &c1) in live.iter().enumerate() {
+        for &c2 in &live[i + 1..] {
+            let mask = board_mask | build_rank_mask(&[c1, c2]);
+            if (0..=9).any(|start| mask & (0b11111 << start) == 0b11111 << start) {
+                straights.push((c1, c2));
+            }
+
+            let mut cards = board.to_vec();
+            cards.extend([c1, c2]);
+            if dominant_suit(&cards).is_some_and(|(_, count)| count >= 5) {
+                flushes.push((c1, c2));
+            }
+        }
+    }
+
+    Ok((straights, flushes))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -596,6 +1175,268 @@ mod tests {
         parse_cards(s).unwrap()
     }
 
+    fn combo(s: &str) -> CardDistribution {
+        let c = cards(s);
+        CardDistribution::from_hand(c[0], c[1])
+    }
+
+    fn omaha(s: &str) -> [Card; 4] {
+        cards(s).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_omaha_single_suited_card_is_not_a_flush_draw() {
+        // One heart in hand plus two on board: four hearts, but not a draw
+        let analysis = analyze_draws_omaha(&omaha("Ah Kc 7d 2s"), &cards("Qh 9h 3c"), &[]).unwrap();
+        assert!(analysis.flush_draws.is_empty());
+
+        // The same one-card "flush" with three hearts on board is not made
+        let analysis =
+            analyze_draws_omaha(&omaha("Ah Kc 7d 2s"), &cards("Qh 9h 3h"), &[]).unwrap();
+        assert!(!analysis.has_flush);
+        assert!(analysis.flush_draws.is_empty());
+
+        // Two hearts in hand make it a nut flush draw with 9 outs
+        let analysis = analyze_draws_omaha(&omaha("Ah 5h 7d 2s"), &cards("Qh 9h 3c"), &[]).unwrap();
+        assert_eq!(analysis.flush_draws.len(), 1);
+        let draw = &analysis.flush_draws[0];
+        assert_eq!(draw.draw_type(), DrawType::FlushDraw);
+        assert_eq!(draw.out_count(), 9);
+        assert!(draw.is_nut);
+    }
+
+    #[test]
+    fn test_omaha_double_suited_draws() {
+        let analysis = analyze_draws_omaha(&omaha("Ah 5h Kd 2d"), &cards("Qh 9h 3d"), &[]).unwrap();
+        let mut draws: Vec<(Suit, DrawType)> =
+            analysis.flush_draws.iter().map(|d| (d.suit, d.draw_type())).collect();
+        draws.sort_by_key(|&(suit, _)| suit as u8);
+        assert_eq!(
+            draws,
+            vec![(Suit::Diamonds, DrawType::BackdoorFlush), (Suit::Hearts, DrawType::FlushDraw)]
+        );
+    }
+
+    #[test]
+    fn test_omaha_straight_draws_use_two_hole_cards() {
+        // 9-8 with 7-6 on board is open-ended: 10 or 5
+        let analysis = analyze_draws_omaha(&omaha("9c 8d Kh Ks"), &cards("7h 6s 2c"), &[]).unwrap();
+        let oesd = analysis
+            .straight_draws
+            .iter()
+            .find(|d| d.draw_type == DrawType::OpenEnded)
+            .unwrap();
+        assert_eq!(oesd.needed_ranks, vec![5, 10]);
+        assert_eq!(oesd.out_count(), 8);
+
+        // One hole card and four to a straight on board is not a draw in Omaha
+        let analysis =
+            analyze_draws_omaha(&omaha("9c Kd Kh Ks"), &cards("7h 6s 5c 2d"), &[]).unwrap();
+        assert!(analysis.straight_draws.is_empty());
+
+        // Hold'em would count the board straight as made; Omaha needs two hole cards
+        let analysis =
+            analyze_draws_omaha(&omaha("Ac Kd Kh Ks"), &cards("9h 8s 7c 6d 5h"), &[]).unwrap();
+        assert!(!analysis.has_straight);
+    }
+
+    #[test]
+    fn test_out_hands_straight_flush_combo_draw() {
+        let hole = cards("9h 8h");
+        let board = cards("7h 6h 2c");
+        let analysis = analyze_draws(&hole, &board, &[]).unwrap();
+
+        let flush = &analysis.flush_draws[0];
+        assert_eq!(flush.completed_hand_type(), HandType::Flush);
+        let hands = flush.out_hands(&hole, &board).unwrap();
+        assert_eq!(hands.len(), 9);
+        for (out, rank, is_nut) in &hands {
+            let expected = if out.rank == Rank::Ten || out.rank == Rank::Five {
+                HandType::StraightFlush
+            } else {
+                HandType::Flush
+            };
+            assert_eq!(rank.hand_type, expected, "{out}");
+            // Only the straight flushes are the nuts; KhQh beats 9h-high flushes
+            assert_eq!(*is_nut, expected == HandType::StraightFlush, "{out}");
+        }
+
+        let straight = analysis
+            .straight_draws
+            .iter()
+            .find(|d| d.draw_type == DrawType::OpenEnded)
+            .unwrap();
+        assert_eq!(straight.completed_hand_type(), HandType::Straight);
+        let hands = straight.out_hands(&hole, &board).unwrap();
+        let hand_for = |card: &str| {
+            let card = Card::parse(card).unwrap();
+            hands.iter().find(|(out, ..)| *out == card).map(|(_, rank, nut)| (rank.hand_type, *nut))
+        };
+        assert_eq!(hand_for("Th"), Some((HandType::StraightFlush, true)));
+        assert_eq!(hand_for("Ts"), Some((HandType::Straight, true)));
+        assert_eq!(hand_for("5c"), Some((HandType::Straight, true)));
+    }
+
+    #[test]
+    fn test_out_hands_omaha_uses_two_hole_cards() {
+        let hole = cards("Ah 5h Kc Kd");
+        let board = cards("Qh 9h 3c");
+        let analysis = analyze_draws_omaha(&omaha("Ah 5h Kc Kd"), &board, &[]).unwrap();
+        let hands = analysis.flush_draws[0].out_hands(&hole, &board).unwrap();
+        assert!(hands.iter().all(|(_, rank, _)| rank.hand_type == HandType::Flush));
+        // Ah5h must play both hearts, so a bigger kicker (or a straight
+        // flush) is always possible with two more hearts on board
+        assert!(hands.iter().all(|(_, _, is_nut)| !is_nut));
+    }
+
+    #[test]
+    fn test_backdoor_flush_completion_probability() {
+        let analysis = analyze_draws(&cards("Ah 7h"), &cards("Kh 9c 2d"), &[]).unwrap();
+        let draw = &analysis.flush_draws[0];
+        assert_eq!(draw.draw_type(), DrawType::BackdoorFlush);
+
+        // C(10, 2) / C(47, 2) = 45 / 1081 ~ 4.2%
+        assert!((draw.completion_probability(2) - 45.0 / 1081.0).abs() < 1e-12);
+        assert!(draw.completion_probability(1).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_flush_draw_completion_probability() {
+        let analysis = analyze_draws(&cards("Ah 7h"), &cards("Kh 9h 2d"), &[]).unwrap();
+        let draw = &analysis.flush_draws[0];
+        assert_eq!(draw.draw_type(), DrawType::FlushDraw);
+
+        // 9 outs: 1 - (38/47)(37/46) ~ 35.0% by the river, 9/46 on the turn
+        let two_cards = 1.0 - (38.0 * 37.0) / (47.0 * 46.0);
+        assert!((draw.completion_probability(2) - two_cards).abs() < 1e-12);
+        assert!((draw.completion_probability(1) - 9.0 / 46.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_straight_completion_probability() {
+        let analysis = analyze_draws(&cards("9c 8d"), &cards("7h 2s Kc"), &[]).unwrap();
+        let backdoor = analysis
+            .straight_draws
+            .iter()
+            .find(|d| d.draw_type == DrawType::BackdoorStraight)
+            .unwrap();
+        // One of each needed rank: 4 * 4 / C(47, 2) ~ 1.5%
+        assert!((backdoor.completion_probability(2) - 16.0 / 1081.0).abs() < 1e-12);
+        assert!(backdoor.completion_probability(1).abs() < f64::EPSILON);
+
+        let analysis = analyze_draws(&cards("9c 8d"), &cards("7h 6s Kc"), &[]).unwrap();
+        let oesd = analysis
+            .straight_draws
+            .iter()
+            .find(|d| d.draw_type == DrawType::OpenEnded)
+            .unwrap();
+        assert!((oesd.completion_probability(1) - 8.0 / 46.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_redraw_risk_non_nut_flush() {
+        let hero = cards("9h 8h");
+        let flop = cards("Kh 7h 2c");
+
+        // Villain's lone ace of hearts picks up the nut flush draw whenever
+        // hero's flush comes in on the turn
+        let ace = combo("Ah Qd");
+        let risk = redraw_risk(&hero, &flop, &ace).unwrap();
+        // 7 hearts left out of 44 rivers, minus rare straight flushes for hero
+        assert!(risk > 0.12 && risk <= 7.0 / 44.0, "risk {risk}");
+
+        // Ace-jack without a heart can never beat hero's flush
+        let no_redraw = combo("Ac Jd");
+        assert!(redraw_risk(&hero, &flop, &no_redraw).unwrap() < f64::EPSILON);
+
+        // A set redraws to a full house when the board pairs
+        let set = combo("Kc Kd");
+        let risk = redraw_risk(&hero, &flop, &set).unwrap();
+        assert!(risk > 0.2 && risk < 0.25, "risk {risk}");
+    }
+
+    #[test]
+    fn test_redraw_risk_errors() {
+        let range = combo("Qc Qd");
+        // A turn board has no card left to redraw on
+        assert!(matches!(
+            redraw_risk(&cards("9h 8h"), &cards("Kh 7h 2c 3d"), &range),
+            Err(HoldemError::InvalidCardCount { got: 4, .. })
+        ));
+        // No draw: hero never "hits"
+        assert!(matches!(
+            redraw_risk(&cards("Ac 2d"), &cards("Kh 7h 9s"), &range),
+            Err(HoldemError::NoValidCombinations)
+        ));
+    }
+
+    #[test]
+    fn test_outs_vs_set() {
+        let card = |s: &str| Card::parse(s).unwrap();
+        let hero = (card("Ah"), card("Kh"));
+        let villain = (card("5s"), card("5d"));
+        let board = cards("9h 8c 5h");
+
+        // Nine hearts are unseen, but the 8h pairs the board and fills the set
+        let outs = outs_vs(hero, villain, &board).unwrap();
+        assert_eq!(outs.len(), 8, "{outs:?}");
+        assert!(outs.iter().all(|c| c.suit == Suit::Hearts));
+        assert!(!outs.contains(&card("8h")));
+
+        // Against an overpair every heart is live, and so are the overcards
+        let outs = outs_vs(hero, (card("Ts"), card("Td")), &board).unwrap();
+        assert!(outs.contains(&card("8h")) && outs.contains(&card("As")));
+
+        assert!(matches!(
+            outs_vs(hero, (card("Ah"), card("2c")), &board),
+            Err(HoldemError::DuplicateCard(_))
+        ));
+        assert!(outs_vs(hero, villain, &cards("9h 8c 5h 2c 3d")).is_err());
+    }
+
+    #[test]
+    fn test_analyze_draws_full_pair_outs() {
+        let card = |s: &str| Card::parse(s).unwrap();
+
+        // Pair of nines: two trips outs, three aces pair the kicker. Pairing
+        // the king or deuce on board does not count.
+        let analysis = analyze_draws_full(&cards("Ah 9c"), &cards("9d Ks 2h"), &[]).unwrap();
+        assert_eq!(analysis.improvement_draws.len(), 2);
+        let two_pair = &analysis.improvement_draws[0];
+        assert_eq!((two_pair.current, two_pair.improved), (HandType::OnePair, HandType::TwoPair));
+        assert_eq!(two_pair.outs, cards("Ac Ad As"));
+        let trips = &analysis.improvement_draws[1];
+        assert_eq!(trips.improved, HandType::ThreeOfAKind);
+        assert_eq!(trips.outs, cards("9h 9s"));
+        assert_eq!(analysis.total_equity_outs, 5);
+
+        // With a flush draw on the turn the 9h makes a flush, so it is a
+        // flush out only and every out is counted once
+        let analysis = analyze_draws_full(&cards("Ah 9c"), &cards("9d Kh 2h 5h"), &[]).unwrap();
+        assert_eq!(analysis.total_outs, 9);
+        let trips = analysis
+            .improvement_draws
+            .iter()
+            .find(|d| d.improved == HandType::ThreeOfAKind)
+            .unwrap();
+        assert_eq!(trips.outs, vec![card("9s")]);
+        assert_eq!(analysis.total_equity_outs, 9 + 1 + 3);
+
+        // A set fills up by pairing the board and has one quads out
+        let analysis = analyze_draws_full(&cards("7c 7d"), &cards("7h Ks 2d"), &[]).unwrap();
+        let boat = &analysis.improvement_draws[0];
+        assert_eq!(boat.improved, HandType::FullHouse);
+        assert_eq!(boat.out_count(), 6);
+        assert_eq!(analysis.improvement_draws[1].outs, vec![card("7s")]);
+
+        // No made hand, no improvement outs; plain analysis leaves them empty
+        let analysis = analyze_draws_full(&cards("Ah Qc"), &cards("9d Ks 2h"), &[]).unwrap();
+        assert!(analysis.improvement_draws.is_empty());
+        let plain = analyze_draws(&cards("Ah 9c"), &cards("9d Ks 2h"), &[]).unwrap();
+        assert!(plain.improvement_draws.is_empty());
+        assert_eq!(plain.total_equity_outs, plain.total_outs);
+    }
+
     #[test]
     fn test_flush_draw() {
         let hole = cards("Ah 9h");
@@ -622,6 +1463,36 @@ mod tests {
         assert_eq!(analysis.flush_draws[0].draw_type(), DrawType::BackdoorFlush);
     }
 
+    #[test]
+    fn test_preflop_suited_hole_cards() {
+        // Not reported by default, so preflop totals stay untouched
+        let analysis = analyze_draws(&cards("Ah 9h"), &[], &[]).unwrap();
+        assert!(analysis.flush_draws.is_empty());
+        assert_eq!(analysis.total_outs, 0);
+        assert!(!analysis.has_draw());
+
+        let draw = preflop_flush_potential(&cards("Ah 9h"), &[]).unwrap().unwrap();
+        assert_eq!(draw.draw_type(), DrawType::BackdoorFlush);
+        assert_eq!(draw.cards_held, 2);
+        assert_eq!(draw.out_count(), 11);
+        // Three of the 11 hearts among 5 board cards from 50: ~6.4%
+        assert!((draw.completion_probability(5) - 0.063_998).abs() < 1e-5);
+
+        assert!(preflop_flush_potential(&cards("Ah 9c"), &[]).unwrap().is_none());
+        let dead = preflop_flush_potential(&cards("Ah 9h"), &cards("Kh")).unwrap().unwrap();
+        assert_eq!(dead.out_count(), 10);
+    }
+
+    #[test]
+    fn test_no_backdoor_flush_on_turn() {
+        let analysis = analyze_draws(&cards("Ah 9h"), &cards("Kh 5c 2c 7d"), &[]).unwrap();
+        assert!(analysis.flush_draws.is_empty());
+
+        let analysis = analyze_draws(&cards("Ah 9h"), &cards("Kh 5h 2c 7d"), &[]).unwrap();
+        assert_eq!(analysis.flush_draws.len(), 1);
+        assert_eq!(analysis.flush_draws[0].draw_type(), DrawType::FlushDraw);
+    }
+
     #[test]
     fn test_open_ended_straight() {
         let hole = cards("9h 8c");
@@ -638,6 +1509,76 @@ mod tests {
         assert!(!oesd.is_empty());
     }
 
+    fn one_card_draws(hole: &str, board: &str) -> Vec<StraightDraw> {
+        analyze_draws(&cards(hole), &cards(board), &[])
+            .unwrap()
+            .straight_draws
+            .into_iter()
+            .filter(|d| d.draw_type != DrawType::BackdoorStraight)
+            .collect()
+    }
+
+    #[test]
+    fn test_middle_oesd_is_one_eight_out_draw() {
+        let draws = one_card_draws("9h 8c", "7d 6s 2h");
+        assert_eq!(draws.len(), 1);
+        assert_eq!(draws[0].draw_type, DrawType::OpenEnded);
+        assert_eq!(draws[0].needed_ranks, vec![5, 10]);
+        assert_eq!(draws[0].out_count(), 8);
+        assert_eq!(count_straight_outs(&cards("9h 8c"), &cards("7d 6s 2h")).unwrap(), 8);
+        let analysis = analyze_draws(&cards("9h 8c"), &cards("7d 6s 2h"), &[]).unwrap();
+        assert_eq!(analysis.total_outs, 8);
+    }
+
+    #[test]
+    fn test_double_gutshot_consolidated() {
+        // 9-7-6-5-3: a four or an eight completes a straight
+        let draws = one_card_draws("9c 7d", "6h 5s 3c");
+        assert_eq!(draws.len(), 1);
+        assert_eq!(draws[0].draw_type, DrawType::DoubleGutshot);
+        assert_eq!(draws[0].needed_ranks, vec![4, 8]);
+        assert_eq!(draws[0].out_count(), 8);
+
+        // 3-5-6-8 has two gaps but no single card makes a straight
+        assert!(one_card_draws("3c 5d", "6h 8s Kc").is_empty());
+
+        let analysis = analyze_draws(&cards("Tc 8d"), &cards("7h 6s 4c 2d"), &[]).unwrap();
+        assert_eq!(analysis.total_outs, 8);
+    }
+
+    #[test]
+    fn test_edge_runs_are_one_way() {
+        // A-2-3-4 only completes with a five
+        let draws = one_card_draws("Ac 2d", "3h 4s Kc");
+        assert_eq!(draws.len(), 1);
+        assert_eq!(draws[0].draw_type, DrawType::Gutshot);
+        assert_eq!(draws[0].needed_ranks, vec![5]);
+
+        // J-Q-K-A only completes with a ten
+        let draws = one_card_draws("Jc Qd", "Kh As 2c");
+        assert_eq!(draws.len(), 1);
+        assert_eq!(draws[0].draw_type, DrawType::Gutshot);
+        assert_eq!(draws[0].needed_ranks, vec![10]);
+        assert!(draws[0].is_nut);
+    }
+
+    #[test]
+    fn test_runs_next_to_the_edges_are_open_ended() {
+        // 2-3-4-5 completes with an ace (wheel) or a six
+        let draws = one_card_draws("2c 3d", "4h 5s Kc");
+        assert_eq!(draws.len(), 1);
+        assert_eq!(draws[0].draw_type, DrawType::OpenEnded);
+        assert_eq!(draws[0].needed_ranks, vec![14, 6]);
+        assert_eq!(draws[0].out_count(), 8);
+
+        // T-J-Q-K completes with a nine or an ace
+        let draws = one_card_draws("Jc Qd", "Kh Ts 2c");
+        assert_eq!(draws.len(), 1);
+        assert_eq!(draws[0].draw_type, DrawType::OpenEnded);
+        assert_eq!(draws[0].needed_ranks, vec![9, 14]);
+        assert_eq!(draws[0].out_count(), 8);
+    }
+
     #[test]
     fn test_gutshot() {
         let hole = cards("Ah Kc");
@@ -695,6 +1636,40 @@ mod tests {
 
         let has_wheel_draw = analysis.straight_draws.iter().any(|d| d.high_card == 5);
         assert!(has_wheel_draw);
+
+        let draws = one_card_draws("Ah 2c", "4d 3s Kh");
+        assert_eq!(draws[0].high_card, 5);
+        assert_eq!(draws[0].needed_ranks, vec![5]);
+        assert_eq!(draws[0].out_count(), 4);
+
+        // A missing low card is labeled by rank; a missing ace is labeled 14
+        let backdoor = analyze_draws(&cards("Ah 2c"), &cards("3d 9s Kh"), &[]).unwrap();
+        let wheel = backdoor.straight_draws.iter().find(|d| d.high_card == 5).unwrap();
+        assert_eq!(wheel.draw_type, DrawType::BackdoorStraight);
+        assert_eq!(wheel.needed_ranks, vec![4, 5]);
+        let backdoor = analyze_draws(&cards("2c 3d"), &cards("4s 9h Kh"), &[]).unwrap();
+        let wheel = backdoor.straight_draws.iter().find(|d| d.high_card == 5).unwrap();
+        assert_eq!(wheel.needed_ranks, vec![14, 5]);
+    }
+
+    #[test]
+    fn test_wheel_draw_outs_make_a_wheel() {
+        let spots = [("Ah 2c", "3d 4s 9h"), ("2c 3d", "4h 5s Kc"), ("Ac 2d", "3h 5s Kc")];
+        for (hole, board) in spots {
+            for draw in one_card_draws(hole, board) {
+                for out in &draw.outs {
+                    let mut hand = cards(hole);
+                    hand.extend(cards(board));
+                    hand.push(*out);
+                    let rank = evaluate_hand(&hand).unwrap();
+                    assert_eq!(rank.hand_type, HandType::Straight, "{hole} {board} + {out}");
+                    if out.rank == Rank::Ace {
+                        // The ace plays low
+                        assert_eq!(rank.primary_ranks, vec![5], "{hole} {board} + {out}");
+                    }
+                }
+            }
+        }
     }
 
     #[test]
@@ -800,7 +1775,7 @@ mod tests {
         // Double gutshot should not be detected on river (no more cards)
         let hole = cards("Th 7c");
         // River: 5 board cards
-        let board = cards("8d 5s 2h 3c Ks");
+        let board = cards("8d 6s 2h 4c Ks");
 
         let analysis = analyze_draws(&hole, &board, &[]).unwrap();
 
@@ -818,11 +1793,11 @@ mod tests {
         // Double gutshot should still be detected on turn (1 card to come)
         let hole = cards("Th 7c");
         // Turn: 4 board cards
-        let board = cards("8d 5s 2h 3c");
+        let board = cards("8d 6s 2h 4c");
 
         let analysis = analyze_draws(&hole, &board, &[]).unwrap();
 
-        // 5-7-8-T needs 6 or 9 - should still detect
+        // 4-6-7-8-T needs 5 or 9 - should still detect
         let double_gs: Vec<_> = analysis
             .straight_draws
             .iter()
@@ -830,4 +1805,30 @@ mod tests {
             .collect();
         assert!(!double_gs.is_empty());
     }
+
+    #[test]
+    fn test_completing_combos_on_four_straight() {
+        let (straights, flushes) = completing_combos(&cards("6c 7d 8h 9s")).unwrap();
+
+        // Any hand holding a five or a ten: C(48,2) - C(40,2)
+        assert_eq!(straights.len(), 348);
+        assert!(straights.iter().all(|&(c1, c2)| {
+            [c1, c2].iter().any(|c| matches!(c.rank, Rank::Five | Rank::Ten))
+        }));
+        assert!(straights.contains(&(cards("Tc")[0], cards("Jc")[0])));
+        assert!(flushes.is_empty());
+    }
+
+    #[test]
+    fn test_completing_combos_flush_and_river() {
+        // Two more hearts complete the flush: C(10,2)
+        let (_, flushes) = completing_combos(&cards("Ah 8h 3h 2c")).unwrap();
+        assert_eq!(flushes.len(), 45);
+        assert!(flushes.iter().all(|(c1, c2)| c1.suit == Suit::Hearts && c2.suit == Suit::Hearts));
+
+        // Every straight on A-2-3-5-K needs a four: C(47,2) - C(43,2)
+        let (straights, _) = completing_combos(&cards("Ac 2d 3h 5s Kd")).unwrap();
+        assert_eq!(straights.len(), 178);
+        assert!(completing_combos(&cards("Ac 2d")).is_err());
+    }
 }
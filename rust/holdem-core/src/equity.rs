@@ -3,13 +3,23 @@
 //! Calculates the probability of each player winning a hand by simulating
 //! random runouts multiple times.
 
-use crate::card::{Card, FULL_DECK};
+use crate::canonize::{
+    canonize_hole_cards, get_all_canonical_hands, get_all_combos, get_combos_excluding,
+    relabeled_indices, suit_permutations, CanonicalHand,
+};
+use crate::card::{Card, Rank, FULL_DECK};
 use crate::error::{HoldemError, HoldemResult};
-use crate::evaluator::find_winners;
-use crate::range::{hands_are_disjoint, CardDistribution, Odometer};
-use rand::prelude::*;
+use crate::evaluator::{
+    evaluate_hand, find_winners, find_winners_detailed, hand_score, HandRank, HandType,
+    PlayerResult,
+};
+use crate::range::{hands_are_disjoint, CardDistribution, Odometer, RangeError, WeightedSampler};
+use rand::RngCore;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+pub use crate::rng::{derive_seed, DeterministicRng};
 
 // std::time::Instant is not available in WASM, so we skip timing there
 // The WASM binding layer (holdem-wasm) handles timing with js_sys::Date
@@ -20,11 +30,18 @@ use std::time::Instant;
 ///
 /// - If cards is Some: uses the specific 2 cards
 /// - If is_random is true: random hand sampled each simulation
+/// - If range is non-empty: any combo from the range (e.g. `["QQ+", "AKs"]`)
+/// - If `sampled_range` is set: one combo drawn from it each simulation
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PlayerHand {
     pub cards: Vec<Card>,
     #[serde(default)]
     pub is_random: bool,
+    #[serde(default)]
+    pub range: Vec<String>,
+    /// Distribution sampled per simulation (see `from_range`)
+    #[serde(default)]
+    pub sampled_range: Option<CardDistribution>,
 }
 
 impl PlayerHand {
@@ -50,6 +67,8 @@ impl PlayerHand {
         Ok(Self {
             cards,
             is_random: false,
+            range: Vec::new(),
+            sampled_range: None,
         })
     }
 
@@ -59,9 +78,49 @@ impl PlayerHand {
         Self {
             cards: Vec::new(),
             is_random: true,
+            range: Vec::new(),
+            sampled_range: None,
+        }
+    }
+
+    /// Create a player holding any combo from a range (see `validate_range`)
+    #[must_use]
+    pub fn range(tokens: Vec<String>) -> Self {
+        Self {
+            cards: Vec::new(),
+            is_random: false,
+            range: tokens,
+            sampled_range: None,
+        }
+    }
+
+    /// Create a player whose hole cards are drawn from `dist` each simulation
+    ///
+    /// Unlike `range`, the combos are sampled rather than enumerated, which
+    /// gives a fast Monte Carlo approximation of the range's equity. Draws
+    /// that clash with another player's cards are rejected and redrawn.
+    #[must_use]
+    pub fn from_range(dist: CardDistribution) -> Self {
+        Self {
+            cards: Vec::new(),
+            is_random: false,
+            range: Vec::new(),
+            sampled_range: Some(dist),
         }
     }
 
+    /// Whether this player is given by a range rather than cards
+    #[must_use]
+    pub fn is_range(&self) -> bool {
+        !self.range.is_empty()
+    }
+
+    /// Whether this player's cards are sampled from a distribution
+    #[must_use]
+    pub fn is_sampled(&self) -> bool {
+        self.sampled_range.is_some()
+    }
+
     /// Parse from string notation (e.g., "Ah Kh")
     pub fn parse(s: &str) -> Result<Self, crate::card::ParseError> {
         let cards = crate::card::parse_cards(s)?;
@@ -91,6 +150,10 @@ pub struct PlayerEquity {
     pub hand_description: String,
     /// Number of combos (for range-based hands)
     pub combos: usize,
+    /// How often the player finished with each `HandType` (indexed by
+    /// `HandType as usize`), when requested via `with_hand_distribution`
+    #[serde(default)]
+    pub hand_type_frequencies: Option<[f64; 10]>,
 }
 
 /// Request for equity calculation
@@ -108,12 +171,107 @@ pub struct EquityRequest {
     pub num_simulations: u32,
     /// Random seed for reproducibility
     pub seed: Option<u64>,
+    /// Groups of player indices whose results are also tallied as a team
+    #[serde(default)]
+    pub teams: Vec<Vec<usize>>,
+    /// Tally each player's final hand type
+    #[serde(default)]
+    pub hand_distribution: bool,
+    /// Stop early once every player's 95% confidence half-width is at most
+    /// this; `num_simulations` is then the cap
+    #[serde(default)]
+    pub target_precision: Option<f64>,
 }
 
 fn default_simulations() -> u32 {
     10_000
 }
 
+/// Flatten a street-by-street board into the flat board used by requests
+///
+/// # Errors
+/// Returns an error if a later street is given without the earlier ones
+/// (turn without flop, river without turn).
+pub fn board_from_streets(
+    flop: Option<[Card; 3]>,
+    turn: Option<Card>,
+    river: Option<Card>,
+) -> HoldemResult<Vec<Card>> {
+    if river.is_some() && turn.is_none() {
+        return Err(HoldemError::InvalidBoard("river given without a turn"));
+    }
+    if turn.is_some() && flop.is_none() {
+        return Err(HoldemError::InvalidBoard("turn given without a flop"));
+    }
+
+    let mut board = Vec::with_capacity(5);
+    if let Some(flop) = flop {
+        board.extend(flop);
+    }
+    board.extend(turn);
+    board.extend(river);
+    Ok(board)
+}
+
+/// Pick the board from either a flat list or the individual streets
+///
+/// Request inputs accept the board as a flat list or as `flop`/`turn`/`river`
+/// fields. With no street given the flat list is used as is; otherwise the
+/// flat list must be empty and the streets are flattened with
+/// `board_from_streets`.
+///
+/// # Errors
+/// Returns an error if both forms are given, or the streets are out of order.
+pub fn resolve_board(
+    board: &[Card],
+    flop: Option<[Card; 3]>,
+    turn: Option<Card>,
+    river: Option<Card>,
+) -> HoldemResult<Vec<Card>> {
+    if flop.is_none() && turn.is_none() && river.is_none() {
+        return Ok(board.to_vec());
+    }
+    if !board.is_empty() {
+        return Err(HoldemError::InvalidBoard(
+            "specify either board or flop/turn/river, not both",
+        ));
+    }
+    board_from_streets(flop, turn, river)
+}
+
+/// Round `value` half away from zero to `decimals` places
+///
+/// Used by the bindings to trim equities and rates for display. Values are
+/// returned unchanged when `decimals` is too large to scale by.
+#[must_use]
+pub fn round_decimals(value: f64, decimals: u32) -> f64 {
+    let scale = 10f64.powf(f64::from(decimals));
+    if scale.is_finite() {
+        (value * scale).round() / scale
+    } else {
+        value
+    }
+}
+
+/// Check that every player's hole cards, a full board and the dead cards
+/// fit in one deck, so dealing a runout can never run out of cards
+fn check_deck_size(num_players: usize, num_dead: usize) -> HoldemResult<()> {
+    let needed = 2 * num_players + 5 + num_dead;
+    if needed > FULL_DECK.len() {
+        return Err(HoldemError::TooManyCards {
+            needed,
+            available: FULL_DECK.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Hole-card combos a random player can hold once `known` cards are out
+fn live_combos(known: usize) -> usize {
+    let live = FULL_DECK.len().saturating_sub(known);
+    live * live.saturating_sub(1) / 2
+}
+
 fn validate_equity_request(request: &EquityRequest) -> HoldemResult<()> {
     if request.players.len() < 2 {
         return Err(HoldemError::NotEnoughPlayers(2));
@@ -121,8 +279,19 @@ fn validate_equity_request(request: &EquityRequest) -> HoldemResult<()> {
     if request.board.len() > 5 {
         return Err(HoldemError::BoardTooLarge(request.board.len()));
     }
+    check_deck_size(request.players.len(), request.dead_cards.len())?;
 
     for player in &request.players {
+        if player.is_random && player.is_range() {
+            return Err(HoldemError::InvalidRange(
+                "a random player cannot also have a range".to_string(),
+            ));
+        }
+        if player.is_sampled() && (player.is_random || player.is_range()) {
+            return Err(HoldemError::InvalidRange(
+                "a sampled range player cannot also be random or have range tokens".to_string(),
+            ));
+        }
         if player.is_random {
             if !player.cards.is_empty() {
                 return Err(HoldemError::InvalidCardCount {
@@ -130,6 +299,13 @@ fn validate_equity_request(request: &EquityRequest) -> HoldemResult<()> {
                     got: player.cards.len(),
                 });
             }
+        } else if player.is_range() || player.is_sampled() {
+            if !player.cards.is_empty() {
+                return Err(HoldemError::InvalidCardCount {
+                    expected: "0 (range player)",
+                    got: player.cards.len(),
+                });
+            }
         } else if player.cards.len() != 2 {
             return Err(HoldemError::InvalidCardCount {
                 expected: "2",
@@ -158,6 +334,19 @@ fn validate_equity_request(request: &EquityRequest) -> HoldemResult<()> {
             }
         }
     }
+
+    for team in &request.teams {
+        for (i, &member) in team.iter().enumerate() {
+            if member >= request.players.len() || team[..i].contains(&member) {
+                return Err(HoldemError::InvalidTeamMember(member));
+            }
+        }
+    }
+    if let Some(epsilon) = request.target_precision
+        && !(epsilon.is_finite() && epsilon > 0.0)
+    {
+        return Err(HoldemError::InvalidPrecision(epsilon));
+    }
     Ok(())
 }
 
@@ -171,9 +360,26 @@ impl EquityRequest {
             dead_cards: Vec::new(),
             num_simulations: default_simulations(),
             seed: None,
+            teams: Vec::new(),
+            hand_distribution: false,
+            target_precision: None,
         }
     }
 
+    /// Create a new equity request with the board given street by street
+    ///
+    /// # Errors
+    /// Returns an error if the river is given without the turn.
+    pub fn from_streets(
+        players: Vec<PlayerHand>,
+        flop: [Card; 3],
+        turn: Option<Card>,
+        river: Option<Card>,
+    ) -> HoldemResult<Self> {
+        let board = board_from_streets(Some(flop), turn, river)?;
+        Ok(Self::new(players, board))
+    }
+
     /// Set number of simulations
     #[must_use]
     pub fn with_simulations(mut self, n: u32) -> Self {
@@ -194,17 +400,301 @@ impl EquityRequest {
         self.dead_cards = dead;
         self
     }
+
+    /// Add `k` random opponents after the existing players
+    #[must_use]
+    pub fn with_random_opponents(mut self, k: usize) -> Self {
+        self.players.extend(std::iter::repeat_with(PlayerHand::random).take(k));
+        self
+    }
+
+    /// Set teams of player indices to tally alongside individual results
+    #[must_use]
+    pub fn with_teams(mut self, teams: Vec<Vec<usize>>) -> Self {
+        self.teams = teams;
+        self
+    }
+
+    /// Enable per-player hand type frequencies in the result
+    #[must_use]
+    pub fn with_hand_distribution(mut self, enabled: bool) -> Self {
+        self.hand_distribution = enabled;
+        self
+    }
+
+    /// Run in batches and stop once every player's equity is known to
+    /// within `epsilon` (95% confidence half-width), or after
+    /// `num_simulations`, whichever comes first
+    ///
+    /// Close spots need more simulations than lopsided ones for the same
+    /// precision. Ignored when a player is a range.
+    #[must_use]
+    pub fn with_target_precision(mut self, epsilon: f64) -> Self {
+        self.target_precision = Some(epsilon);
+        self
+    }
+
+    /// Start building a request that is validated by `build()`
+    #[must_use]
+    pub fn builder() -> EquityRequestBuilder {
+        EquityRequestBuilder::new()
+    }
+}
+
+/// Builder for `EquityRequest` that validates eagerly
+///
+/// Unlike `EquityRequest::new`, card conflicts and bad card counts are
+/// reported by `build()` rather than later by `calculate_equity`.
+#[derive(Clone, Debug)]
+pub struct EquityRequestBuilder {
+    request: EquityRequest,
+}
+
+impl EquityRequestBuilder {
+    /// Create a builder with no players and an empty board
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            request: EquityRequest::new(Vec::new(), Vec::new()),
+        }
+    }
+
+    /// Add a player
+    #[must_use]
+    pub fn player(mut self, player: PlayerHand) -> Self {
+        self.request.players.push(player);
+        self
+    }
+
+    /// Set community cards (0-5)
+    #[must_use]
+    pub fn board(mut self, board: Vec<Card>) -> Self {
+        self.request.board = board;
+        self
+    }
+
+    /// Set dead cards
+    #[must_use]
+    pub fn dead(mut self, dead: Vec<Card>) -> Self {
+        self.request.dead_cards = dead;
+        self
+    }
+
+    /// Set number of simulations
+    #[must_use]
+    pub fn simulations(mut self, n: u32) -> Self {
+        self.request.num_simulations = n;
+        self
+    }
+
+    /// Set random seed
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.request.seed = Some(seed);
+        self
+    }
+
+    /// Validate and return the request
+    ///
+    /// # Errors
+    /// Returns the same validation errors as `calculate_equity`: fewer than
+    /// 2 players, more than 5 board cards, duplicate cards or a bad hand.
+    pub fn build(self) -> HoldemResult<EquityRequest> {
+        validate_equity_request(&self.request)?;
+        Ok(self.request)
+    }
+}
+
+impl Default for EquityRequestBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Result of equity calculation
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EquityResult {
-    /// Equity for each player
+    /// Equity for each player, one entry per request player in request
+    /// order (`players[i].index == i`), including players with zero equity
     pub players: Vec<PlayerEquity>,
     /// Total simulations run
     pub total_simulations: u64,
     /// Elapsed time in milliseconds
     pub elapsed_ms: f64,
+    /// Results for each team in `EquityRequest::teams`
+    #[serde(default)]
+    pub teams: Vec<TeamEquity>,
+}
+
+impl EquityResult {
+    /// Combined equity of a group of players
+    ///
+    /// Pot shares are additive, so this is the sum of the members' equities
+    /// and matches the simulated `TeamEquity::equity` for the same team.
+    /// Out-of-range indices are ignored.
+    #[must_use]
+    pub fn team_equity(&self, indices: &[usize]) -> f64 {
+        indices
+            .iter()
+            .filter_map(|&i| self.players.get(i))
+            .map(|p| p.equity)
+            .sum()
+    }
+
+    /// Pool this result with another batch run for the same request
+    ///
+    /// Win and tie counts and simulation totals add up, rates are recomputed
+    /// from the pooled counts, and equities (including team equities and hand
+    /// type frequencies) are averaged weighted by each batch's simulations.
+    /// Players and teams are paired by position; descriptions and combo
+    /// counts are taken from `self`, and elapsed times add up.
+    ///
+    /// # Errors
+    /// Returns `MismatchedResults` if the two results have different numbers
+    /// of players or teams, different team members, or only one of them
+    /// tracked hand type frequencies.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn merge(&self, other: &EquityResult) -> HoldemResult<EquityResult> {
+        if self.players.len() != other.players.len() {
+            return Err(HoldemError::MismatchedResults("different numbers of players"));
+        }
+        if self.teams.len() != other.teams.len()
+            || self.teams.iter().zip(&other.teams).any(|(a, b)| a.members != b.members)
+        {
+            return Err(HoldemError::MismatchedResults("different teams"));
+        }
+        if self
+            .players
+            .iter()
+            .zip(&other.players)
+            .any(|(a, b)| a.hand_type_frequencies.is_some() != b.hand_type_frequencies.is_some())
+        {
+            return Err(HoldemError::MismatchedResults(
+                "hand type frequencies tracked in only one result",
+            ));
+        }
+
+        let weighted = |a: f64, na: u64, b: f64, nb: u64| {
+            let total = na + nb;
+            if total == 0 {
+                0.0
+            } else {
+                (a * na as f64 + b * nb as f64) / total as f64
+            }
+        };
+        let rate = |count: u64, total: u64| {
+            if total == 0 {
+                0.0
+            } else {
+                count as f64 / total as f64
+            }
+        };
+
+        let players = self
+            .players
+            .iter()
+            .zip(&other.players)
+            .map(|(a, b)| {
+                let (na, nb) = (a.total_simulations, b.total_simulations);
+                let total = na + nb;
+                let win_count = a.win_count + b.win_count;
+                let tie_count = a.tie_count + b.tie_count;
+                let hand_type_frequencies = a
+                    .hand_type_frequencies
+                    .zip(b.hand_type_frequencies)
+                    .map(|(fa, fb)| std::array::from_fn(|t| weighted(fa[t], na, fb[t], nb)));
+                PlayerEquity {
+                    win_count,
+                    tie_count,
+                    total_simulations: total,
+                    win_rate: rate(win_count, total),
+                    tie_rate: rate(tie_count, total),
+                    equity: weighted(a.equity, na, b.equity, nb),
+                    hand_type_frequencies,
+                    ..a.clone()
+                }
+            })
+            .collect();
+
+        let (na, nb) = (self.total_simulations, other.total_simulations);
+        let total_simulations = na + nb;
+        let teams = self
+            .teams
+            .iter()
+            .zip(&other.teams)
+            .map(|(a, b)| {
+                let win_count = a.win_count + b.win_count;
+                let tie_count = a.tie_count + b.tie_count;
+                TeamEquity {
+                    members: a.members.clone(),
+                    win_count,
+                    tie_count,
+                    win_rate: rate(win_count, total_simulations),
+                    tie_rate: rate(tie_count, total_simulations),
+                    equity: weighted(a.equity, na, b.equity, nb),
+                }
+            })
+            .collect();
+
+        Ok(EquityResult {
+            players,
+            total_simulations,
+            elapsed_ms: self.elapsed_ms + other.elapsed_ms,
+            teams,
+        })
+    }
+
+    /// Label a heads-up matchup by the favorite's equity
+    ///
+    /// Thresholds: below 55% is a `Coinflip`, below 65% a `SlightFavorite`,
+    /// below 80% `Dominated` and anything higher a `Crush`. Returns `None`
+    /// unless the result has exactly two players.
+    #[must_use]
+    pub fn classify_heads_up(&self) -> Option<MatchupClass> {
+        let [a, b] = self.players.as_slice() else {
+            return None;
+        };
+        let favorite = a.equity.max(b.equity);
+        Some(if favorite < 0.55 {
+            MatchupClass::Coinflip
+        } else if favorite < 0.65 {
+            MatchupClass::SlightFavorite
+        } else if favorite < 0.80 {
+            MatchupClass::Dominated
+        } else {
+            MatchupClass::Crush
+        })
+    }
+}
+
+/// How lopsided a heads-up matchup is (see `EquityResult::classify_heads_up`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchupClass {
+    /// Close to 50/50, e.g. a pair against two overcards
+    Coinflip,
+    /// One side is ahead but the other has plenty of outs
+    SlightFavorite,
+    /// The underdog is drawing thin, e.g. AK vs AQ
+    Dominated,
+    /// A big favorite, e.g. an overpair against an underpair
+    Crush,
+}
+
+/// Equity result for a team of players
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TeamEquity {
+    /// Player indices in the team
+    pub members: Vec<usize>,
+    /// Simulations where team members won the whole pot
+    pub win_count: u64,
+    /// Simulations where the team split the pot with other players
+    pub tie_count: u64,
+    /// Win rate (0.0 - 1.0)
+    pub win_rate: f64,
+    /// Tie rate (0.0 - 1.0)
+    pub tie_rate: f64,
+    /// Share of the pot won by any team member
+    pub equity: f64,
 }
 
 /// Internal accumulator for tracking equity during simulation
@@ -213,20 +703,39 @@ struct EquityAccumulator {
     wins: Vec<u64>,
     ties: Vec<u64>,
     equity_sum: Vec<f64>,
+    /// Sum of squared per-simulation equity, for the variance
+    equity_sq_sum: Vec<f64>,
+    teams: Vec<Vec<usize>>,
+    team_wins: Vec<u64>,
+    team_ties: Vec<u64>,
+    team_equity_sum: Vec<f64>,
+    hand_type_counts: Option<Vec<[u64; 10]>>,
+    combos: Vec<usize>,
     total: u64,
 }
 
 impl EquityAccumulator {
-    fn new(num_players: usize) -> Self {
+    /// `combos[i]` is the number of hole-card combinations player `i` can hold
+    fn new(combos: Vec<usize>, teams: Vec<Vec<usize>>, hand_distribution: bool) -> Self {
+        let num_players = combos.len();
+        let num_teams = teams.len();
         Self {
             num_players,
             wins: vec![0; num_players],
             ties: vec![0; num_players],
             equity_sum: vec![0.0; num_players],
+            equity_sq_sum: vec![0.0; num_players],
+            teams,
+            team_wins: vec![0; num_teams],
+            team_ties: vec![0; num_teams],
+            team_equity_sum: vec![0.0; num_teams],
+            hand_type_counts: hand_distribution.then(|| vec![[0; 10]; num_players]),
+            combos,
             total: 0,
         }
     }
 
+    #[allow(clippy::cast_precision_loss)]
     fn record(&mut self, winner_indices: &[usize]) {
         self.total += 1;
 
@@ -235,17 +744,62 @@ impl EquityAccumulator {
             let winner = winner_indices[0];
             self.wins[winner] += 1;
             self.equity_sum[winner] += 1.0;
+            self.equity_sq_sum[winner] += 1.0;
         } else {
             // Tie - split equity
             let share = 1.0 / winner_indices.len() as f64;
             for &idx in winner_indices {
                 self.ties[idx] += 1;
                 self.equity_sum[idx] += share;
+                self.equity_sq_sum[idx] += share * share;
+            }
+        }
+
+        // A team collects every share won by one of its members
+        for (t, members) in self.teams.iter().enumerate() {
+            let team_winners = winner_indices.iter().filter(|i| members.contains(i)).count();
+            if team_winners == 0 {
+                continue;
+            }
+            if team_winners == winner_indices.len() {
+                self.team_wins[t] += 1;
+            } else {
+                self.team_ties[t] += 1;
+            }
+            self.team_equity_sum[t] += team_winners as f64 / winner_indices.len() as f64;
+        }
+    }
+
+    /// Largest 95% confidence half-width of any player's equity so far
+    ///
+    /// Infinite until at least two simulations have run.
+    #[allow(clippy::cast_precision_loss)]
+    fn max_half_width(&self) -> f64 {
+        if self.total < 2 {
+            return f64::INFINITY;
+        }
+        let n = self.total as f64;
+        self.equity_sum
+            .iter()
+            .zip(&self.equity_sq_sum)
+            .map(|(sum, sq_sum)| {
+                let mean = sum / n;
+                let variance = (sq_sum / n - mean * mean).max(0.0);
+                1.96 * (variance / n).sqrt()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    fn record_hand_types(&mut self, results: &[PlayerResult]) {
+        if let Some(counts) = &mut self.hand_type_counts {
+            for result in results {
+                counts[result.index][result.rank.hand_type as usize] += 1;
             }
         }
     }
 
-    fn into_results(self, hand_descriptions: Vec<String>, elapsed_ms: f64) -> EquityResult {
+    #[allow(clippy::cast_precision_loss)]
+    fn to_results(&self, hand_descriptions: &[String], elapsed_ms: f64) -> EquityResult {
         let players: Vec<PlayerEquity> = (0..self.num_players)
             .map(|i| {
                 let win_rate = if self.total > 0 {
@@ -273,152 +827,691 @@ impl EquityAccumulator {
                     tie_rate,
                     equity,
                     hand_description: hand_descriptions.get(i).cloned().unwrap_or_default(),
-                    combos: 1, // Single hand, not range
+                    combos: self.combos[i],
+                    hand_type_frequencies: self.hand_type_counts.as_ref().map(|counts| {
+                        counts[i].map(|c| {
+                            if self.total > 0 {
+                                c as f64 / self.total as f64
+                            } else {
+                                0.0
+                            }
+                        })
+                    }),
                 }
             })
             .collect();
 
+        let rate = |count: f64| {
+            if self.total > 0 {
+                count / self.total as f64
+            } else {
+                0.0
+            }
+        };
+        let teams: Vec<TeamEquity> = self
+            .teams
+            .iter()
+            .enumerate()
+            .map(|(t, members)| TeamEquity {
+                members: members.clone(),
+                win_count: self.team_wins[t],
+                tie_count: self.team_ties[t],
+                win_rate: rate(self.team_wins[t] as f64),
+                tie_rate: rate(self.team_ties[t] as f64),
+                equity: rate(self.team_equity_sum[t]),
+            })
+            .collect();
+
         EquityResult {
             players,
             total_simulations: self.total,
             elapsed_ms,
+            teams,
         }
     }
 }
 
-/// Calculate equity for all players
+/// The cards left to deal in one simulation
 ///
-/// Supports both known hands and random players. Random players have their
-/// hole cards sampled from the remaining deck each simulation.
-///
-/// # Errors
-/// Returns an error if:
-/// - Fewer than 2 players
-/// - More than 5 board cards
-/// - Duplicate cards detected
-/// - Invalid player hand configuration
-pub fn calculate_equity(request: &EquityRequest) -> HoldemResult<EquityResult> {
-    validate_equity_request(request)?;
-
-    #[cfg(not(target_arch = "wasm32"))]
-    let start = Instant::now();
-
-    // Identify random vs known players
-    let random_player_indices: Vec<usize> = request
-        .players
-        .iter()
-        .enumerate()
-        .filter(|(_, p)| p.is_random)
-        .map(|(i, _)| i)
-        .collect();
+/// Every Monte Carlo loop shuffles the remaining deck with the caller's RNG
+/// and deals from the top, in call order. Keeping that in one place keeps
+/// the seeded card streams of all equity functions in step.
+struct Dealer {
+    cards: Vec<Card>,
+    next: usize,
+}
 
-    // Collect all known cards (board + known player hands + dead cards)
-    let mut known_cards: HashSet<Card> = HashSet::new();
-    for player in &request.players {
-        if !player.is_random {
-            for &card in &player.cards {
-                known_cards.insert(card);
-            }
-        }
+impl Dealer {
+    /// Dealer over `FULL_DECK` minus the known cards
+    fn excluding(known_cards: &HashSet<Card>) -> Self {
+        Self::new(FULL_DECK.iter().filter(|c| !known_cards.contains(c)).copied().collect())
     }
-    for &card in &request.board {
-        known_cards.insert(card);
+
+    fn new(cards: Vec<Card>) -> Self {
+        Self { cards, next: 0 }
     }
-    for &card in &request.dead_cards {
-        known_cards.insert(card);
+
+    /// Shuffle every card back in and start dealing from the top
+    fn reshuffle(&mut self, rng: &mut impl RngCore) {
+        crate::rng::shuffle(rng, &mut self.cards);
+        self.next = 0;
     }
 
-    // Build remaining deck
-    let remaining: Vec<Card> = FULL_DECK
-        .iter()
-        .filter(|c| !known_cards.contains(c))
-        .copied()
-        .collect();
+    /// Move `cards` behind the undealt part of the deck so they are never
+    /// dealt, keeping the shuffled order of the rest
+    fn set_aside(&mut self, cards: &[Card]) {
+        self.cards[self.next..].sort_by_key(|c| cards.contains(c));
+    }
 
-    let cards_needed_board = 5 - request.board.len();
-    let num_players = request.players.len();
+    /// Deal two hole cards
+    fn deal_hole(&mut self) -> Vec<Card> {
+        self.deal_runout(2).to_vec()
+    }
 
-    // Initialize RNG
-    let mut rng = match request.seed {
-        Some(seed) => StdRng::seed_from_u64(seed),
-        None => StdRng::from_os_rng(),
-    };
+    /// Deal `n` community cards
+    fn deal_runout(&mut self, n: usize) -> &[Card] {
+        let start = self.next;
+        self.next += n;
+        &self.cards[start..self.next]
+    }
+}
 
-    // Initialize accumulator
-    let mut acc = EquityAccumulator::new(num_players);
+/// Monte Carlo state for a single `EquityRequest`.
+///
+/// Holds the remaining deck and accumulator so the simulation can be run in
+/// one go (`calculate_equity`) or in steps (`EquitySession`). The caller
+/// supplies the RNG for each run. The request is borrowed for one-off runs
+/// and owned by sessions.
+struct EquitySimulation<'a> {
+    request: Cow<'a, EquityRequest>,
+    dealer: Dealer,
+    cards_needed_board: usize,
+    /// Per player: the sampled range, minus known cards, and its sampler
+    samplers: Vec<Option<(CardDistribution, WeightedSampler)>>,
+    acc: EquityAccumulator,
+    hand_descriptions: Vec<String>,
+}
 
-    // Hand descriptions
-    let hand_descriptions: Vec<String> = request
-        .players
-        .iter()
-        .map(|p| {
-            if p.is_random {
-                "(Random)".to_string()
-            } else {
-                p.cards
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<_>>()
-                    .join(" ")
+impl<'a> EquitySimulation<'a> {
+    /// Set up the simulation; the request must already be validated
+    ///
+    /// Fails with `PlayerHasNoCombos` if a sampled range is emptied by the
+    /// board and dead cards, or `RangeBlocked` if other players' cards empty it.
+    fn new(request: Cow<'a, EquityRequest>) -> HoldemResult<Self> {
+        // Collect all known cards (board + known player hands + dead cards)
+        let mut known_cards: HashSet<Card> = HashSet::new();
+        for player in &request.players {
+            if !player.is_random {
+                for &card in &player.cards {
+                    known_cards.insert(card);
+                }
+            }
+        }
+        let table_cards: HashSet<Card> =
+            request.board.iter().chain(&request.dead_cards).copied().collect();
+        known_cards.extend(&table_cards);
+
+        let mut samplers = Vec::with_capacity(request.players.len());
+        for (i, player) in request.players.iter().enumerate() {
+            let Some(dist) = &player.sampled_range else {
+                samplers.push(None);
+                continue;
+            };
+            let dist = dist.filter_excluding(&table_cards);
+            if dist.is_empty() {
+                return Err(HoldemError::PlayerHasNoCombos(i));
             }
+            let dist = dist.filter_excluding(&known_cards);
+            let sampler = WeightedSampler::from_distribution(&dist)
+                .map_err(|_| HoldemError::RangeBlocked(i))?;
+            samplers.push(Some((dist, sampler)));
+        }
+
+        // Hand descriptions
+        let hand_descriptions: Vec<String> = request
+            .players
+            .iter()
+            .zip(&samplers)
+            .map(|(p, sampler)| {
+                if p.is_random {
+                    "(Random)".to_string()
+                } else if let Some((dist, _)) = sampler {
+                    format!("{} combos", dist.len())
+                } else {
+                    p.cards
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                }
+            })
+            .collect();
+
+        // One combo for known cards, every live combo for random, the range
+        // size for sampled players
+        let random_combos = live_combos(known_cards.len());
+        let combos = request
+            .players
+            .iter()
+            .zip(&samplers)
+            .map(|(p, sampler)| match sampler {
+                Some((dist, _)) => dist.len(),
+                None if p.is_random => random_combos,
+                None => 1,
+            })
+            .collect();
+
+        Ok(Self {
+            dealer: Dealer::excluding(&known_cards),
+            cards_needed_board: 5 - request.board.len(),
+            samplers,
+            acc: EquityAccumulator::new(
+                combos,
+                request.teams.clone(),
+                request.hand_distribution,
+            ),
+            hand_descriptions,
+            request,
         })
-        .collect();
+    }
 
-    // Run simulations
-    let mut deck_remaining = remaining.clone();
+    /// Shuffle the deck and deal one simulation's hole cards
+    ///
+    /// Sampled players draw their combos together, and the whole set is
+    /// redrawn whenever two of them clash, so every conflict-free set is
+    /// drawn in proportion to its weight. Their cards are kept out of the
+    /// deck, and random players are then dealt from the top.
+    fn deal_holes(&mut self, rng: &mut impl RngCore) -> HoldemResult<Vec<Vec<Card>>> {
+        let mut drawn: Vec<Card> = Vec::new();
+        let mut sampled_holes: Vec<Option<Vec<Card>>> = Vec::new();
+        let mut dealt = false;
+        for _ in 0..MAX_DRAWS_PER_SAMPLE {
+            drawn.clear();
+            sampled_holes.clear();
+            let mut clash = false;
+            for entry in &self.samplers {
+                let Some((dist, sampler)) = entry else {
+                    sampled_holes.push(None);
+                    continue;
+                };
+                let (c1, c2) = dist.hands()[sampler.sample(rng)];
+                clash |= drawn.contains(&c1) || drawn.contains(&c2);
+                drawn.extend([c1, c2]);
+                sampled_holes.push(Some(vec![c1, c2]));
+            }
+            if !clash {
+                dealt = true;
+                break;
+            }
+        }
+        if !dealt {
+            return Err(HoldemError::NoValidCombinations);
+        }
 
-    for _ in 0..request.num_simulations {
-        // Shuffle remaining deck
-        deck_remaining.shuffle(&mut rng);
+        self.dealer.reshuffle(rng);
+        if !drawn.is_empty() {
+            self.dealer.set_aside(&drawn);
+        }
 
-        // Deal cards to random players first
-        let mut deck_idx = 0;
-        let mut sim_hole_cards: Vec<Vec<Card>> = Vec::with_capacity(num_players);
+        Ok(self
+            .request
+            .players
+            .iter()
+            .zip(sampled_holes)
+            .map(|(player, hole)| match hole {
+                Some(hole) => hole,
+                None if player.is_random => self.dealer.deal_hole(),
+                None => player.cards.clone(),
+            })
+            .collect())
+    }
 
-        for (i, player) in request.players.iter().enumerate() {
-            if random_player_indices.contains(&i) {
-                // Random player: deal from shuffled deck
-                sim_hole_cards.push(vec![deck_remaining[deck_idx], deck_remaining[deck_idx + 1]]);
-                deck_idx += 2;
-            } else {
-                // Known player: use their cards
-                sim_hole_cards.push(player.cards.clone());
+    /// Run up to `n` more simulations, stopping early once the request's
+    /// target precision is met
+    ///
+    /// Precision is checked every `PRECISION_BATCH` simulations counted from
+    /// the start, so splitting the work into several calls stops at the same
+    /// point as one call. Returns whether the target was met.
+    fn run_to_precision(&mut self, n: u32, rng: &mut impl RngCore) -> HoldemResult<bool> {
+        let Some(epsilon) = self.request.target_precision else {
+            self.run(n, rng)?;
+            return Ok(false);
+        };
+        let batch = u64::from(PRECISION_BATCH);
+        let mut remaining = n;
+        while remaining > 0 {
+            let to_check = batch - self.acc.total % batch;
+            let chunk = remaining.min(u32::try_from(to_check).unwrap_or(PRECISION_BATCH));
+            self.run(chunk, rng)?;
+            remaining -= chunk;
+            if self.acc.total.is_multiple_of(batch) && self.acc.max_half_width() <= epsilon {
+                return Ok(true);
             }
         }
+        Ok(false)
+    }
 
-        // Deal community cards
-        let runout: Vec<Card> = deck_remaining[deck_idx..deck_idx + cards_needed_board].to_vec();
+    /// Run `n` more simulations, adding them to the accumulator
+    ///
+    /// Fails instead of panicking if a dealt hand is not 5-7 cards, which a
+    /// validated request never produces, or if sampled ranges keep
+    /// clashing with each other.
+    fn run(&mut self, n: u32, rng: &mut impl RngCore) -> HoldemResult<()> {
+        for _ in 0..n {
+            // Deal hole cards to random and sampled players first
+            let sim_hole_cards = self.deal_holes(rng)?;
 
-        // Build complete board
-        let mut full_board = request.board.clone();
-        full_board.extend(runout);
+            // Deal community cards
+            let runout = self.dealer.deal_runout(self.cards_needed_board);
 
-        // Build complete hands for each player
-        let hands: Vec<Vec<Card>> = sim_hole_cards
-            .into_iter()
-            .map(|mut hole| {
-                hole.extend(full_board.iter().copied());
-                hole
-            })
-            .collect();
+            // Build complete board
+            let mut full_board = self.request.board.clone();
+            full_board.extend_from_slice(runout);
+
+            // Build complete hands for each player
+            let hands: Vec<Vec<Card>> = sim_hole_cards
+                .into_iter()
+                .map(|mut hole| {
+                    hole.extend(full_board.iter().copied());
+                    hole
+                })
+                .collect();
+
+            // Evaluate showdown
+            let results = find_winners_detailed(&hands)?;
+            let winners: Vec<usize> =
+                results.iter().filter(|r| r.is_winner).map(|r| r.index).collect();
+
+            // Record result
+            self.acc.record(&winners);
+            self.acc.record_hand_types(&results);
+        }
+        Ok(())
+    }
+
+    /// Results for all simulations run so far
+    fn results(&self, elapsed_ms: f64) -> EquityResult {
+        self.acc.to_results(&self.hand_descriptions, elapsed_ms)
+    }
+}
+
+/// Simulations between convergence checks when a target precision is set
+const PRECISION_BATCH: u32 = 500;
+
+/// Calculate equity for all players
+///
+/// Supports known hands, random players and range players. Random players
+/// have their hole cards sampled from the remaining deck each simulation,
+/// and sampled range players (`PlayerHand::from_range`) draw a fresh combo
+/// from their distribution. If any player is a range, the request is handed
+/// to `calculate_equity_with_ranges`, which enumerates sampled ranges too;
+/// in that case win/tie counts are derived from rates, and teams and hand
+/// type frequencies are rejected because that engine cannot tally them.
+///
+/// # Errors
+/// Returns an error if:
+/// - Fewer than 2 players
+/// - More than 5 board cards
+/// - Duplicate cards detected, including two players sharing a card
+///   (`DuplicateCard` names the card)
+/// - Invalid player hand configuration
+/// - A range player's combos all use another player's cards (`RangeBlocked`)
+/// - A range player's combos are all blocked by the board or dead cards
+///   (`PlayerHasNoCombos`)
+/// - A range is invalid or leaves no valid combination
+/// - Range players are combined with teams or hand type frequencies
+///   (`InvalidRange`)
+pub fn calculate_equity(request: &EquityRequest) -> HoldemResult<EquityResult> {
+    if request.players.iter().any(PlayerHand::is_range) {
+        validate_equity_request(request)?;
+        return calculate_mixed_equity(request, request.seed);
+    }
+    calculate_equity_with_rng(request, &mut DeterministicRng::from_seed(request.seed))
+}
 
-        // Find winners (unwrap is safe here - we always have 7-card hands)
-        let winners = find_winners(&hands).unwrap();
+/// Calculate equity for all players, drawing randomness from `rng`
+///
+/// Like `calculate_equity`, but `request.seed` is ignored: every shuffle
+/// comes from `rng`, so several calls can share one stream and any
+/// `RngCore` can drive the simulation. A request with range players takes
+/// a single `u64` from `rng` as the seed for the range engine.
+///
+/// # Errors
+/// Returns the same errors as `calculate_equity`.
+pub fn calculate_equity_with_rng(
+    request: &EquityRequest,
+    rng: &mut impl RngCore,
+) -> HoldemResult<EquityResult> {
+    validate_equity_request(request)?;
 
-        // Record result
-        acc.record(&winners);
+    if request.players.iter().any(PlayerHand::is_range) {
+        return calculate_mixed_equity(request, Some(rng.next_u64()));
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    let start = Instant::now();
+
+    let mut simulation = EquitySimulation::new(Cow::Borrowed(request))?;
+    simulation.run_to_precision(request.num_simulations, rng)?;
+
     #[cfg(not(target_arch = "wasm32"))]
     let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
     #[cfg(target_arch = "wasm32")]
     let elapsed_ms = 0.0; // WASM timing handled by holdem-wasm with js_sys::Date
 
-    Ok(acc.into_results(hand_descriptions, elapsed_ms))
+    Ok(simulation.results(elapsed_ms))
+}
+
+/// Run a request with range players through the range engine with `seed`
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn calculate_mixed_equity(
+    request: &EquityRequest,
+    seed: Option<u64>,
+) -> HoldemResult<EquityResult> {
+    // The range engine has no per-simulation outcomes to tally these from
+    if !request.teams.is_empty() {
+        return Err(HoldemError::InvalidRange(
+            "teams are not supported with range players".to_string(),
+        ));
+    }
+    if request.hand_distribution {
+        return Err(HoldemError::InvalidRange(
+            "hand type frequencies are not supported with range players".to_string(),
+        ));
+    }
+
+    let mut excluded = request.board.clone();
+    excluded.extend_from_slice(&request.dead_cards);
+    let held: HashSet<Card> = request.players.iter().flat_map(|p| p.cards.clone()).collect();
+
+    let mut players = Vec::with_capacity(request.players.len());
+    let mut hand_descriptions = Vec::with_capacity(request.players.len());
+    for (i, player) in request.players.iter().enumerate() {
+        if player.is_random {
+            players.push(RangePlayer::random());
+            hand_descriptions.push("(Random)".to_string());
+        } else if player.is_range() {
+            let dist = CardDistribution::from_range(&player.range, &excluded).map_err(|e| match e {
+                RangeError::NoCombosAvailable => HoldemError::PlayerHasNoCombos(i),
+                e => HoldemError::InvalidRange(e.to_string()),
+            })?;
+            // Report a range emptied by other players' hole cards up front,
+            // rather than as a generic lack of valid combinations
+            if dist.filter_excluding(&held).is_empty() {
+                return Err(HoldemError::RangeBlocked(i));
+            }
+            players.push(RangePlayer::range(dist));
+            hand_descriptions.push(player.range.join(", "));
+        } else if let Some(dist) = &player.sampled_range {
+            players.push(RangePlayer::range(dist.clone()));
+            hand_descriptions.push(format!("{} combos", dist.len()));
+        } else {
+            players.push(RangePlayer::specific(player.cards[0], player.cards[1]));
+            hand_descriptions.push(format!("{} {}", player.cards[0], player.cards[1]));
+        }
+    }
+
+    let mut range_request = RangeEquityRequest::new(players, request.board.clone())
+        .with_simulations(request.num_simulations)
+        .with_dead_cards(request.dead_cards.clone());
+    range_request.seed = seed;
+
+    let result = calculate_equity_with_ranges(&range_request)?;
+    let total = result.total_simulations;
+    let count = |rate: f64| (rate * total as f64).round() as u64;
+
+    Ok(EquityResult {
+        players: result
+            .players
+            .into_iter()
+            .zip(hand_descriptions)
+            .map(|(p, hand_description)| PlayerEquity {
+                index: p.index,
+                win_count: count(p.win_rate),
+                tie_count: count(p.tie_rate),
+                total_simulations: total,
+                win_rate: p.win_rate,
+                tie_rate: p.tie_rate,
+                equity: p.equity,
+                hand_description,
+                combos: p.combos,
+                hand_type_frequencies: None,
+            })
+            .collect(),
+        total_simulations: total,
+        elapsed_ms: result.elapsed_ms,
+        teams: Vec::new(),
+    })
+}
+
+/// Calculate equity in fixed-size batches, yielding cumulative results.
+///
+/// Each item reflects all simulations run so far, so a UI can show the
+/// equity stabilizing while the work is still in progress. The last item
+/// covers `request.num_simulations` simulations (fewer if the target
+/// precision is met first) and, for the same seed, is identical to the
+/// result of `calculate_equity`. A `batch_size` of 0 is
+/// treated as 1.
+///
+/// # Errors
+/// Returns the same validation errors as `calculate_equity`, and an error
+/// if any player is a range (ranges are only supported by `calculate_equity`).
+/// Items are errors only if a simulation fails (see `EquitySession::step`).
+pub fn calculate_equity_batched(
+    request: &EquityRequest,
+    batch_size: u32,
+) -> HoldemResult<impl Iterator<Item = HoldemResult<EquityResult>> + '_> {
+    let batch_size = batch_size.max(1);
+    let mut session = EquitySession::new(request.clone())?;
+
+    Ok(std::iter::from_fn(move || {
+        (!session.is_finished()).then(|| session.step(batch_size))
+    }))
+}
+
+/// A resumable equity calculation
+///
+/// Owns the RNG, deck and running totals between calls, so a caller such
+/// as a browser UI can run a few simulations at a time with `step` and show
+/// the running result in between. Once `request.num_simulations` have run,
+/// or the request's target precision is met, the session is finished and,
+/// for the same seed, its result is identical to `calculate_equity`.
+pub struct EquitySession {
+    simulation: EquitySimulation<'static>,
+    rng: DeterministicRng,
+    remaining: u32,
+    elapsed_ms: f64,
+}
+
+impl EquitySession {
+    /// Validate the request and set up a session with no simulations run
+    ///
+    /// # Errors
+    /// Returns the same validation errors as `calculate_equity`, and an
+    /// error if any player is a range.
+    pub fn new(request: EquityRequest) -> HoldemResult<Self> {
+        validate_equity_request(&request)?;
+        if request.players.iter().any(PlayerHand::is_range) {
+            return Err(HoldemError::InvalidRange(
+                "range players are not supported in batched equity".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            remaining: request.num_simulations,
+            rng: DeterministicRng::from_seed(request.seed),
+            simulation: EquitySimulation::new(Cow::Owned(request))?,
+            elapsed_ms: 0.0,
+        })
+    }
+
+    /// Run up to `batch` more simulations and return the cumulative result
+    ///
+    /// # Errors
+    /// Returns an error if a simulated hand cannot be evaluated, which a
+    /// validated request never causes.
+    pub fn step(&mut self, batch: u32) -> HoldemResult<EquityResult> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let start = Instant::now();
+
+        let batch = batch.min(self.remaining);
+        if self.simulation.run_to_precision(batch, &mut self.rng)? {
+            self.remaining = 0;
+        } else {
+            self.remaining -= batch;
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.elapsed_ms += start.elapsed().as_secs_f64() * 1000.0;
+        }
+        Ok(self.results())
+    }
+
+    /// Result of all simulations run so far
+    #[must_use]
+    pub fn results(&self) -> EquityResult {
+        self.simulation.results(self.elapsed_ms)
+    }
+
+    /// Simulations still to run (0 once the target precision is met)
+    #[must_use]
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// Whether every requested simulation has run or the target precision
+    /// was met
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+/// Cache key for an `EquityRequest`, identical for suit-isomorphic requests
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct EquityCacheKey {
+    /// Sorted card indices per player; empty for random and range players
+    hole_cards: Vec<Vec<u8>>,
+    board: Vec<u8>,
+    dead_cards: Vec<u8>,
+    random: Vec<bool>,
+    ranges: Vec<Vec<String>>,
+    num_simulations: u32,
+    seed: Option<u64>,
+    teams: Vec<Vec<usize>>,
+    hand_distribution: bool,
+    target_precision: Option<u64>,
+}
+
+impl EquityCacheKey {
+    /// Smallest key over every relabeling of the suits
+    ///
+    /// Range tokens may name specific suits, so requests with a range
+    /// player are only keyed as given.
+    fn new(request: &EquityRequest) -> Self {
+        let keyed = |suits: &[u8]| {
+            let map = |cards: &[Card]| relabeled_indices(cards, suits);
+            Self {
+                hole_cards: request.players.iter().map(|p| map(&p.cards)).collect(),
+                board: map(&request.board),
+                dead_cards: map(&request.dead_cards),
+                random: request.players.iter().map(|p| p.is_random).collect(),
+                ranges: request.players.iter().map(|p| p.range.clone()).collect(),
+                num_simulations: request.num_simulations,
+                seed: request.seed,
+                teams: request.teams.clone(),
+                hand_distribution: request.hand_distribution,
+                target_precision: request.target_precision.map(f64::to_bits),
+            }
+        };
+
+        if request.players.iter().any(PlayerHand::is_range) {
+            return keyed(&[0, 1, 2, 3]);
+        }
+        suit_permutations()
+            .map(|suits| keyed(&suits))
+            .min()
+            .expect("there is always a suit permutation")
+    }
+}
+
+/// Opt-in memoization of `calculate_equity` results
+///
+/// Requests are keyed on their players, board, dead cards, simulation count,
+/// seed, teams, hand distribution flag and target precision. Board and dead card order does not
+/// matter, and suit-isomorphic requests (e.g. `AhKh` on `Qh Jh Th` and
+/// `AsKs` on `Qs Js Ts`) share an entry: the cached result is returned with
+/// the hand descriptions of the new request.
+#[derive(Clone, Debug, Default)]
+pub struct EquityCache {
+    entries: HashMap<EquityCacheKey, EquityResult>,
+}
+
+impl EquityCache {
+    /// Create an empty cache
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cached result for the request, calculating and storing it on a miss
+    ///
+    /// Requests with a sampled range player are calculated but never cached.
+    ///
+    /// # Errors
+    /// Returns the same errors as `calculate_equity`. Errors are not cached.
+    pub fn get_or_compute(&mut self, request: &EquityRequest) -> HoldemResult<EquityResult> {
+        // Sampled distributions are not part of the key
+        if request.players.iter().any(PlayerHand::is_sampled) {
+            return calculate_equity(request);
+        }
+        let key = EquityCacheKey::new(request);
+        let Some(mut result) = self.entries.get(&key).cloned() else {
+            let result = calculate_equity(request)?;
+            self.entries.insert(key, result.clone());
+            return Ok(result);
+        };
+
+        for (player, equity) in request.players.iter().zip(&mut result.players) {
+            if player.is_random {
+                equity.hand_description = "(Random)".to_string();
+            } else if !player.is_range() {
+                equity.hand_description = player
+                    .cards
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+            }
+        }
+        Ok(result)
+    }
+
+    /// Number of cached results
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether nothing has been cached yet
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drop every cached result
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
 }
 
 /// Player input for range-based equity calculation
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum RangePlayer {
     /// Specific cards (2 hole cards)
     Specific(Card, Card),
@@ -449,18 +1542,33 @@ impl RangePlayer {
 }
 
 /// Request for range-based equity calculation
-#[derive(Clone, Debug)]
+///
+/// Optional fields fall back to their `new` defaults when missing from
+/// serialized input.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RangeEquityRequest {
     /// Players with their hand distributions
     pub players: Vec<RangePlayer>,
     /// Community cards (0-5)
     pub board: Vec<Card>,
     /// Dead cards
+    #[serde(default)]
     pub dead_cards: Vec<Card>,
     /// Number of Monte Carlo simulations per combination
+    #[serde(default = "default_simulations")]
     pub num_simulations: u32,
     /// Random seed
+    #[serde(default)]
     pub seed: Option<u64>,
+    /// Thresholds for choosing between enumeration and sampling
+    #[serde(default)]
+    pub strategy: StrategyConfig,
+    /// Also report player 0's showdown splits per canonical hand
+    #[serde(default)]
+    pub showdown_matrix: bool,
+    /// Also report player 0's equity in every evaluated combination
+    #[serde(default)]
+    pub per_combo_output: bool,
 }
 
 impl RangeEquityRequest {
@@ -473,6 +1581,9 @@ impl RangeEquityRequest {
             dead_cards: Vec::new(),
             num_simulations: default_simulations(),
             seed: None,
+            strategy: StrategyConfig::default(),
+            showdown_matrix: false,
+            per_combo_output: false,
         }
     }
 
@@ -496,6 +1607,55 @@ impl RangeEquityRequest {
         self.dead_cards = dead;
         self
     }
+
+    /// Set the strategy thresholds, e.g. to force exact enumeration
+    #[must_use]
+    pub fn with_strategy(mut self, strategy: StrategyConfig) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Request the per-hand showdown matrix in the result
+    #[must_use]
+    pub fn with_showdown_matrix(mut self, enabled: bool) -> Self {
+        self.showdown_matrix = enabled;
+        self
+    }
+
+    /// Request player 0's equity for each evaluated combination in the result
+    #[must_use]
+    pub fn with_per_combo_output(mut self, enabled: bool) -> Self {
+        self.per_combo_output = enabled;
+        self
+    }
+}
+
+/// How one canonical hand of player 0 fares at showdown against the others
+///
+/// Fractions are averaged over every evaluated combination in which player
+/// 0 holds a combo of `hand`, and add up to 1.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ShowdownRow {
+    /// Player 0's canonical hand
+    pub hand: CanonicalHand,
+    /// Fraction of showdowns won outright
+    pub win: f64,
+    /// Fraction of showdowns split with at least one other player
+    pub tie: f64,
+    /// Fraction of showdowns lost
+    pub lose: f64,
+}
+
+/// Player 0's equity in one evaluated combination of a range calculation
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ComboEquity {
+    /// Player 0's hole cards
+    pub hero: (Card, Card),
+    /// The other players' hole cards in request order, `None` for random
+    /// players
+    pub villains: Vec<Option<(Card, Card)>>,
+    /// Player 0's equity over this combination's simulations
+    pub equity: f64,
 }
 
 /// Result for range-based equity calculation
@@ -505,10 +1665,71 @@ pub struct RangeEquityResult {
     pub players: Vec<RangePlayerEquity>,
     /// Total valid combinations evaluated
     pub total_combinations: u64,
+    /// Size of the combo space: the product of every player's combo count
+    /// (random players count as 1), before removing card conflicts
+    #[serde(default)]
+    pub total_theoretical_combinations: u64,
     /// Total simulations across all combinations
     pub total_simulations: u64,
     /// Elapsed time in milliseconds
     pub elapsed_ms: f64,
+    /// Player 0's showdown splits per canonical hand, in the order the hands
+    /// were first evaluated. Only present if the request asked for it and
+    /// player 0 is not a random player.
+    #[serde(default)]
+    pub showdown_matrix: Option<Vec<ShowdownRow>>,
+    /// Player 0's equity per evaluated combination, in evaluation order.
+    /// Every combination carries the same weight in the aggregate, so the
+    /// mean of these equities is player 0's reported equity. Only present
+    /// if the request asked for it and player 0 is not a random player.
+    #[serde(default)]
+    pub per_combo: Option<Vec<ComboEquity>>,
+}
+
+impl RangeEquityResult {
+    /// Fraction of the theoretical combo space covered by `total_combinations`
+    ///
+    /// 1.0 when every combination was enumerated; lower when combos were
+    /// sampled or dropped for sharing cards. Returns 0.0 for an empty space.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn coverage(&self) -> f64 {
+        if self.total_theoretical_combinations == 0 {
+            0.0
+        } else {
+            self.total_combinations as f64 / self.total_theoretical_combinations as f64
+        }
+    }
+}
+
+/// Format a range equity result as CSV, one row per player
+///
+/// Columns are `player,hand,combos,equity,win_rate,tie_rate`, with a header
+/// row first. Hand descriptions containing commas or quotes are quoted.
+#[must_use]
+pub fn results_to_csv(results: &RangeEquityResult) -> String {
+    let mut csv = String::from("player,hand,combos,equity,win_rate,tie_rate\n");
+    csv.extend(results.players.iter().map(|p| {
+        format!(
+            "{},{},{},{},{},{}\n",
+            p.index,
+            csv_field(&p.hand_description),
+            p.combos,
+            p.equity,
+            p.win_rate,
+            p.tie_rate
+        )
+    }));
+    csv
+}
+
+/// Quote a CSV field if it contains a separator, quote or newline
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
 }
 
 /// Equity result for a single player in range calculation
@@ -550,15 +1771,42 @@ const SMALL_RANGE_THRESHOLD: usize = 50;
 /// Threshold for medium ranges: enumerate all with reduced simulations
 const MEDIUM_RANGE_THRESHOLD: usize = 500;
 
-/// Threshold for huge ranges: use biased but fast sampling
+/// Threshold for huge ranges: sample each player's combo independently
 const HUGE_RANGE_THRESHOLD: usize = 10_000;
 
 /// Maximum combos to sample for large ranges
 const MAX_SAMPLED_COMBOS: usize = 200;
 
+/// Draws allowed per wanted sample before giving up on conflicting ranges
+const MAX_DRAWS_PER_SAMPLE: usize = 100;
+
 /// Minimum simulations per combo to ensure statistical significance
 const MIN_SIMS_PER_COMBO: u32 = 100;
 
+/// Thresholds used to pick a range equity strategy
+///
+/// Raising `max_exhaustive_combos` trades speed for exact enumeration of
+/// larger matchups; the defaults match the table above.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StrategyConfig {
+    /// Largest combo space that is enumerated rather than sampled
+    pub max_exhaustive_combos: usize,
+    /// Combinations simulated when the combo space is sampled
+    pub max_sampled_combos: usize,
+    /// Floor on simulations per combo when enumerating a large space
+    pub min_sims_per_combo: u32,
+}
+
+impl Default for StrategyConfig {
+    fn default() -> Self {
+        Self {
+            max_exhaustive_combos: MEDIUM_RANGE_THRESHOLD,
+            max_sampled_combos: MAX_SAMPLED_COMBOS,
+            min_sims_per_combo: MIN_SIMS_PER_COMBO,
+        }
+    }
+}
+
 /// Calculation strategy based on range size
 #[derive(Debug, Clone, Copy)]
 enum EquityStrategy {
@@ -569,41 +1817,85 @@ enum EquityStrategy {
         max_combos: usize,
         sims_per_combo: u32,
     },
-    /// Biased but fast sampling - breaks early once enough samples collected
-    BiasedSampled {
+    /// Unbiased independent sampling - draws each player's combo directly,
+    /// rejecting conflicts, without walking the odometer
+    IndependentSampled {
         max_combos: usize,
         sims_per_combo: u32,
     },
 }
 
 /// Select optimal calculation strategy based on total combo count
-fn select_strategy(total_combos: usize, requested_sims: u32) -> EquityStrategy {
+fn select_strategy(
+    total_combos: usize,
+    requested_sims: u32,
+    config: &StrategyConfig,
+) -> EquityStrategy {
     if total_combos <= SMALL_RANGE_THRESHOLD {
         // Small range: enumerate all, use full simulations (at least 1000)
         EquityStrategy::Exhaustive {
             sims_per_combo: requested_sims.max(1000),
         }
-    } else if total_combos <= MEDIUM_RANGE_THRESHOLD {
+    } else if total_combos <= config.max_exhaustive_combos {
         // Medium range: enumerate all, reduce sims to control total time
         // Target: roughly same total work as 50 combos × requested_sims
         let sims = ((requested_sims as usize * SMALL_RANGE_THRESHOLD) / total_combos)
-            .max(MIN_SIMS_PER_COMBO as usize) as u32;
+            .max(config.min_sims_per_combo as usize) as u32;
         EquityStrategy::Exhaustive { sims_per_combo: sims }
     } else if total_combos <= HUGE_RANGE_THRESHOLD {
         // Large range: unbiased reservoir sampling
         EquityStrategy::ReservoirSampled {
-            max_combos: MAX_SAMPLED_COMBOS,
+            max_combos: config.max_sampled_combos,
             sims_per_combo: requested_sims,
         }
     } else {
-        // Huge range (>10k combos): biased but fast sampling
-        EquityStrategy::BiasedSampled {
-            max_combos: MAX_SAMPLED_COMBOS,
+        // Huge range (>10k combos): independent per-player sampling
+        EquityStrategy::IndependentSampled {
+            max_combos: config.max_sampled_combos,
             sims_per_combo: requested_sims,
         }
     }
 }
 
+/// Running showdown totals per canonical hand of player 0
+#[derive(Default)]
+struct ShowdownTally {
+    /// (hand, summed win fraction, summed tie fraction, combinations)
+    groups: Vec<(CanonicalHand, f64, f64, u64)>,
+}
+
+impl ShowdownTally {
+    /// Add one combination's results for player 0 holding `hero`
+    fn record(&mut self, hero: (Card, Card), wins: u64, ties: u64, sims: u32) {
+        if sims == 0 {
+            return;
+        }
+        let hand = canonize_hole_cards(&[hero.0, hero.1]);
+        #[allow(clippy::cast_precision_loss)]
+        let (win, tie) = (wins as f64 / f64::from(sims), ties as f64 / f64::from(sims));
+        match self.groups.iter_mut().find(|(h, ..)| *h == hand) {
+            Some((_, win_sum, tie_sum, count)) => {
+                *win_sum += win;
+                *tie_sum += tie;
+                *count += 1;
+            }
+            None => self.groups.push((hand, win, tie, 1)),
+        }
+    }
+
+    /// Averaged rows in first-seen order
+    #[allow(clippy::cast_precision_loss)]
+    fn into_rows(self) -> Vec<ShowdownRow> {
+        self.groups
+            .into_iter()
+            .map(|(hand, win_sum, tie_sum, count)| {
+                let (win, tie) = (win_sum / count as f64, tie_sum / count as f64);
+                ShowdownRow { hand, win, tie, lose: (1.0 - win - tie).max(0.0) }
+            })
+            .collect()
+    }
+}
+
 /// Calculate equity with range support using adaptive strategy.
 ///
 /// # Performance Optimization
@@ -616,7 +1908,10 @@ fn select_strategy(total_combos: usize, requested_sims: u32) -> EquityStrategy {
 /// | Small | < 50 | Exhaustive | Enumerate all combos, more sims each |
 /// | Medium | 50-500 | Exhaustive | Enumerate all, fewer sims to control time |
 /// | Large | 500-10k | ReservoirSampled | Unbiased sampling, iterates all combos |
-/// | Huge | > 10k | BiasedSampled | Fast but biased toward front of odometer |
+/// | Huge | > 10k | IndependentSampled | Unbiased, draws each player's combo directly |
+///
+/// The exhaustive limit, sample size and per-combo simulation floor can be
+/// changed with `RangeEquityRequest::with_strategy`.
 ///
 /// # Algorithm
 ///
@@ -628,7 +1923,8 @@ fn select_strategy(total_combos: usize, requested_sims: u32) -> EquityStrategy {
 ///    - Skip if cards conflict (same card used twice)
 ///    - Run Monte Carlo simulation
 ///    - Weight and accumulate results
-/// 6. Return weighted average equity
+/// 6. Return weighted average equity, plus player 0's per-hand showdown
+///    splits if `request.showdown_matrix` is set
 ///
 /// # Complexity
 ///
@@ -637,7 +1933,8 @@ fn select_strategy(total_combos: usize, requested_sims: u32) -> EquityStrategy {
 ///
 /// # Errors
 /// Returns an error if fewer than 2 players, more than 5 board cards,
-/// duplicate cards in board/dead, or no valid combinations exist.
+/// duplicate cards in board/dead, a range emptied by board/dead cards
+/// (`PlayerHasNoCombos`), or no valid combinations exist.
 pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResult<RangeEquityResult> {
     if request.players.len() < 2 {
         return Err(HoldemError::NotEnoughPlayers(2));
@@ -645,6 +1942,7 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
     if request.board.len() > 5 {
         return Err(HoldemError::BoardTooLarge(request.board.len()));
     }
+    check_deck_size(request.players.len(), request.dead_cards.len())?;
 
     #[cfg(not(target_arch = "wasm32"))]
     let start = Instant::now();
@@ -664,6 +1962,14 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
         }
     }
 
+    // Cards no range or random player can hold
+    let specific_cards = 2 * request
+        .players
+        .iter()
+        .filter(|p| matches!(p, RangePlayer::Specific(..)))
+        .count();
+    let random_combos = live_combos(base_excluded.len() + specific_cards);
+
     // Build distributions for each player
     let mut distributions: Vec<Vec<(Card, Card)>> = Vec::with_capacity(num_players);
     let mut hand_descriptions: Vec<String> = Vec::with_capacity(num_players);
@@ -690,7 +1996,7 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
                 // Random will be handled specially during simulation
                 distributions.push(vec![]); // Empty marker
                 hand_descriptions.push("Random".to_string());
-                combo_counts.push(1326);
+                combo_counts.push(random_combos);
             }
             RangePlayer::Range(dist) => {
                 // Filter by base excluded cards
@@ -705,10 +2011,7 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
     // Check if any range player has no combos
     for (i, dist) in distributions.iter().enumerate() {
         if dist.is_empty() && !matches!(request.players[i], RangePlayer::Random) {
-            return Err(HoldemError::InvalidCardCount {
-                expected: "at least 1 combo",
-                got: 0,
-            });
+            return Err(HoldemError::PlayerHasNoCombos(i));
         }
     }
 
@@ -750,13 +2053,14 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
     // Calculate total theoretical combinations and select strategy
     let odometer = Odometer::new(extents.clone());
     let total_theoretical_combos = odometer.total_combinations();
-    let strategy = select_strategy(total_theoretical_combos, request.num_simulations);
+    let strategy =
+        select_strategy(total_theoretical_combos, request.num_simulations, &request.strategy);
 
     // Extract sims_per_combo (common to all strategies)
     let sims_per_combo = match strategy {
         EquityStrategy::Exhaustive { sims_per_combo } => sims_per_combo,
         EquityStrategy::ReservoirSampled { sims_per_combo, .. } => sims_per_combo,
-        EquityStrategy::BiasedSampled { sims_per_combo, .. } => sims_per_combo,
+        EquityStrategy::IndependentSampled { sims_per_combo, .. } => sims_per_combo,
     };
 
     // Initialize accumulators
@@ -766,13 +2070,25 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
     let mut total_weight: f64 = 0.0;
     let mut total_combinations: u64 = 0;
     let mut total_simulations: u64 = 0;
-
-    // Initialize RNG
-    let mut rng = match request.seed {
-        Some(seed) => StdRng::seed_from_u64(seed),
-        None => StdRng::from_os_rng(),
+    let track_showdown =
+        request.showdown_matrix && !matches!(request.players[0], RangePlayer::Random);
+    let mut showdown = track_showdown.then(ShowdownTally::default);
+    let track_per_combo =
+        request.per_combo_output && !matches!(request.players[0], RangePlayer::Random);
+    let mut per_combo: Option<Vec<ComboEquity>> = track_per_combo.then(Vec::new);
+    let combo_row = |hands: &[(Card, Card)], equity_sum: f64| ComboEquity {
+        hero: hands[0],
+        villains: (1..num_players)
+            .map(|i| (!random_player_indices.contains(&i)).then_some(hands[i]))
+            .collect(),
+        equity: equity_sum / f64::from(sims_per_combo),
     };
 
+    // Initialize RNG. It only picks which combinations to sample; each
+    // combination's runouts come from its own seed (see `run_simulation`)
+    let mut rng = DeterministicRng::from_seed(request.seed);
+    let base_seed = request.seed.unwrap_or_else(|| rng.next_u64());
+
     let cards_needed_board = 5 - request.board.len();
 
     // Helper to check if a combination is valid (no card conflicts)
@@ -821,38 +2137,43 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
         Some((current_hands, remaining))
     };
 
-    // Helper to run simulation for a combination
+    // Helper to run simulation for a combination. The RNG is seeded from the
+    // combination's cards (sorted, so independent of player order), so a
+    // combination gets the same runouts whatever order or strategy reaches it
     let run_simulation = |current_hands: &[(Card, Card)],
-                          remaining: &[Card],
-                          rng: &mut StdRng|
-     -> (Vec<u64>, Vec<u64>, Vec<f64>) {
+                          remaining: &[Card]|
+     -> HoldemResult<(Vec<u64>, Vec<u64>, Vec<f64>)> {
         let mut combo_wins = vec![0u64; num_players];
         let mut combo_ties = vec![0u64; num_players];
         let mut combo_equity = vec![0.0f64; num_players];
-        let mut deck_remaining = remaining.to_vec();
+        let mut dealer = Dealer::new(remaining.to_vec());
+
+        let mut held: Vec<usize> = current_hands
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !random_player_indices.contains(i))
+            .flat_map(|(_, &(c1, c2))| [usize::from(c1.to_index()), usize::from(c2.to_index())])
+            .collect();
+        held.sort_unstable();
+        let mut rng = DeterministicRng::seed_from_u64(derive_seed(base_seed, &held));
 
         for _ in 0..sims_per_combo {
-            deck_remaining.shuffle(rng);
+            dealer.reshuffle(&mut rng);
 
-            let mut deck_idx = 0;
             let mut sim_hole_cards: Vec<Vec<Card>> = Vec::with_capacity(num_players);
 
             for (i, &(c1, c2)) in current_hands.iter().enumerate() {
                 if random_player_indices.contains(&i) {
                     // Deal random cards
-                    sim_hole_cards.push(vec![deck_remaining[deck_idx], deck_remaining[deck_idx + 1]]);
-                    deck_idx += 2;
+                    sim_hole_cards.push(dealer.deal_hole());
                 } else {
                     sim_hole_cards.push(vec![c1, c2]);
                 }
             }
 
-            // Deal community cards
-            let runout: Vec<Card> = deck_remaining[deck_idx..deck_idx + cards_needed_board].to_vec();
-
             // Build complete board
             let mut full_board = request.board.clone();
-            full_board.extend(runout);
+            full_board.extend_from_slice(dealer.deal_runout(cards_needed_board));
 
             // Build complete hands
             let hands: Vec<Vec<Card>> = sim_hole_cards
@@ -864,7 +2185,7 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
                 .collect();
 
             // Find winners
-            let winners = find_winners(&hands).unwrap();
+            let winners = find_winners(&hands)?;
 
             // Record results
             if winners.len() == 1 {
@@ -880,7 +2201,7 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
             }
         }
 
-        (combo_wins, combo_ties, combo_equity)
+        Ok((combo_wins, combo_ties, combo_equity))
     };
 
     match strategy {
@@ -895,7 +2216,7 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
                     total_combinations += 1;
 
                     let (combo_wins, combo_ties, combo_equity) =
-                        run_simulation(&current_hands, &remaining, &mut rng);
+                        run_simulation(&current_hands, &remaining)?;
 
                     total_simulations += sims_per_combo as u64;
 
@@ -908,6 +2229,13 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
                         total_wins[i] += (combo_wins[i] as f64 / sim_count) * weight;
                         total_ties[i] += (combo_ties[i] as f64 / sim_count) * weight;
                     }
+                    if let Some(tally) = &mut showdown {
+                        let (wins, ties) = (combo_wins[0], combo_ties[0]);
+                        tally.record(current_hands[0], wins, ties, sims_per_combo);
+                    }
+                    if let Some(rows) = &mut per_combo {
+                        rows.push(combo_row(&current_hands, combo_equity[0]));
+                    }
                 }
             }
         }
@@ -935,7 +2263,7 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
                         reservoir.push((hands, remaining));
                     } else {
                         // Reservoir sampling: replace element j with probability k/n
-                        let j = rng.random_range(0..valid_count);
+                        let j = rng.below(valid_count);
                         if j < max_combos {
                             reservoir[j] = (hands, remaining);
                         }
@@ -943,12 +2271,13 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
                 }
             }
 
-            total_combinations = valid_count as u64;
+            // Only the reservoir is simulated, so coverage reflects the sample
+            total_combinations = reservoir.len() as u64;
 
             // Phase 2: Run simulations on reservoir samples
             for (hands, remaining) in &reservoir {
                 let (combo_wins, combo_ties, combo_equity) =
-                    run_simulation(hands, remaining, &mut rng);
+                    run_simulation(hands, remaining)?;
 
                 total_simulations += sims_per_combo as u64;
 
@@ -961,30 +2290,36 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
                     total_wins[i] += (combo_wins[i] as f64 / sim_count) * weight;
                     total_ties[i] += (combo_ties[i] as f64 / sim_count) * weight;
                 }
+                if let Some(tally) = &mut showdown {
+                    tally.record(hands[0], combo_wins[0], combo_ties[0], sims_per_combo);
+                }
+                if let Some(rows) = &mut per_combo {
+                    rows.push(combo_row(hands, combo_equity[0]));
+                }
             }
         }
 
-        EquityStrategy::BiasedSampled { max_combos, .. } => {
+        EquityStrategy::IndependentSampled { max_combos, .. } => {
             // =================================================================
-            // BIASED SAMPLING: Fast but biased toward front of odometer
+            // INDEPENDENT SAMPLING: Unbiased and O(samples)
             // =================================================================
-            // Used for huge ranges (>10k combos) where reservoir sampling would
-            // be too slow. Trade-off: Results may be biased toward combinations
-            // that appear earlier in the odometer iteration order.
-
-            let sample_rate = max_combos as f64 / total_theoretical_combos as f64;
+            // Used for huge ranges (>10k combos) where even iterating the
+            // odometer is too slow. Each player's combo is drawn uniformly and
+            // conflicting draws are rejected, so every valid combination is
+            // equally likely, exactly as with enumeration. Heavily overlapping
+            // ranges may accept fewer than `max_combos` samples.
+
+            let max_draws = max_combos * MAX_DRAWS_PER_SAMPLE;
+            let mut indices = vec![0; num_players];
             let mut sampled_count: usize = 0;
 
-            let odometer = Odometer::new(extents);
-            for indices in odometer {
-                // Early exit once we have enough samples
+            for _ in 0..max_draws {
                 if sampled_count >= max_combos {
                     break;
                 }
 
-                // Probabilistic skip based on sample rate
-                if rng.random::<f64>() > sample_rate {
-                    continue;
+                for (index, &extent) in indices.iter_mut().zip(&extents) {
+                    *index = rng.below(extent);
                 }
 
                 if let Some((current_hands, remaining)) = is_valid_combination(&indices) {
@@ -992,7 +2327,7 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
                     sampled_count += 1;
 
                     let (combo_wins, combo_ties, combo_equity) =
-                        run_simulation(&current_hands, &remaining, &mut rng);
+                        run_simulation(&current_hands, &remaining)?;
 
                     total_simulations += sims_per_combo as u64;
 
@@ -1005,6 +2340,13 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
                         total_wins[i] += (combo_wins[i] as f64 / sim_count) * weight;
                         total_ties[i] += (combo_ties[i] as f64 / sim_count) * weight;
                     }
+                    if let Some(tally) = &mut showdown {
+                        let (wins, ties) = (combo_wins[0], combo_ties[0]);
+                        tally.record(current_hands[0], wins, ties, sims_per_combo);
+                    }
+                    if let Some(rows) = &mut per_combo {
+                        rows.push(combo_row(&current_hands, combo_equity[0]));
+                    }
                 }
             }
         }
@@ -1053,11 +2395,173 @@ pub fn calculate_equity_with_ranges(request: &RangeEquityRequest) -> HoldemResul
     Ok(RangeEquityResult {
         players,
         total_combinations,
+        total_theoretical_combinations: total_theoretical_combos as u64,
         total_simulations,
         elapsed_ms,
+        showdown_matrix: showdown.map(ShowdownTally::into_rows),
+        per_combo,
     })
 }
 
+/// Quick equity between canonical hands using one concrete combo each
+///
+/// Each hand is assigned the first combo assignment (in odometer order) that
+/// avoids the board and the other players, and a single `calculate_equity`
+/// run is made. This is a fast approximation: suit interactions of that one
+/// pairing (shared suits, flush blockers) are baked in. To average over every
+/// valid pairing instead, pass the hands as ranges to
+/// `calculate_equity_with_ranges`.
+///
+/// # Errors
+/// Returns an error if fewer than 2 hands are given, the board is invalid,
+/// or no conflict-free assignment of combos exists.
+pub fn canonical_matchup_quick(
+    hands: &[CanonicalHand],
+    board: &[Card],
+    sims: u32,
+) -> HoldemResult<EquityResult> {
+    if hands.len() < 2 {
+        return Err(HoldemError::NotEnoughPlayers(2));
+    }
+
+    let combos: Vec<Vec<(Card, Card)>> =
+        hands.iter().map(|h| get_combos_excluding(h, board)).collect();
+    let assignment = Odometer::new(combos.iter().map(Vec::len).collect())
+        .map(|indices| -> Vec<(Card, Card)> {
+            indices.iter().enumerate().map(|(p, &i)| combos[p][i]).collect()
+        })
+        .find(|candidate| hands_are_disjoint(candidate))
+        .ok_or(HoldemError::NoValidCombinations)?;
+
+    let players = assignment
+        .into_iter()
+        .map(|(c1, c2)| PlayerHand::new(vec![c1, c2]))
+        .collect();
+    let request = EquityRequest::new(players, board.to_vec()).with_simulations(sims);
+    calculate_equity(&request)
+}
+
+/// Hero's equity against each canonical hand in a villain range
+///
+/// Every villain combo that avoids hero, the board and the dead cards is
+/// run for `sims` Monte Carlo runouts; combo equities are then averaged
+/// (by combo weight) within each `canonize_hole_cards` group. Groups are
+/// returned in the order they first appear in the range, ready for a
+/// heatmap.
+///
+/// # Errors
+/// Returns an error if the board has more than 5 cards, a card appears
+/// twice, or no villain combo is left after removing blocked cards.
+pub fn calculate_equity_breakdown(
+    hero: (Card, Card),
+    villain: &CardDistribution,
+    board: &[Card],
+    dead: &[Card],
+    sims: u32,
+) -> HoldemResult<Vec<(CanonicalHand, f64)>> {
+    let mut rng = DeterministicRng::from_entropy();
+    calculate_equity_breakdown_with_rng(hero, villain, board, dead, sims, &mut rng)
+}
+
+/// `calculate_equity_breakdown` drawing every runout from `rng`
+///
+/// With a seeded RNG the breakdown is reproducible.
+///
+/// # Errors
+/// Returns the same errors as `calculate_equity_breakdown`.
+pub fn calculate_equity_breakdown_with_rng(
+    hero: (Card, Card),
+    villain: &CardDistribution,
+    board: &[Card],
+    dead: &[Card],
+    sims: u32,
+    rng: &mut impl RngCore,
+) -> HoldemResult<Vec<(CanonicalHand, f64)>> {
+    if board.len() > 5 {
+        return Err(HoldemError::BoardTooLarge(board.len()));
+    }
+
+    let mut known_cards: HashSet<Card> = HashSet::new();
+    for &card in [hero.0, hero.1].iter().chain(board).chain(dead) {
+        if !known_cards.insert(card) {
+            return Err(HoldemError::DuplicateCard(card.to_string()));
+        }
+    }
+
+    let villain = villain.filter_excluding(&known_cards);
+    if villain.is_empty() {
+        return Err(HoldemError::NoValidCombinations);
+    }
+
+    let remaining: Vec<Card> = FULL_DECK
+        .iter()
+        .filter(|c| !known_cards.contains(c))
+        .copied()
+        .collect();
+    // (group, weighted equity sum, total weight)
+    let mut groups: Vec<(CanonicalHand, f64, f64)> = Vec::new();
+
+    for (i, &(v1, v2)) in villain.hands().iter().enumerate() {
+        let mut equity_sum = 0.0;
+        for _ in 0..sims {
+            equity_sum += heads_up_showdown(hero, (v1, v2), board, &remaining, rng)?.1;
+        }
+        let equity = if sims == 0 { 0.0 } else { equity_sum / f64::from(sims) };
+
+        let weight = villain.weight(i);
+        let hand = canonize_hole_cards(&[v1, v2]);
+        match groups.iter_mut().find(|(h, _, _)| *h == hand) {
+            Some((_, sum, total)) => {
+                *sum += equity * weight;
+                *total += weight;
+            }
+            None => groups.push((hand, equity * weight, weight)),
+        }
+    }
+
+    Ok(groups
+        .into_iter()
+        .map(|(hand, sum, total)| (hand, if total > 0.0 { sum / total } else { 0.0 }))
+        .collect())
+}
+
+/// Raw equity scaled by a heuristic equity realization factor
+///
+/// Raw all-in equity assumes the hand always sees a showdown. In practice a
+/// hand realizes more or less of it depending on how easy it is to play.
+/// The factor starts at 1.0 in position and 0.85 out of position, then:
+///
+/// | Property                              | Adjustment |
+/// |---------------------------------------|------------|
+/// | Suited                                | +0.07      |
+/// | Connected (gap 1)                     | +0.05      |
+/// | One-gapper (gap 2)                    | +0.03      |
+/// | Gap of 4 or more                      | -0.05      |
+/// | Offsuit with a kicker below ten       | -0.07      |
+///
+/// Pairs get no adjustment. The result is clamped to `[0, 1]`.
+#[must_use]
+pub fn realized_equity(raw_equity: f64, hand: &CanonicalHand, in_position: bool) -> f64 {
+    let mut factor = if in_position { 1.0 } else { 0.85 };
+
+    if !hand.is_pair() {
+        if hand.suited {
+            factor += 0.07;
+        }
+        factor += match hand.gap() {
+            1 => 0.05,
+            2 => 0.03,
+            3 => 0.0,
+            _ => -0.05,
+        };
+        if !hand.suited && hand.low_rank < Rank::Ten {
+            factor -= 0.07;
+        }
+    }
+
+    (raw_equity * factor).clamp(0.0, 1.0)
+}
+
 /// Convenience function: calculate equity of hole cards vs random opponents
 ///
 /// # Errors
@@ -1070,6 +2574,21 @@ pub fn equity_vs_random(
     num_opponents: usize,
     num_simulations: u32,
     seed: Option<u64>,
+) -> HoldemResult<f64> {
+    let mut rng = DeterministicRng::from_seed(seed);
+    equity_vs_random_with_rng(hole_cards, board, num_opponents, num_simulations, &mut rng)
+}
+
+/// `equity_vs_random` drawing every deal from `rng` instead of a seed
+///
+/// # Errors
+/// Returns the same errors as `equity_vs_random`.
+pub fn equity_vs_random_with_rng(
+    hole_cards: &[Card],
+    board: &[Card],
+    num_opponents: usize,
+    num_simulations: u32,
+    rng: &mut impl RngCore,
 ) -> HoldemResult<f64> {
     if hole_cards.len() != 2 {
         return Err(HoldemError::InvalidCardCount {
@@ -1080,187 +2599,2008 @@ pub fn equity_vs_random(
     if num_opponents < 1 {
         return Err(HoldemError::NotEnoughOpponents(1));
     }
+    check_deck_size(num_opponents + 1, 0)?;
+
+    // Collect known cards
+    let mut known_cards: HashSet<Card> = HashSet::new();
+    for &card in hole_cards {
+        known_cards.insert(card);
+    }
+    for &card in board {
+        known_cards.insert(card);
+    }
+
+    let cards_needed_board = 5 - board.len();
+
+    let mut equity_sum = 0.0;
+    let mut dealer = Dealer::excluding(&known_cards);
+
+    for _ in 0..num_simulations {
+        dealer.reshuffle(rng);
+
+        // Deal runout
+        let runout: Vec<Card> = dealer.deal_runout(cards_needed_board).to_vec();
+
+        // Deal opponent hands
+        let opponent_hands: Vec<Vec<Card>> =
+            (0..num_opponents).map(|_| dealer.deal_hole()).collect();
+
+        // Build complete board
+        let mut full_board = board.to_vec();
+        full_board.extend(runout);
+
+        // Build all hands
+        let mut hands: Vec<Vec<Card>> = Vec::with_capacity(num_opponents + 1);
+
+        // Hero's hand
+        let mut hero_hand = hole_cards.to_vec();
+        hero_hand.extend(full_board.iter().copied());
+        hands.push(hero_hand);
+
+        // Opponent hands
+        for opp in opponent_hands {
+            let mut hand = opp;
+            hand.extend(full_board.iter().copied());
+            hands.push(hand);
+        }
+
+        // Find winners
+        let winners = find_winners(&hands)?;
+
+        // Check if hero (index 0) won
+        if winners.contains(&0) {
+            equity_sum += 1.0 / winners.len() as f64;
+        }
+    }
+
+    Ok(equity_sum / num_simulations as f64)
+}
+
+/// Preflop equity of one starting hand against `num_opponents` random hands
+///
+/// `num_simulations` is split evenly over the hand's combos (at least one
+/// each) and the combo equities are averaged. Each combo is seeded from
+/// `seed` and its own cards.
+///
+/// # Errors
+/// Returns an error if `num_opponents < 1` or the players need more cards
+/// than the deck holds.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+pub fn preflop_hand_equity(
+    hand: &CanonicalHand,
+    num_opponents: usize,
+    num_simulations: u32,
+    seed: Option<u64>,
+) -> HoldemResult<f64> {
+    let combos = get_all_combos(hand);
+    let sims_per_combo = (num_simulations / combos.len() as u32).max(1);
+
+    let mut total_equity = 0.0;
+    for &(c1, c2) in &combos {
+        let mut held = [usize::from(c1.to_index()), usize::from(c2.to_index())];
+        held.sort_unstable();
+        let combo_seed = seed.map(|base| derive_seed(base, &held));
+        total_equity +=
+            equity_vs_random(&[c1, c2], &[], num_opponents, sims_per_combo, combo_seed)?;
+    }
+    Ok(total_equity / combos.len() as f64)
+}
+
+/// Preflop equity of all 169 starting hands against `num_opponents` random hands
+///
+/// Runs `preflop_hand_equity` for every canonical hand, in
+/// `get_all_canonical_hands` order.
+///
+/// # Errors
+/// Returns an error if `num_opponents < 1` or the players need more cards
+/// than the deck holds.
+pub fn preflop_chart(
+    num_opponents: usize,
+    num_simulations: u32,
+    seed: Option<u64>,
+) -> HoldemResult<Vec<(CanonicalHand, f64)>> {
+    get_all_canonical_hands()
+        .into_iter()
+        .map(|hand| Ok((hand, preflop_hand_equity(&hand, num_opponents, num_simulations, seed)?)))
+        .collect()
+}
+
+/// Hero's results over the runouts where hero makes at least a given hand
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConditionalEquity {
+    /// Fraction of runouts where hero reached the hand
+    pub reach_probability: f64,
+    /// Win rate over the qualifying runouts
+    pub win_rate: f64,
+    /// Tie rate over the qualifying runouts
+    pub tie_rate: f64,
+    /// Equity (ties split) over the qualifying runouts
+    pub equity: f64,
+    /// Number of qualifying runouts
+    pub reached: u64,
+    /// Total runouts simulated
+    pub total_simulations: u64,
+}
+
+/// Hero's equity only over runouts where hero ends with at least `min_type`
+///
+/// Answers "when I make my flush, how often do I win?". Each simulation
+/// samples a villain hand that avoids hero and the board, deals the rest
+/// of the board, and skips the showdown unless hero's best hand reaches
+/// `min_type`. All rates are 0 when no runout qualifies.
+///
+/// # Errors
+/// Returns an error if:
+/// - `hole_cards.len() != 2` or the board has more than 5 cards
+/// - a card appears twice
+/// - every villain combo collides with hero or the board
+pub fn conditional_on_hero_hand(
+    hole_cards: &[Card],
+    villain: &RangePlayer,
+    board: &[Card],
+    min_type: HandType,
+    num_simulations: u32,
+    seed: Option<u64>,
+) -> HoldemResult<ConditionalEquity> {
+    let mut rng = DeterministicRng::from_seed(seed);
+    conditional_on_hero_hand_with_rng(
+        hole_cards,
+        villain,
+        board,
+        min_type,
+        num_simulations,
+        &mut rng,
+    )
+}
+
+/// `conditional_on_hero_hand` drawing every deal from `rng` instead of a seed
+///
+/// # Errors
+/// Returns the same errors as `conditional_on_hero_hand`.
+#[allow(clippy::cast_precision_loss)]
+pub fn conditional_on_hero_hand_with_rng(
+    hole_cards: &[Card],
+    villain: &RangePlayer,
+    board: &[Card],
+    min_type: HandType,
+    num_simulations: u32,
+    rng: &mut impl RngCore,
+) -> HoldemResult<ConditionalEquity> {
+    if hole_cards.len() != 2 {
+        return Err(HoldemError::InvalidCardCount {
+            expected: "2",
+            got: hole_cards.len(),
+        });
+    }
+    if board.len() > 5 {
+        return Err(HoldemError::BoardTooLarge(board.len()));
+    }
+
+    let mut known_cards: HashSet<Card> = HashSet::new();
+    for &card in hole_cards.iter().chain(board) {
+        if !known_cards.insert(card) {
+            return Err(HoldemError::DuplicateCard(card.to_string()));
+        }
+    }
+
+    let villain_range = match villain {
+        RangePlayer::Specific(c1, c2) => CardDistribution::from_hand(*c1, *c2),
+        RangePlayer::Random => crate::range::named("any_two").unwrap_or_default(),
+        RangePlayer::Range(dist) => dist.clone(),
+    }
+    .filter_excluding(&known_cards);
+    let sampler = WeightedSampler::from_distribution(&villain_range)
+        .map_err(|_| HoldemError::NoValidCombinations)?;
+
+    let remaining: Vec<Card> = FULL_DECK
+        .iter()
+        .filter(|c| !known_cards.contains(c))
+        .copied()
+        .collect();
+    let hero = (hole_cards[0], hole_cards[1]);
+
+    let (mut reached, mut wins, mut ties) = (0u64, 0u64, 0u64);
+    let mut equity_sum = 0.0;
+
+    for _ in 0..num_simulations {
+        let villain_hand = villain_range.hands()[sampler.sample(rng)];
+        let (hero_rank, share) = heads_up_showdown(hero, villain_hand, board, &remaining, rng)?;
+        if hero_rank.hand_type < min_type {
+            continue;
+        }
+        reached += 1;
+
+        if share >= 1.0 {
+            wins += 1;
+        } else if share > 0.0 {
+            ties += 1;
+        }
+        equity_sum += share;
+    }
+
+    let per_reached = |x: f64| if reached == 0 { 0.0 } else { x / reached as f64 };
+    Ok(ConditionalEquity {
+        reach_probability: if num_simulations == 0 {
+            0.0
+        } else {
+            reached as f64 / f64::from(num_simulations)
+        },
+        win_rate: per_reached(wins as f64),
+        tie_rate: per_reached(ties as f64),
+        equity: per_reached(equity_sum),
+        reached,
+        total_simulations: u64::from(num_simulations),
+    })
+}
+
+/// Each hero starting hand's equity against a whole villain range
+///
+/// Every hero combo that avoids the board plays `num_simulations` showdowns
+/// against villain combos sampled by weight and random runouts. Combos are
+/// then averaged, by hero's range weights, into one equity per canonical
+/// hand, listed in the order each hand first appears in `hero`. Each combo
+/// is seeded from `seed` and its own cards, so the grid does not depend on
+/// the order of either range.
+///
+/// # Errors
+/// Returns an error if the board has more than 5 cards or duplicates, or
+/// if no hero combo has a villain combo left to play against.
+pub fn calculate_range_grid(
+    hero: &CardDistribution,
+    villain: &CardDistribution,
+    board: &[Card],
+    num_simulations: u32,
+    seed: Option<u64>,
+) -> HoldemResult<Vec<(CanonicalHand, f64)>> {
+    if board.len() > 5 {
+        return Err(HoldemError::BoardTooLarge(board.len()));
+    }
+    let mut board_cards: HashSet<Card> = HashSet::new();
+    for &card in board {
+        if !board_cards.insert(card) {
+            return Err(HoldemError::DuplicateCard(card.to_string()));
+        }
+    }
+
+    let base_seed = seed.unwrap_or_else(|| DeterministicRng::from_entropy().next_u64());
+
+    let mut order: Vec<CanonicalHand> = Vec::new();
+    let mut totals: HashMap<CanonicalHand, (f64, f64)> = HashMap::new();
+
+    for (i, &(h1, h2)) in hero.hands().iter().enumerate() {
+        let weight = hero.weight(i);
+        if weight <= 0.0 || board_cards.contains(&h1) || board_cards.contains(&h2) {
+            continue;
+        }
+        let mut known_cards = board_cards.clone();
+        known_cards.extend([h1, h2]);
+        let villain_range = villain.filter_excluding(&known_cards);
+        let Ok(sampler) = WeightedSampler::from_distribution(&villain_range) else {
+            continue;
+        };
+
+        let mut held = [usize::from(h1.to_index()), usize::from(h2.to_index())];
+        held.sort_unstable();
+        let mut rng = DeterministicRng::seed_from_u64(derive_seed(base_seed, &held));
+        let remaining: Vec<Card> = FULL_DECK
+            .iter()
+            .filter(|c| !known_cards.contains(c))
+            .copied()
+            .collect();
+
+        let mut equity_sum = 0.0;
+        for _ in 0..num_simulations {
+            let villain_hand = villain_range.hands()[sampler.sample(&mut rng)];
+            equity_sum +=
+                heads_up_showdown((h1, h2), villain_hand, board, &remaining, &mut rng)?.1;
+        }
+        let equity = if num_simulations == 0 {
+            0.0
+        } else {
+            equity_sum / f64::from(num_simulations)
+        };
+
+        let hand = canonize_hole_cards(&[h1, h2]);
+        let entry = totals.entry(hand).or_insert_with(|| {
+            order.push(hand);
+            (0.0, 0.0)
+        });
+        entry.0 += equity * weight;
+        entry.1 += weight;
+    }
+
+    if order.is_empty() {
+        return Err(HoldemError::NoValidCombinations);
+    }
+    Ok(order
+        .into_iter()
+        .map(|hand| {
+            let (sum, weight) = totals[&hand];
+            (hand, sum / weight)
+        })
+        .collect())
+}
+
+/// Deal one runout and play hero against villain heads-up
+///
+/// The rest of the board comes from `remaining` (which must exclude hero's
+/// cards and the board) minus villain's cards. Returns hero's best hand and
+/// hero's share of the pot: 1 for a win, split evenly on a tie.
+#[allow(clippy::cast_precision_loss)]
+fn heads_up_showdown(
+    hero: (Card, Card),
+    villain: (Card, Card),
+    board: &[Card],
+    remaining: &[Card],
+    rng: &mut impl RngCore,
+) -> HoldemResult<(HandRank, f64)> {
+    let mut dealer = Dealer::new(
+        remaining
+            .iter()
+            .filter(|&&c| c != villain.0 && c != villain.1)
+            .copied()
+            .collect(),
+    );
+    dealer.reshuffle(rng);
+
+    let mut full_board = board.to_vec();
+    full_board.extend_from_slice(dealer.deal_runout(5 - board.len()));
+    let mut hero_hand = vec![hero.0, hero.1];
+    hero_hand.extend(full_board.iter().copied());
+    let mut villain_hand = vec![villain.0, villain.1];
+    villain_hand.extend(full_board);
+
+    let results = find_winners_detailed(&[hero_hand, villain_hand])?;
+    let winners = results.iter().filter(|r| r.is_winner).count();
+    let share = if results[0].is_winner { 1.0 / winners as f64 } else { 0.0 };
+    Ok((results[0].rank.clone(), share))
+}
+
+/// Split one showdown's chips into main and side pots
+///
+/// Each pot layer is contested only by the players who committed at least
+/// that much, and is split evenly among the best of them.
+#[allow(clippy::cast_precision_loss)]
+fn settle_side_pots(commitments: &[u64], ranks: &[HandRank], payouts: &mut [f64]) {
+    let mut levels: Vec<u64> = commitments.iter().copied().filter(|&c| c > 0).collect();
+    levels.sort_unstable();
+    levels.dedup();
+
+    let mut previous = 0;
+    for level in levels {
+        let eligible: Vec<usize> = (0..commitments.len())
+            .filter(|&i| commitments[i] >= level)
+            .collect();
+        let pot = (level - previous) * eligible.len() as u64;
+        let best = eligible.iter().map(|&i| &ranks[i]).max();
+        let winners: Vec<usize> =
+            eligible.into_iter().filter(|&i| Some(&ranks[i]) == best).collect();
+        for &w in &winners {
+            payouts[w] += pot as f64 / winners.len() as f64;
+        }
+        previous = level;
+    }
+}
+
+/// Expected chips each all-in player gets back, accounting for side pots
+///
+/// Every player is paired with the chips they committed. A player can only
+/// win, from each opponent, as much as they matched themselves; the excess
+/// forms side pots contested by the bigger stacks. Random players are dealt
+/// fresh hole cards every simulation. The returned amounts sum to the total
+/// committed.
+///
+/// # Errors
+/// Returns an error if:
+/// - Fewer than 2 players or more than 5 board cards
+/// - Duplicate cards or an invalid player hand
+/// - Any player is a range
+pub fn allin_payouts(
+    players: &[(PlayerHand, u64)],
+    board: &[Card],
+    num_simulations: u32,
+    seed: Option<u64>,
+) -> HoldemResult<Vec<f64>> {
+    let mut rng = DeterministicRng::from_seed(seed);
+    allin_payouts_with_rng(players, board, num_simulations, &mut rng)
+}
+
+/// `allin_payouts` drawing every runout from `rng` instead of a seed
+///
+/// # Errors
+/// Returns the same errors as `allin_payouts`.
+#[allow(clippy::cast_precision_loss)]
+pub fn allin_payouts_with_rng(
+    players: &[(PlayerHand, u64)],
+    board: &[Card],
+    num_simulations: u32,
+    rng: &mut impl RngCore,
+) -> HoldemResult<Vec<f64>> {
+    let request = EquityRequest::new(
+        players.iter().map(|(hand, _)| hand.clone()).collect(),
+        board.to_vec(),
+    )
+    .with_simulations(num_simulations);
+    validate_equity_request(&request)?;
+    if request.players.iter().any(PlayerHand::is_range) {
+        return Err(HoldemError::InvalidRange(
+            "range players are not supported in all-in payouts".to_string(),
+        ));
+    }
+
+    let commitments: Vec<u64> = players.iter().map(|&(_, chips)| chips).collect();
+    let mut simulation = EquitySimulation::new(Cow::Borrowed(&request))?;
+    let mut payouts = vec![0.0; players.len()];
+
+    for _ in 0..num_simulations {
+        let holes = simulation.deal_holes(rng)?;
+        let mut full_board = board.to_vec();
+        full_board.extend_from_slice(simulation.dealer.deal_runout(simulation.cards_needed_board));
+
+        let ranks = holes
+            .into_iter()
+            .map(|mut hand| {
+                hand.extend(full_board.iter().copied());
+                evaluate_hand(&hand)
+            })
+            .collect::<HoldemResult<Vec<HandRank>>>()?;
+        settle_side_pots(&commitments, &ranks, &mut payouts);
+    }
+
+    if num_simulations > 0 {
+        for payout in &mut payouts {
+            *payout /= f64::from(num_simulations);
+        }
+    }
+    Ok(payouts)
+}
+
+/// Exact heads-up equity on each street of a fixed runout
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StreetEquities {
+    /// Both players' equity with the flop dealt (turn and river enumerated)
+    pub flop: [f64; 2],
+    /// Both players' equity with the turn dealt (river enumerated)
+    pub turn: [f64; 2],
+    /// Showdown result on the river: 1.0 / 0.0, or 0.5 each for a split
+    pub river: [f64; 2],
+}
+
+/// How equity shifted street by street for two hands and a known runout
+///
+/// Every remaining turn and river card is enumerated, so the numbers are
+/// exact: 990 runouts on the flop and 44 on the turn.
+///
+/// # Errors
+/// Returns `DuplicateCard` if a card appears twice among the hands and board.
+pub fn equity_by_street(
+    p1: (Card, Card),
+    p2: (Card, Card),
+    flop: [Card; 3],
+    turn: Card,
+    river: Card,
+) -> HoldemResult<StreetEquities> {
+    let holes = [[p1.0, p1.1], [p2.0, p2.1]];
+    let board = [flop[0], flop[1], flop[2], turn, river];
+
+    let mut known_cards: HashSet<Card> = HashSet::new();
+    for &card in holes.iter().flatten().chain(&board) {
+        if !known_cards.insert(card) {
+            return Err(HoldemError::DuplicateCard(card.to_string()));
+        }
+    }
+
+    Ok(StreetEquities {
+        flop: exact_heads_up_equity(holes, &board[..3]),
+        turn: exact_heads_up_equity(holes, &board[..4]),
+        river: exact_heads_up_equity(holes, &board),
+    })
+}
+
+/// Exact heads-up equity with every remaining board completion enumerated
+///
+/// Returns `(p1 wins, p2 wins, ties)` as fractions of all runouts, so the
+/// three add up to 1. Cards in `dead` are never dealt. Preflop this walks
+/// all C(48,5) = 1,712,304 boards; from the flop on it is near-instant.
+///
+/// # Errors
+/// Returns `BoardTooLarge` for more than 5 board cards, `TooManyCards` if the
+/// dead cards leave too few to finish the board, or `DuplicateCard` if a card
+/// appears twice among the hands, board and dead cards.
+pub fn exact_heads_up(
+    p1: (Card, Card),
+    p2: (Card, Card),
+    board: &[Card],
+    dead: &[Card],
+) -> HoldemResult<(f64, f64, f64)> {
+    if board.len() > 5 {
+        return Err(HoldemError::BoardTooLarge(board.len()));
+    }
+    check_deck_size(2, dead.len())?;
+
+    let holes = [[p1.0, p1.1], [p2.0, p2.1]];
+    let mut known_cards: HashSet<Card> = HashSet::new();
+    for &card in holes.iter().flatten().chain(board).chain(dead) {
+        if !known_cards.insert(card) {
+            return Err(HoldemError::DuplicateCard(card.to_string()));
+        }
+    }
+
+    let [p1_wins, p2_wins, ties] = heads_up_outcomes(holes, board, dead);
+    Ok((p1_wins, p2_wins, ties))
+}
+
+/// Exact equity of two hands over every completion of `board`, ties split
+fn exact_heads_up_equity(holes: [[Card; 2]; 2], board: &[Card]) -> [f64; 2] {
+    let [p1_wins, p2_wins, ties] = heads_up_outcomes(holes, board, &[]);
+    [p1_wins + ties / 2.0, p2_wins + ties / 2.0]
+}
+
+/// Fractions of runouts won by each hand and tied, in that order
+///
+/// Callers ensure the hands, board and dead cards are distinct and leave
+/// enough cards to finish the board.
+#[allow(clippy::cast_precision_loss)]
+fn heads_up_outcomes(holes: [[Card; 2]; 2], board: &[Card], dead: &[Card]) -> [f64; 3] {
+    let remaining: Vec<Card> = FULL_DECK
+        .iter()
+        .filter(|c| {
+            !board.contains(c) && !dead.contains(c) && !holes.iter().flatten().any(|h| h == *c)
+        })
+        .copied()
+        .collect();
+
+    let mut hands = holes.map(|hole| {
+        let mut hand = [hole[0]; 7];
+        hand[..2].copy_from_slice(&hole);
+        hand[2..2 + board.len()].copy_from_slice(board);
+        hand
+    });
+    let mut counts = [0u64; 3];
+    deal_runouts(&remaining, 2 + board.len(), &mut hands, &mut counts);
+
+    let total = counts.iter().sum::<u64>() as f64;
+    counts.map(|count| count as f64 / total)
+}
+
+/// Fill `hands[..][slot..]` with each combination of `remaining` and tally
+/// the showdowns into `counts`
+fn deal_runouts(
+    remaining: &[Card],
+    slot: usize,
+    hands: &mut [[Card; 7]; 2],
+    counts: &mut [u64; 3],
+) {
+    if slot == 7 {
+        let outcome = match hand_score(&hands[0]).cmp(&hand_score(&hands[1])) {
+            std::cmp::Ordering::Greater => 0,
+            std::cmp::Ordering::Less => 1,
+            std::cmp::Ordering::Equal => 2,
+        };
+        counts[outcome] += 1;
+        return;
+    }
+
+    let last = remaining.len() - (7 - slot);
+    for (i, &card) in remaining[..=last].iter().enumerate() {
+        hands[0][slot] = card;
+        hands[1][slot] = card;
+        deal_runouts(&remaining[i + 1..], slot + 1, hands, counts);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::parse_cards;
+    use crate::evaluator::HandType;
+
+    fn cards(s: &str) -> Vec<Card> {
+        parse_cards(s).unwrap()
+    }
+
+    #[test]
+    fn test_equity_aa_vs_kk() {
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah As")),
+                PlayerHand::new(cards("Kh Ks")),
+            ],
+            vec![],
+        )
+        .with_simulations(10_000)
+        .with_seed(42);
+
+        let result = calculate_equity(&request).unwrap();
+
+        assert_eq!(result.players.len(), 2);
+        // AA should have ~82% equity vs KK
+        assert!(result.players[0].equity > 0.75);
+        assert!(result.players[0].equity < 0.90);
+        assert!(result.players[1].equity > 0.10);
+        assert!(result.players[1].equity < 0.25);
+    }
+
+    #[test]
+    fn test_classify_heads_up() {
+        let matchup = |a: &str, b: &str| {
+            let request = EquityRequest::new(
+                vec![PlayerHand::new(cards(a)), PlayerHand::new(cards(b))],
+                vec![],
+            )
+            .with_simulations(5_000)
+            .with_seed(42);
+            calculate_equity(&request).unwrap().classify_heads_up()
+        };
+
+        assert_eq!(matchup("Ah Kh", "2c 2d"), Some(MatchupClass::Coinflip));
+        assert_eq!(matchup("Ah As", "Kh Ks"), Some(MatchupClass::Crush));
+        assert_eq!(matchup("Kc Kd", "Ah As"), Some(MatchupClass::Crush));
+        assert_eq!(matchup("Ah Kc", "As Qd"), Some(MatchupClass::Dominated));
+
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah As")),
+                PlayerHand::new(cards("Kh Ks")),
+                PlayerHand::new(cards("Qh Qs")),
+            ],
+            vec![],
+        )
+        .with_simulations(100)
+        .with_seed(1);
+        assert_eq!(calculate_equity(&request).unwrap().classify_heads_up(), None);
+    }
+
+    #[test]
+    fn test_equity_with_board() {
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Kh")),
+                PlayerHand::new(cards("7h 2c")),
+            ],
+            cards("Qh Jh Th"), // AK has royal flush draw
+        )
+        .with_simulations(10_000)
+        .with_seed(42);
+
+        let result = calculate_equity(&request).unwrap();
+
+        // With the flush draw, AK should be heavily favored
+        assert!(result.players[0].equity > 0.80);
+    }
+
+    #[test]
+    fn test_equity_sums_to_one() {
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah As")),
+                PlayerHand::new(cards("Kh Ks")),
+                PlayerHand::new(cards("Qh Qs")),
+            ],
+            vec![],
+        )
+        .with_simulations(5_000)
+        .with_seed(42);
+
+        let result = calculate_equity(&request).unwrap();
+
+        let total_equity: f64 = result.players.iter().map(|p| p.equity).sum();
+        assert!((total_equity - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_equity_deterministic_with_seed() {
+        let request1 = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Kh")),
+                PlayerHand::new(cards("7s 2d")),
+            ],
+            vec![],
+        )
+        .with_simulations(1_000)
+        .with_seed(12345);
+
+        let request2 = request1.clone();
+
+        let result1 = calculate_equity(&request1).unwrap();
+        let result2 = calculate_equity(&request2).unwrap();
+
+        assert_eq!(result1.players[0].equity, result2.players[0].equity);
+    }
+
+    #[test]
+    fn test_equity_mixed_specific_random_and_range() {
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Ad")),
+                PlayerHand::random(),
+                PlayerHand::range(vec!["KK".to_string(), "QQ".to_string()]),
+            ],
+            vec![],
+        )
+        .with_simulations(1_000)
+        .with_seed(7);
+
+        let result = calculate_equity(&request).unwrap();
+        assert_eq!(result.players.len(), 3);
+
+        let total_equity: f64 = result.players.iter().map(|p| p.equity).sum();
+        assert!((total_equity - 1.0).abs() < 0.01);
+        assert!(result.players[0].equity > result.players[2].equity);
+        assert!(result.players[2].equity > result.players[1].equity);
+
+        assert_eq!(result.players[0].hand_description, "Ah Ad");
+        assert_eq!(result.players[2].hand_description, "KK, QQ");
+        assert_eq!(result.players[2].combos, 12);
+
+        // Same seed, same answer
+        let again = calculate_equity(&request).unwrap();
+        assert!((again.players[2].equity - result.players[2].equity).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_equity_reports_combo_counts() {
+        // Simple engine: known cards are one combo, a random hand is any of
+        // the C(50, 2) combos left
+        let simple = EquityRequest::new(
+            vec![PlayerHand::new(cards("Ah Ad")), PlayerHand::random()],
+            vec![],
+        )
+        .with_simulations(100)
+        .with_seed(1);
+        let result = calculate_equity(&simple).unwrap();
+        assert_eq!(result.players[0].combos, 1);
+        assert_eq!(result.players[1].combos, 1225);
+
+        let flop = EquityRequest::new(
+            vec![PlayerHand::new(cards("Ah Ad")), PlayerHand::random()],
+            cards("Ks 7c 2h"),
+        )
+        .with_dead_cards(cards("2c"))
+        .with_simulations(100)
+        .with_seed(1);
+        // 52 cards minus AhAd, the flop and the dead card leave 46
+        assert_eq!(calculate_equity(&flop).unwrap().players[1].combos, 46 * 45 / 2);
+
+        // KK loses the three Kd combos to the dead card, QQ the Qs ones to the board
+        let ranged = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Ad")),
+                PlayerHand::range(vec!["KK".to_string(), "QQ".to_string()]),
+            ],
+            cards("Qs 7s 2c"),
+        )
+        .with_dead_cards(cards("Kd"))
+        .with_simulations(100)
+        .with_seed(1);
+        let result = calculate_equity(&ranged).unwrap();
+        assert_eq!(result.players[0].combos, 1);
+        assert_eq!(result.players[1].combos, 6);
+    }
+
+    #[test]
+    fn test_equity_range_player_errors() {
+        let invalid = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Ad")),
+                PlayerHand::range(vec!["ZZ".to_string()]),
+            ],
+            vec![],
+        );
+        assert!(matches!(
+            calculate_equity(&invalid),
+            Err(HoldemError::InvalidRange(_))
+        ));
+
+        let mut with_cards = PlayerHand::range(vec!["KK".to_string()]);
+        with_cards.cards = cards("Kh Kd");
+        let request = EquityRequest::new(vec![PlayerHand::new(cards("Ah Ad")), with_cards], vec![]);
+        assert!(calculate_equity(&request).is_err());
+
+        let ranged = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Ad")),
+                PlayerHand::range(vec!["KK".to_string()]),
+            ],
+            vec![],
+        );
+        assert!(calculate_equity_batched(&ranged, 100).is_err());
+
+        // Range players can't be combined with outputs the range engine
+        // doesn't produce
+        for request in [
+            ranged.clone().with_teams(vec![vec![0]]),
+            ranged.clone().with_hand_distribution(true),
+        ] {
+            assert!(matches!(
+                calculate_equity(&request),
+                Err(HoldemError::InvalidRange(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_equity_shared_card_errors() {
+        // Two specific hands sharing the ace of hearts
+        let shared = EquityRequest::new(
+            vec![PlayerHand::new(cards("Ah Kd")), PlayerHand::new(cards("Ah Qc"))],
+            vec![],
+        );
+        let err = calculate_equity(&shared).unwrap_err();
+        assert!(matches!(&err, HoldemError::DuplicateCard(card) if card == "Ah"));
+        assert!(err.to_string().contains("Ah"), "{err}");
+
+        // With the Ac dead, every remaining AA combo uses one of player 0's aces
+        let blocked = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Ad")),
+                PlayerHand::range(vec!["AA".to_string()]),
+            ],
+            vec![],
+        )
+        .with_dead_cards(cards("Ac"));
+        let err = calculate_equity(&blocked).unwrap_err();
+        assert!(matches!(err, HoldemError::RangeBlocked(1)), "{err:?}");
+        assert!(err.to_string().contains("Player 1"));
+    }
+
+    /// `players[i]` must describe request player `i`, whatever its equity
+    fn assert_players_aligned(result: &EquityResult, num_players: usize) {
+        assert_eq!(result.players.len(), num_players);
+        for (i, player) in result.players.iter().enumerate() {
+            assert_eq!(player.index, i);
+        }
+    }
+
+    #[test]
+    fn test_equity_players_aligned_with_zero_equity() {
+        // Player 1 is drawing dead against the royal flush on a full board
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Th 3c")),
+                PlayerHand::new(cards("As Ks")),
+                PlayerHand::random(),
+            ],
+            cards("Ah Kh Qh Jh 2c"),
+        )
+        .with_simulations(200)
+        .with_seed(1);
+
+        let result = calculate_equity(&request).unwrap();
+        assert_players_aligned(&result, 3);
+        assert!((result.players[0].equity - 1.0).abs() < f64::EPSILON);
+        assert_eq!(result.players[1].win_count, 0);
+        assert!(result.players[1].equity.abs() < f64::EPSILON);
+        assert!(result.players[2].equity.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_equity_players_aligned_across_features() {
+        let players = vec![
+            PlayerHand::new(cards("7c 2d")),
+            PlayerHand::new(cards("Ah Ad")),
+            PlayerHand::random(),
+            PlayerHand::new(cards("Ks Kd")),
+        ];
+
+        let teamed = EquityRequest::new(players.clone(), vec![])
+            .with_simulations(200)
+            .with_seed(3)
+            .with_teams(vec![vec![3, 1]])
+            .with_hand_distribution(true);
+        let result = calculate_equity(&teamed).unwrap();
+        assert_players_aligned(&result, 4);
+        assert_eq!(result.teams[0].members, vec![3, 1]);
+        let team = result.players[1].equity + result.players[3].equity;
+        assert!((result.teams[0].equity - team).abs() < 1e-9);
+
+        for batch in calculate_equity_batched(&teamed, 50).unwrap() {
+            assert_players_aligned(&batch.unwrap(), 4);
+        }
+
+        let mut ranged = players;
+        ranged[2] = PlayerHand::range(vec!["QQ".to_string()]);
+        let request = EquityRequest::new(ranged, vec![]).with_simulations(100).with_seed(3);
+        let result = calculate_equity(&request).unwrap();
+        assert_players_aligned(&result, 4);
+        assert_eq!(result.players[2].combos, 6);
+        assert_eq!(result.players[3].hand_description, "Ks Kd");
+    }
+
+    #[test]
+    fn test_equity_request_builder() {
+        let request = EquityRequest::builder()
+            .player(PlayerHand::new(cards("Ah Kh")))
+            .player(PlayerHand::random())
+            .board(cards("Qh Jh 2c"))
+            .dead(cards("3d"))
+            .simulations(500)
+            .seed(9)
+            .build()
+            .unwrap();
+        assert_eq!(request.players.len(), 2);
+        assert_eq!(request.board.len(), 3);
+        assert_eq!(request.dead_cards.len(), 1);
+        assert_eq!(request.num_simulations, 500);
+        assert_eq!(request.seed, Some(9));
+    }
+
+    #[test]
+    fn test_equity_request_builder_validates() {
+        let duplicate = EquityRequest::builder()
+            .player(PlayerHand::new(cards("Ah Kh")))
+            .player(PlayerHand::new(cards("Ah Qd")))
+            .build();
+        assert!(matches!(duplicate, Err(HoldemError::DuplicateCard(_))));
+
+        let on_board = EquityRequest::builder()
+            .player(PlayerHand::new(cards("Ah Kh")))
+            .player(PlayerHand::random())
+            .board(cards("Kh 7c 2d"))
+            .build();
+        assert!(matches!(on_board, Err(HoldemError::DuplicateCard(_))));
+
+        let lonely = EquityRequest::builder()
+            .player(PlayerHand::new(cards("Ah Kh")))
+            .build();
+        assert!(matches!(lonely, Err(HoldemError::NotEnoughPlayers(2))));
+    }
+
+    #[test]
+    fn test_equity_batched_matches_full_run() {
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Kh")),
+                PlayerHand::random(),
+            ],
+            cards("Qh 7c 2h"),
+        )
+        .with_simulations(1_050)
+        .with_seed(99);
+
+        let batches: Vec<EquityResult> =
+            calculate_equity_batched(&request, 250).unwrap().map(Result::unwrap).collect();
+        let totals: Vec<u64> = batches.iter().map(|r| r.total_simulations).collect();
+        assert_eq!(totals, vec![250, 500, 750, 1_000, 1_050]);
+
+        let full = calculate_equity(&request).unwrap();
+        let last = batches.last().unwrap();
+        for (a, b) in last.players.iter().zip(&full.players) {
+            assert_eq!(a.win_count, b.win_count);
+            assert_eq!(a.tie_count, b.tie_count);
+            assert!((a.equity - b.equity).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_malformed_simulation_returns_error() {
+        // Bypasses validation: a three-card "hole" makes eight-card hands
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand {
+                    cards: cards("Ah Kh Qh"),
+                    is_random: false,
+                    range: Vec::new(),
+                    sampled_range: None,
+                },
+                PlayerHand::random(),
+            ],
+            cards("2c 3d 4s 5h 9c"),
+        );
+        let mut simulation = EquitySimulation::new(Cow::Owned(request)).unwrap();
+        assert!(matches!(
+            simulation.run(10, &mut DeterministicRng::seed_from_u64(1)),
+            Err(HoldemError::InvalidCardCount { expected: "5-7", got: 8 })
+        ));
+    }
+
+    #[test]
+    fn test_equity_batched_validates_request() {
+        let request = EquityRequest::new(vec![PlayerHand::new(cards("Ah Kh"))], vec![]);
+        assert!(calculate_equity_batched(&request, 100).is_err());
+    }
+
+    #[test]
+    fn test_equity_session_accumulates_across_steps() {
+        let request = EquityRequest::new(
+            vec![PlayerHand::new(cards("Ah Kh")), PlayerHand::random()],
+            cards("Qh 7c 2h"),
+        )
+        .with_simulations(1_000)
+        .with_seed(7);
+        let mut session = EquitySession::new(request.clone()).unwrap();
+        assert_eq!(session.results().total_simulations, 0);
+
+        assert_eq!(session.step(300).unwrap().total_simulations, 300);
+        assert_eq!(session.remaining(), 700);
+        assert_eq!(session.step(300).unwrap().total_simulations, 600);
+        // The last step stops at the requested total
+        let last = session.step(1_000).unwrap();
+        assert_eq!(last.total_simulations, 1_000);
+        assert!(session.is_finished());
+        assert_eq!(session.step(100).unwrap().total_simulations, 1_000);
+
+        let full = calculate_equity(&request).unwrap();
+        for (a, b) in last.players.iter().zip(&full.players) {
+            assert_eq!((a.win_count, a.tie_count), (b.win_count, b.tie_count));
+        }
+
+        let ranged = EquityRequest::new(
+            vec![PlayerHand::new(cards("Ah Kh")), PlayerHand::range(vec!["QQ".to_string()])],
+            vec![],
+        );
+        assert!(EquitySession::new(ranged).is_err());
+    }
+
+    #[test]
+    fn test_equity_golden_value_with_seed() {
+        // Pinned by DeterministicRng: must stay identical across rand
+        // upgrades and between native and WASM builds
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Kh")),
+                PlayerHand::new(cards("Qs Qd")),
+            ],
+            vec![],
+        )
+        .with_simulations(10_000)
+        .with_seed(42);
+
+        let result = calculate_equity(&request).unwrap();
+        assert_eq!(result.players[0].win_count, 4588);
+        assert!((result.players[0].equity - 0.461_15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_team_equity() {
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Ad")),
+                PlayerHand::new(cards("Ks Kc")),
+                PlayerHand::new(cards("Qs Qc")),
+            ],
+            vec![],
+        )
+        .with_teams(vec![vec![1, 2]])
+        .with_simulations(5_000)
+        .with_seed(7);
+
+        let result = calculate_equity(&request).unwrap();
+        let team = &result.teams[0];
+        assert_eq!(team.members, vec![1, 2]);
+
+        // The team beats either member alone and matches the summed equity
+        assert!(team.equity > result.players[1].equity);
+        assert!(team.equity > result.players[2].equity);
+        assert!((team.equity - result.team_equity(&[1, 2])).abs() < 1e-9);
+        assert!((team.equity + result.players[0].equity - 1.0).abs() < 1e-9);
+        assert!(team.win_rate <= team.equity);
+    }
+
+    #[test]
+    fn test_team_invalid_member() {
+        let players = vec![PlayerHand::new(cards("Ah Ad")), PlayerHand::random()];
+        let request = EquityRequest::new(players.clone(), vec![]).with_teams(vec![vec![0, 2]]);
+        assert!(matches!(
+            calculate_equity(&request),
+            Err(HoldemError::InvalidTeamMember(2))
+        ));
+
+        let request = EquityRequest::new(players, vec![]).with_teams(vec![vec![1, 1]]);
+        assert!(matches!(
+            calculate_equity(&request),
+            Err(HoldemError::InvalidTeamMember(1))
+        ));
+    }
+
+    #[test]
+    fn test_hand_type_distribution() {
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Ad")),
+                PlayerHand::new(cards("7c 2d")),
+            ],
+            vec![],
+        )
+        .with_hand_distribution(true)
+        .with_simulations(5_000)
+        .with_seed(11);
+
+        let result = calculate_equity(&request).unwrap();
+        let aa = result.players[0].hand_type_frequencies.unwrap();
+        let total: f64 = aa.iter().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        // A pocket pair always ends with one pair or better
+        assert!(aa[HandType::HighCard as usize] < f64::EPSILON);
+        assert!(aa[HandType::RoyalFlush as usize] < 0.001);
+        assert!(result.players[1].hand_type_frequencies.is_some());
+    }
+
+    #[test]
+    fn test_hand_type_distribution_off_by_default() {
+        let request = EquityRequest::new(
+            vec![PlayerHand::new(cards("Ah Ad")), PlayerHand::random()],
+            vec![],
+        )
+        .with_simulations(100)
+        .with_seed(1);
+
+        let result = calculate_equity(&request).unwrap();
+        assert!(result.players.iter().all(|p| p.hand_type_frequencies.is_none()));
+    }
+
+    #[test]
+    fn test_canonical_matchup_quick() {
+        let qq = CanonicalHand::parse("QQ").unwrap();
+        let aks = CanonicalHand::parse("AKs").unwrap();
+
+        let result = canonical_matchup_quick(&[qq, aks], &[], 20_000).unwrap();
+        assert_eq!(result.players.len(), 2);
+        // QQ vs AKs is a near coin flip slightly favoring the pair
+        assert!(result.players[0].equity > 0.48 && result.players[0].equity < 0.60);
+
+        // Combos avoid the board and each other
+        let board = cards("Qc Qd 2h");
+        let result = canonical_matchup_quick(&[qq, qq], &board, 100);
+        assert!(matches!(result, Err(HoldemError::NoValidCombinations)));
+    }
+
+    #[test]
+    fn test_equity_breakdown_by_canonical_hand() {
+        let hero = (Card::parse("Ah").unwrap(), Card::parse("As").unwrap());
+        let villain = CardDistribution::from_range(
+            &["AA".to_string(), "KK".to_string(), "22".to_string()],
+            &[],
+        )
+        .unwrap();
+        let breakdown = calculate_equity_breakdown(hero, &villain, &[], &[], 300).unwrap();
+
+        let equity_vs = |notation: &str| {
+            let hand = CanonicalHand::parse(notation).unwrap();
+            breakdown.iter().find(|(h, _)| *h == hand).unwrap().1
+        };
+        assert_eq!(breakdown.len(), 3);
+        // Only AdAc is left: a near-certain chop
+        let aa = equity_vs("AA");
+        assert!(aa > 0.40 && aa < 0.60, "AA {aa}");
+        assert!(equity_vs("KK") > 0.75, "KK {}", equity_vs("KK"));
+        assert!(equity_vs("22") > 0.75, "22 {}", equity_vs("22"));
+    }
+
+    #[test]
+    fn test_dealer_deals_each_card_once_per_shuffle() {
+        let known: HashSet<Card> = cards("Ah Kh").into_iter().collect();
+        let mut dealer = Dealer::excluding(&known);
+        let mut rng = DeterministicRng::seed_from_u64(5);
+
+        for _ in 0..3 {
+            dealer.reshuffle(&mut rng);
+            let mut dealt: Vec<Card> = dealer.deal_runout(5).to_vec();
+            for _ in 0..20 {
+                dealt.extend(dealer.deal_hole());
+            }
+            let unique: HashSet<Card> = dealt.iter().copied().collect();
+            assert_eq!(unique.len(), 45);
+            assert!(unique.is_disjoint(&known));
+        }
+    }
+
+    #[test]
+    fn test_seeded_card_streams_are_pinned() {
+        // Pinned before dealing moved into `Dealer`: must not change
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Ah Kh")),
+                PlayerHand::random(),
+                PlayerHand::new(cards("7c 7d")),
+            ],
+            cards("Qh 2s 9h"),
+        )
+        .with_simulations(2000)
+        .with_seed(99);
+        let result = calculate_equity(&request).unwrap();
+        let counts: Vec<(u64, u64)> =
+            result.players.iter().map(|p| (p.win_count, p.tie_count)).collect();
+        assert_eq!(counts, vec![(1066, 10), (358, 10), (566, 0)]);
+
+        let equity = equity_vs_random(&cards("Ah As"), &[], 2, 2000, Some(99)).unwrap();
+        assert!((equity - 0.721_333_333_333_333_4).abs() < 1e-12, "{equity}");
+
+        let villain = CardDistribution::from_range(&["QQ".to_string(), "AKs".to_string()], &[])
+            .unwrap();
+        let hero = cards("Jh Ts");
+        let request = RangeEquityRequest::new(
+            vec![
+                RangePlayer::specific(hero[0], hero[1]),
+                RangePlayer::range(villain),
+                RangePlayer::random(),
+            ],
+            cards("9h 8d 2c"),
+        )
+        .with_simulations(1000)
+        .with_seed(99);
+        // Re-pinned when each range combination got its own derived seed
+        let result = calculate_equity_with_ranges(&request).unwrap();
+        let expected = [0.308_1, 0.513_35, 0.178_55];
+        for (player, want) in result.players.iter().zip(expected) {
+            assert!((player.equity - want).abs() < 1e-12, "{} vs {want}", player.equity);
+        }
+    }
 
-    // Collect known cards
-    let mut known_cards: HashSet<Card> = HashSet::new();
-    for &card in hole_cards {
-        known_cards.insert(card);
+    #[test]
+    fn test_range_equity_independent_of_player_order() {
+        let range = |tokens: &[&str]| {
+            let tokens: Vec<String> = tokens.iter().map(ToString::to_string).collect();
+            RangePlayer::range(CardDistribution::from_range(&tokens, &[]).unwrap())
+        };
+        let board = cards("Th 7h 2c");
+        let run = |players: Vec<RangePlayer>| {
+            let request = RangeEquityRequest::new(players, board.clone())
+                .with_simulations(1000)
+                .with_seed(2061);
+            calculate_equity_with_ranges(&request).unwrap()
+        };
+
+        let forward = run(vec![range(&["AA", "KK"]), range(&["AKs", "QQ"])]);
+        let reversed = run(vec![range(&["AKs", "QQ"]), range(&["AA", "KK"])]);
+        assert_eq!(forward.total_combinations, reversed.total_combinations);
+        assert!((forward.players[0].equity - reversed.players[1].equity).abs() < 1e-12);
+        assert!((forward.players[1].equity - reversed.players[0].equity).abs() < 1e-12);
+
+        // Token order inside a range changes combo order, not the result
+        let shuffled = run(vec![range(&["KK", "AA"]), range(&["QQ", "AKs"])]);
+        assert!((forward.players[0].equity - shuffled.players[0].equity).abs() < 1e-12);
     }
-    for &card in board {
-        known_cards.insert(card);
+
+    #[test]
+    fn test_too_many_players_for_the_deck() {
+        // 24 players and a board need 53 cards
+        let mut players = vec![PlayerHand::random(); 24];
+        let request = EquityRequest::new(players.clone(), cards("Ah Kd 7c 5s 2h"));
+        assert!(matches!(
+            calculate_equity(&request),
+            Err(HoldemError::TooManyCards { needed: 53, available: 52 })
+        ));
+
+        // 23 players fit exactly
+        players.pop();
+        let request = EquityRequest::new(players, cards("Ah Kd 7c 5s 2h")).with_simulations(10);
+        assert!(calculate_equity(&request).is_ok());
+
+        // 11 random players fit, but not once 26 dead cards are added
+        let board = cards("Ah Kd 7c 5s 2h");
+        let dead: Vec<Card> =
+            FULL_DECK.iter().filter(|c| !board.contains(c)).take(26).copied().collect();
+        let request = EquityRequest::new(vec![PlayerHand::random(); 11], board)
+            .with_dead_cards(dead)
+            .with_simulations(10);
+        assert!(matches!(
+            calculate_equity(&request),
+            Err(HoldemError::TooManyCards { needed: 53, .. })
+        ));
+
+        let request = RangeEquityRequest::new(vec![RangePlayer::random(); 24], Vec::new());
+        assert!(matches!(
+            calculate_equity_with_ranges(&request),
+            Err(HoldemError::TooManyCards { .. })
+        ));
+        assert!(matches!(
+            equity_vs_random(&cards("Ah As"), &[], 24, 10, None),
+            Err(HoldemError::TooManyCards { .. })
+        ));
     }
 
-    // Build remaining deck
-    let remaining: Vec<Card> = FULL_DECK
-        .iter()
-        .filter(|c| !known_cards.contains(c))
-        .copied()
-        .collect();
+    #[test]
+    fn test_equity_vs_random() {
+        let hole = cards("Ah As");
+        let equity = equity_vs_random(&hole, &[], 1, 10_000, Some(42)).unwrap();
 
-    let cards_needed_board = 5 - board.len();
+        // AA vs 1 random should be ~85%
+        assert!(equity > 0.80);
+        assert!(equity < 0.90);
+    }
 
-    // Initialize RNG
-    let mut rng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_os_rng(),
-    };
+    #[test]
+    fn test_equity_vs_multiple_random() {
+        let hole = cards("Ah As");
+        let equity = equity_vs_random(&hole, &[], 5, 10_000, Some(42)).unwrap();
 
-    let mut equity_sum = 0.0;
-    let mut deck_remaining = remaining.clone();
+        // AA vs 5 random should be ~49%
+        assert!(equity > 0.40);
+        assert!(equity < 0.60);
+    }
 
-    for _ in 0..num_simulations {
-        deck_remaining.shuffle(&mut rng);
+    #[test]
+    fn test_conditional_on_making_flush() {
+        // Nut flush draw vs a set: 9 hearts among 45 unseen cards
+        let villain = RangePlayer::specific(Card::parse("Ks").unwrap(), Card::parse("Kd").unwrap());
+        let hole = cards("Ah 5h");
+        let board = cards("Kh 7h 2c");
+        let result =
+            conditional_on_hero_hand(&hole, &villain, &board, HandType::Flush, 4000, Some(42))
+                .unwrap();
+
+        // 1 - (36/45)(35/44) = 36.4%
+        assert!(result.reach_probability > 0.32, "reach {}", result.reach_probability);
+        assert!(result.reach_probability < 0.41, "reach {}", result.reach_probability);
+        // The flush loses whenever the board pairs for a full house: 67.8% wins
+        assert!(result.win_rate > 0.62, "win {}", result.win_rate);
+        assert!(result.win_rate < 0.74, "win {}", result.win_rate);
+        assert!(result.tie_rate.abs() < f64::EPSILON);
+        assert_eq!(result.total_simulations, 4000);
+
+        let any = conditional_on_hero_hand(&hole, &villain, &board, HandType::HighCard, 1000, Some(1))
+            .unwrap();
+        assert_eq!(any.reached, 1000);
+        assert!(any.equity < 0.40, "equity {}", any.equity);
+    }
 
-        let mut idx = 0;
+    #[test]
+    fn test_conditional_on_hero_hand_rejects_bad_input() {
+        let board = cards("Kh 7h 2c");
+        let err = conditional_on_hero_hand(
+            &cards("Kh 5h"),
+            &RangePlayer::random(),
+            &board,
+            HandType::Flush,
+            10,
+            None,
+        );
+        assert!(matches!(err, Err(HoldemError::DuplicateCard(_))));
 
-        // Deal runout
-        let runout: Vec<Card> = deck_remaining[idx..idx + cards_needed_board].to_vec();
-        idx += cards_needed_board;
+        let villain = RangePlayer::specific(Card::parse("Ah").unwrap(), Card::parse("Kd").unwrap());
+        let err =
+            conditional_on_hero_hand(&cards("Ah 5h"), &villain, &board, HandType::Flush, 10, None);
+        assert!(matches!(err, Err(HoldemError::NoValidCombinations)));
+    }
 
-        // Deal opponent hands
-        let mut opponent_hands: Vec<Vec<Card>> = Vec::with_capacity(num_opponents);
-        for _ in 0..num_opponents {
-            opponent_hands.push(deck_remaining[idx..idx + 2].to_vec());
-            idx += 2;
-        }
+    #[test]
+    fn test_allin_short_stack_wins_only_matched_chips() {
+        let players = vec![
+            (PlayerHand::new(cards("Ah As")), 100),
+            (PlayerHand::new(cards("Kh Ks")), 300),
+            (PlayerHand::new(cards("Qh Qs")), 300),
+        ];
+        // AA holds on a dry board: it takes the 300 main pot, KK the 400 side pot
+        let payouts = allin_payouts(&players, &cards("2c 7d 9h 3s 4c"), 10, Some(1)).unwrap();
+        assert_eq!(payouts, vec![300.0, 400.0, 0.0]);
+
+        // With the runout still to come, chips are conserved and the short
+        // stack can never collect more than the main pot
+        let payouts = allin_payouts(&players, &[], 2000, Some(7)).unwrap();
+        assert!((payouts.iter().sum::<f64>() - 700.0).abs() < 1e-6);
+        assert!(payouts[0] > 200.0 && payouts[0] < 300.0, "{payouts:?}");
+        assert!(payouts[1] > payouts[2], "{payouts:?}");
+    }
 
-        // Build complete board
-        let mut full_board = board.to_vec();
-        full_board.extend(runout);
+    #[test]
+    fn test_allin_split_side_pot() {
+        // The board plays for everyone: each pot layer is split among its contestants
+        let players = vec![
+            (PlayerHand::new(cards("2c 3d")), 50),
+            (PlayerHand::new(cards("2d 3c")), 200),
+            (PlayerHand::random(), 200),
+        ];
+        let payouts = allin_payouts(&players, &cards("Ah Kh Qh Jh Th"), 5, Some(3)).unwrap();
+        assert!((payouts[0] - 50.0).abs() < 1e-9);
+        assert!((payouts[1] - 200.0).abs() < 1e-9);
+        assert!((payouts[2] - 200.0).abs() < 1e-9);
+
+        let range = vec![
+            (PlayerHand::range(vec!["AA".to_string()]), 100),
+            players[1].clone(),
+        ];
+        assert!(matches!(
+            allin_payouts(&range, &[], 10, None),
+            Err(HoldemError::InvalidRange(_))
+        ));
+    }
 
-        // Build all hands
-        let mut hands: Vec<Vec<Card>> = Vec::with_capacity(num_opponents + 1);
+    #[test]
+    fn test_range_grid_pairs_vs_random() {
+        let pairs = CardDistribution::from_range(&["22+".to_string()], &[]).unwrap();
+        let any_two = crate::range::named("any_two").unwrap();
+        let grid = calculate_range_grid(&pairs, &any_two, &[], 200, Some(5)).unwrap();
+        assert_eq!(grid.len(), 13);
+
+        let best = grid.iter().max_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        let worst = grid.iter().min_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        assert_eq!(best.0, CanonicalHand::parse("AA").unwrap());
+        assert_eq!(worst.0, CanonicalHand::parse("22").unwrap());
+        assert!(best.1 > 0.8 && worst.1 > 0.45, "{grid:?}");
+
+        // Blocked by the board, the aces drop out entirely
+        let grid = calculate_range_grid(
+            &CardDistribution::from_range(&["AA".to_string()], &[]).unwrap(),
+            &any_two,
+            &cards("Ah As Ad Kc 2d"),
+            10,
+            Some(5),
+        );
+        assert!(matches!(grid, Err(HoldemError::NoValidCombinations)));
+    }
 
-        // Hero's hand
-        let mut hero_hand = hole_cards.to_vec();
-        hero_hand.extend(full_board.iter().copied());
-        hands.push(hero_hand);
+    #[test]
+    fn test_preflop_chart_orders_pairs() {
+        let chart = preflop_chart(2, 150, Some(2076)).unwrap();
+        assert_eq!(chart.len(), 169);
+        let equity = |notation: &str| {
+            let hand = CanonicalHand::parse(notation).unwrap();
+            chart.iter().find(|(h, _)| *h == hand).unwrap().1
+        };
+
+        let best = chart.iter().max_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        assert_eq!(best.0, CanonicalHand::parse("AA").unwrap());
+        assert!(equity("AA") > equity("TT"));
+        assert!(equity("TT") > equity("66"));
+        assert!(equity("66") > equity("72o"));
+
+        assert!(matches!(preflop_chart(0, 10, None), Err(HoldemError::NotEnoughOpponents(1))));
+    }
 
-        // Opponent hands
-        for opp in opponent_hands {
-            let mut hand = opp;
-            hand.extend(full_board.iter().copied());
-            hands.push(hand);
+    #[test]
+    fn test_results_to_csv() {
+        let player = |index: usize, hand: &str, combos: usize, equity: f64| RangePlayerEquity {
+            index,
+            equity,
+            win_rate: equity,
+            tie_rate: 0.0,
+            combos,
+            hand_description: hand.to_string(),
+        };
+        let result = RangeEquityResult {
+            players: vec![player(0, "AhAd", 1, 0.75), player(1, "KK, QQ", 12, 0.25)],
+            total_combinations: 12,
+            total_theoretical_combinations: 12,
+            total_simulations: 1000,
+            elapsed_ms: 1.0,
+            showdown_matrix: None,
+            per_combo: None,
+        };
+
+        assert_eq!(
+            results_to_csv(&result),
+            "player,hand,combos,equity,win_rate,tie_rate\n\
+             0,AhAd,1,0.75,0.75,0\n\
+             1,\"KK, QQ\",12,0.25,0.25,0\n"
+        );
+    }
+
+    #[test]
+    fn test_equity_by_street_swing() {
+        let card = |s: &str| Card::parse(s).unwrap();
+        // Aces lead a flush draw on the flop and turn, then the river is a heart
+        let streets = equity_by_street(
+            (card("As"), card("Ad")),
+            (card("Kh"), card("Qh")),
+            [card("Jh"), card("7h"), card("2c")],
+            card("3s"),
+            card("9h"),
+        )
+        .unwrap();
+
+        assert!(streets.flop[0] > 0.5 && streets.flop[0] < 0.8, "{streets:?}");
+        // On the turn only the 9 remaining hearts of 44 cards save the draw
+        assert!((streets.turn[1] - 9.0 / 44.0).abs() < 1e-12, "{streets:?}");
+        assert!(streets.turn[0] > streets.flop[0]);
+        assert!((streets.river[1] - 1.0).abs() < 1e-12, "{streets:?}");
+        for eq in [streets.flop, streets.turn, streets.river] {
+            assert!((eq[0] + eq[1] - 1.0).abs() < 1e-12);
         }
 
-        // Find winners (unwrap is safe here - we always have 7-card hands)
-        let winners = find_winners(&hands).unwrap();
+        let dup = equity_by_street(
+            (card("As"), card("Ad")),
+            (card("As"), card("Qh")),
+            [card("Jh"), card("7h"), card("2c")],
+            card("3s"),
+            card("9h"),
+        );
+        assert!(matches!(dup, Err(HoldemError::DuplicateCard(_))));
+    }
 
-        // Check if hero (index 0) won
-        if winners.contains(&0) {
-            equity_sum += 1.0 / winners.len() as f64;
-        }
+    #[test]
+    fn test_range_equity_range_emptied_by_dead_cards() {
+        let range = |s: &str| CardDistribution::from_range(&[s.to_string()], &[]).unwrap();
+        let request = RangeEquityRequest::new(
+            vec![RangePlayer::range(range("KK")), RangePlayer::range(range("AA"))],
+            cards("Ac 7h 2s"),
+        )
+        .with_dead_cards(cards("Ad Ah As"));
+        let err = calculate_equity_with_ranges(&request).unwrap_err();
+        assert!(matches!(err, HoldemError::PlayerHasNoCombos(1)), "{err:?}");
     }
 
-    Ok(equity_sum / num_simulations as f64)
-}
+    #[test]
+    fn test_range_equity_showdown_matrix() {
+        let range = |s: &str| CardDistribution::from_range(&[s.to_string()], &[]).unwrap();
+        let request = RangeEquityRequest::new(
+            vec![RangePlayer::range(range("AA")), RangePlayer::range(range("KK"))],
+            cards("Ac Kd 7h 2s"),
+        )
+        .with_simulations(1000)
+        .with_seed(3);
+        assert!(!request.showdown_matrix);
+
+        // Three aces combos vs three kings combos; kings need the last king
+        let result = calculate_equity_with_ranges(&request.with_showdown_matrix(true)).unwrap();
+        let matrix = result.showdown_matrix.unwrap();
+        assert_eq!(matrix.len(), 1);
+        let row = &matrix[0];
+        assert_eq!(row.hand.to_string(), "AA");
+        assert!((row.win - 43.0 / 44.0).abs() < 0.02, "{row:?}");
+        assert!((row.win + row.tie + row.lose - 1.0).abs() < 1e-9);
+        assert!((row.win - result.players[0].win_rate).abs() < 1e-9);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::card::parse_cards;
+    #[test]
+    fn test_range_equity_per_combo_output() {
+        let range = |s: &str| CardDistribution::from_range(&[s.to_string()], &[]).unwrap();
+        let request = RangeEquityRequest::new(
+            vec![RangePlayer::range(range("AA")), RangePlayer::range(range("KK"))],
+            cards("Ac Kd 7h 2s"),
+        )
+        .with_simulations(1000)
+        .with_seed(3);
+        assert!(calculate_equity_with_ranges(&request).unwrap().per_combo.is_none());
+
+        let result = calculate_equity_with_ranges(&request.with_per_combo_output(true)).unwrap();
+        let rows = result.per_combo.unwrap();
+        assert_eq!(rows.len() as u64, result.total_combinations);
+        assert_eq!(rows.len(), 9);
+        for row in &rows {
+            assert_eq!(row.hero.0.rank, Rank::Ace);
+            let Some((k1, k2)) = row.villains[0] else { panic!("{row:?}") };
+            assert_eq!((k1.rank, k2.rank), (Rank::King, Rank::King));
+        }
 
-    fn cards(s: &str) -> Vec<Card> {
-        parse_cards(s).unwrap()
+        #[allow(clippy::cast_precision_loss)]
+        let mean = rows.iter().map(|r| r.equity).sum::<f64>() / rows.len() as f64;
+        assert!((mean - result.players[0].equity).abs() < 1e-9);
     }
 
     #[test]
-    fn test_equity_aa_vs_kk() {
+    fn test_range_equity_request_serde_round_trip() {
+        let villain = CardDistribution::from_range(&["TT+".to_string(), "AKs".to_string()], &[])
+            .unwrap();
+        let hero = cards("Ah Qh");
+        let request = RangeEquityRequest::new(
+            vec![RangePlayer::specific(hero[0], hero[1]), RangePlayer::range(villain)],
+            cards("Kh 9h 2c"),
+        )
+        .with_dead_cards(cards("3d"))
+        .with_simulations(200)
+        .with_seed(5)
+        .with_showdown_matrix(true);
+
+        let json = serde_json::to_string(&request).unwrap();
+        let back: RangeEquityRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.board, request.board);
+        assert_eq!(back.dead_cards, request.dead_cards);
+        assert_eq!((back.num_simulations, back.seed), (200, Some(5)));
+        assert_eq!(back.strategy, request.strategy);
+        assert!(back.showdown_matrix);
+        assert!(matches!(back.players[0], RangePlayer::Specific(a, b) if [a, b] == hero[..]));
+        let (RangePlayer::Range(before), RangePlayer::Range(after)) =
+            (&request.players[1], &back.players[1])
+        else {
+            panic!("range player did not round-trip: {:?}", back.players[1]);
+        };
+        assert_eq!(after.hands(), before.hands());
+        assert_eq!(after.len(), 5 * 6 + 4);
+
+        // Optional fields can be left out
+        let minimal: RangeEquityRequest =
+            serde_json::from_str(r#"{"players":["Random","Random"],"board":[]}"#).unwrap();
+        assert_eq!(minimal.num_simulations, default_simulations());
+        assert!(minimal.dead_cards.is_empty() && !minimal.showdown_matrix);
+    }
+
+    #[test]
+    fn test_equity_overlapping_sampled_ranges_match_exhaustive() {
+        // AhAd blocks two of the three AQs combos, KcKd none. Of the four
+        // conflict-free pairs only one has aces, so hero's set is mostly
+        // behind; redrawing just the second range would give aces half
+        // the time instead of a quarter
+        let combos = |s: &str| {
+            let c = cards(s);
+            let mut dist = CardDistribution::from_hand(c[0], c[1]);
+            for pair in c[2..].chunks(2) {
+                dist = dist.union(&CardDistribution::from_hand(pair[0], pair[1]));
+            }
+            dist
+        };
+        let first = combos("Ah Ad Kc Kd");
+        let second = combos("Ah Qh Ad Qd Ac Qc");
+        let hero = cards("7s 7d");
+        let board = cards("Ks 7c 2h");
+
         let request = EquityRequest::new(
             vec![
-                PlayerHand::new(cards("Ah As")),
-                PlayerHand::new(cards("Kh Ks")),
+                PlayerHand::new(hero.clone()),
+                PlayerHand::from_range(first.clone()),
+                PlayerHand::from_range(second.clone()),
             ],
+            board.clone(),
+        )
+        .with_simulations(6000)
+        .with_seed(5);
+        let sampled = calculate_equity(&request).unwrap();
+
+        let exhaustive = calculate_equity_with_ranges(
+            &RangeEquityRequest::new(
+                vec![
+                    RangePlayer::specific(hero[0], hero[1]),
+                    RangePlayer::range(first),
+                    RangePlayer::range(second),
+                ],
+                board,
+            )
+            .with_simulations(1000)
+            .with_seed(5),
+        )
+        .unwrap();
+        for (s, e) in sampled.players.iter().zip(&exhaustive.players) {
+            assert!((s.equity - e.equity).abs() < 0.03, "{sampled:?} {exhaustive:?}");
+        }
+    }
+
+    #[test]
+    fn test_equity_sampled_range_matches_exhaustive() {
+        let villain = CardDistribution::from_range(&["QQ+".to_string()], &[]).unwrap();
+        let request = EquityRequest::new(
+            vec![PlayerHand::new(cards("Ah Kh")), PlayerHand::from_range(villain.clone())],
             vec![],
         )
-        .with_simulations(10_000)
-        .with_seed(42);
+        .with_simulations(4000)
+        .with_seed(11);
+        let sampled = calculate_equity(&request).unwrap();
+        // Ah and Kh each block three of QQ+'s 18 combos
+        assert_eq!(sampled.players[1].combos, 12);
+
+        let exhaustive = calculate_equity_with_ranges(
+            &RangeEquityRequest::new(
+                vec![
+                    RangePlayer::specific(cards("Ah")[0], cards("Kh")[0]),
+                    RangePlayer::range(villain),
+                ],
+                vec![],
+            )
+            .with_simulations(1000)
+            .with_seed(11),
+        )
+        .unwrap();
+        let diff = sampled.players[0].equity - exhaustive.players[0].equity;
+        assert!(diff.abs() < 0.03, "{sampled:?} {exhaustive:?}");
 
-        let result = calculate_equity(&request).unwrap();
+        // Sampled cards never reach the other players or the board
+        let aces = CardDistribution::from_range(&["AA".to_string()], &[]).unwrap();
+        let request = EquityRequest::new(
+            vec![
+                PlayerHand::from_range(aces.clone()),
+                PlayerHand::random(),
+                PlayerHand::from_range(aces),
+            ],
+            vec![],
+        );
+        let mut simulation = EquitySimulation::new(Cow::Owned(request)).unwrap();
+        let mut rng = DeterministicRng::seed_from_u64(2);
+        for _ in 0..200 {
+            let mut dealt = simulation.deal_holes(&mut rng).unwrap().concat();
+            dealt.extend_from_slice(simulation.dealer.deal_runout(5));
+            assert_eq!(dealt.iter().collect::<HashSet<_>>().len(), 11, "{dealt:?}");
+        }
 
-        assert_eq!(result.players.len(), 2);
-        // AA should have ~82% equity vs KK
-        assert!(result.players[0].equity > 0.75);
-        assert!(result.players[0].equity < 0.90);
-        assert!(result.players[1].equity > 0.10);
-        assert!(result.players[1].equity < 0.25);
+        let blocked = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("As Ad")),
+                PlayerHand::from_range(CardDistribution::from_hand(
+                    cards("As")[0],
+                    cards("Kd")[0],
+                )),
+            ],
+            vec![],
+        );
+        assert!(matches!(calculate_equity(&blocked), Err(HoldemError::RangeBlocked(1))));
     }
 
     #[test]
-    fn test_equity_with_board() {
-        let request = EquityRequest::new(
+    fn test_equity_range_emptied_by_dead_cards() {
+        let ranged = EquityRequest::new(
             vec![
-                PlayerHand::new(cards("Ah Kh")),
-                PlayerHand::new(cards("7h 2c")),
+                PlayerHand::new(cards("Kh Kd")),
+                PlayerHand::range(vec!["AA".to_string()]),
             ],
-            cards("Qh Jh Th"), // AK has royal flush draw
+            vec![],
         )
-        .with_simulations(10_000)
-        .with_seed(42);
-
-        let result = calculate_equity(&request).unwrap();
+        .with_dead_cards(cards("Ac Ad Ah As"));
+        let err = calculate_equity(&ranged).unwrap_err();
+        assert!(matches!(err, HoldemError::PlayerHasNoCombos(1)), "{err:?}");
 
-        // With the flush draw, AK should be heavily favored
-        assert!(result.players[0].equity > 0.80);
+        let sampled = EquityRequest::new(
+            vec![
+                PlayerHand::new(cards("Kh Kd")),
+                PlayerHand::from_range(CardDistribution::from_hand(
+                    cards("Ac")[0],
+                    cards("Ad")[0],
+                )),
+            ],
+            cards("Ac 7h 2s"),
+        );
+        let err = calculate_equity(&sampled).unwrap_err();
+        assert!(matches!(err, HoldemError::PlayerHasNoCombos(1)), "{err:?}");
     }
 
     #[test]
-    fn test_equity_sums_to_one() {
+    fn test_equity_target_precision_stops_early() {
+        let converge = |p1: &str, p2: &str| {
+            let request = EquityRequest::new(
+                vec![PlayerHand::new(cards(p1)), PlayerHand::new(cards(p2))],
+                vec![],
+            )
+            .with_simulations(50_000)
+            .with_seed(4)
+            .with_target_precision(0.01);
+            calculate_equity(&request).unwrap().total_simulations
+        };
+
+        // Variance peaks at 50% equity, so a coin flip needs the most work
+        let lopsided = converge("Ah As", "7c 2d");
+        let coin_flip = converge("Qh Qs", "Ac Kd");
+        assert!(lopsided < 50_000 && coin_flip < 50_000, "{lopsided} {coin_flip}");
+        assert!(lopsided * 3 < coin_flip * 2, "{lopsided} {coin_flip}");
+
+        // Without a target every simulation runs
         let request = EquityRequest::new(
-            vec![
-                PlayerHand::new(cards("Ah As")),
-                PlayerHand::new(cards("Kh Ks")),
-                PlayerHand::new(cards("Qh Qs")),
-            ],
+            vec![PlayerHand::new(cards("Ah As")), PlayerHand::new(cards("7c 2d"))],
             vec![],
         )
-        .with_simulations(5_000)
-        .with_seed(42);
+        .with_simulations(1200)
+        .with_seed(4);
+        assert_eq!(calculate_equity(&request).unwrap().total_simulations, 1200);
+
+        // Sessions stop at the same point whatever the step size
+        let precise = request.with_simulations(50_000).with_target_precision(0.01);
+        let expected = calculate_equity(&precise).unwrap();
+        let last = calculate_equity_batched(&precise, 777).unwrap().last().unwrap().unwrap();
+        assert_eq!(last.total_simulations, expected.total_simulations);
+        assert_eq!(last.players[0].win_count, expected.players[0].win_count);
+
+        for epsilon in [0.0, -0.01, f64::NAN, f64::INFINITY] {
+            let invalid = precise.clone().with_target_precision(epsilon);
+            assert!(matches!(calculate_equity(&invalid), Err(HoldemError::InvalidPrecision(_))));
+            assert!(EquitySession::new(invalid).is_err());
+        }
+    }
 
-        let result = calculate_equity(&request).unwrap();
+    #[test]
+    fn test_equity_with_shared_rng() {
+        let request = EquityRequest::new(
+            vec![PlayerHand::new(cards("Ah As")), PlayerHand::new(cards("Kh Ks"))],
+            vec![],
+        )
+        .with_simulations(500);
+        let mut shared = DeterministicRng::seed_from_u64(7);
+        let first = calculate_equity_with_rng(&request, &mut shared).unwrap();
+        let second = calculate_equity_with_rng(&request, &mut shared).unwrap();
+        let wins = |r: &EquityResult| r.players.iter().map(|p| p.win_count).collect::<Vec<_>>();
+
+        // The first call is the seeded API; the second picks up the stream
+        // where the first left off instead of repeating it
+        let seeded = calculate_equity(&request.clone().with_seed(7)).unwrap();
+        assert_eq!(wins(&first), wins(&seeded));
+        assert_ne!(wins(&second), wins(&first));
+
+        // The pair replays exactly, and the request's own seed is ignored
+        // once an RNG is supplied
+        let reseeded = request.clone().with_seed(99);
+        let mut replay = DeterministicRng::seed_from_u64(7);
+        for expected in [&first, &second] {
+            let result = calculate_equity_with_rng(&reseeded, &mut replay).unwrap();
+            assert_eq!(wins(&result), wins(expected));
+        }
 
-        let total_equity: f64 = result.players.iter().map(|p| p.equity).sum();
-        assert!((total_equity - 1.0).abs() < 0.01);
+        // Any RngCore can drive the simulation
+        let mut std_rng = <rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(1);
+        let equity = equity_vs_random_with_rng(&cards("Ah As"), &[], 1, 500, &mut std_rng);
+        assert!(equity.unwrap() > 0.75);
     }
 
     #[test]
-    fn test_equity_deterministic_with_seed() {
-        let request1 = EquityRequest::new(
-            vec![
-                PlayerHand::new(cards("Ah Kh")),
-                PlayerHand::new(cards("7s 2d")),
-            ],
+    fn test_equity_result_merge() {
+        let request = EquityRequest::new(
+            vec![PlayerHand::new(cards("Ah As")), PlayerHand::new(cards("Kh Ks"))],
             vec![],
         )
-        .with_simulations(1_000)
-        .with_seed(12345);
+        .with_simulations(5000);
+        let a = calculate_equity(&request.clone().with_seed(1)).unwrap();
+        let b = calculate_equity(&request.clone().with_seed(2)).unwrap();
+        let merged = a.merge(&b).unwrap();
+
+        assert_eq!(merged.total_simulations, 10_000);
+        for ((m, x), y) in merged.players.iter().zip(&a.players).zip(&b.players) {
+            assert_eq!(m.win_count, x.win_count + y.win_count);
+            assert_eq!(m.tie_count, x.tie_count + y.tie_count);
+            assert_eq!(m.total_simulations, 10_000);
+            assert!((m.equity - f64::midpoint(x.equity, y.equity)).abs() < 1e-12);
+        }
 
-        let request2 = request1.clone();
+        let single =
+            calculate_equity(&request.clone().with_simulations(10_000).with_seed(3)).unwrap();
+        for (m, s) in merged.players.iter().zip(&single.players) {
+            assert!((m.equity - s.equity).abs() < 0.02, "{} vs {}", m.equity, s.equity);
+            assert!((m.win_rate - s.win_rate).abs() < 0.02);
+        }
 
-        let result1 = calculate_equity(&request1).unwrap();
-        let result2 = calculate_equity(&request2).unwrap();
+        // Results from different requests refuse to merge
+        let three_way = calculate_equity(
+            &EquityRequest::new(
+                vec![
+                    PlayerHand::new(cards("Ah As")),
+                    PlayerHand::new(cards("Kh Ks")),
+                    PlayerHand::new(cards("Qh Qs")),
+                ],
+                vec![],
+            )
+            .with_simulations(100),
+        )
+        .unwrap();
+        assert!(matches!(a.merge(&three_way), Err(HoldemError::MismatchedResults(_))));
+        let with_types =
+            calculate_equity(&request.with_simulations(100).with_hand_distribution(true)).unwrap();
+        assert!(matches!(a.merge(&with_types), Err(HoldemError::MismatchedResults(_))));
+    }
 
-        assert_eq!(result1.players[0].equity, result2.players[0].equity);
+    #[test]
+    fn test_exact_heads_up() {
+        let card = |s: &str| Card::parse(s).unwrap();
+        let aces = (card("Ah"), card("As"));
+        let kings = (card("Kh"), card("Ks"));
+
+        // All 1,712,304 preflop boards: AA vs KK sharing suits is ~82.6%
+        let (aa, kk, tie) = exact_heads_up(aces, kings, &[], &[]).unwrap();
+        assert!((aa + tie / 2.0 - 0.826).abs() < 0.002, "{aa} {kk} {tie}");
+        assert!((aa + kk + tie - 1.0).abs() < 1e-12);
+
+        // On the turn the kings need one of the last two kings in 44 cards,
+        // and a dead king leaves only one
+        let board = cards("2h 7c 9d Js");
+        let (_, kk, _) = exact_heads_up(aces, kings, &board, &[]).unwrap();
+        assert!((kk - 2.0 / 44.0).abs() < 1e-12);
+        let (_, kk, _) = exact_heads_up(aces, kings, &board, &[card("Kd")]).unwrap();
+        assert!((kk - 1.0 / 43.0).abs() < 1e-12);
+
+        // A board straight both players play splits every runout
+        let broadway = cards("Tc Jd Qh Ks Ac");
+        let low = (card("2c"), card("3d"));
+        let split = exact_heads_up(low, (card("4h"), card("5s")), &broadway, &[]);
+        assert_eq!(split.unwrap(), (0.0, 0.0, 1.0));
+
+        let dup = exact_heads_up(aces, kings, &board, &[card("As")]);
+        assert!(matches!(dup, Err(HoldemError::DuplicateCard(_))));
+        let six = exact_heads_up(aces, kings, &cards("2h 7c 9d Js 3c 4c"), &[]);
+        assert!(matches!(six, Err(HoldemError::BoardTooLarge(6))));
     }
 
     #[test]
-    fn test_equity_vs_random() {
-        let hole = cards("Ah As");
-        let equity = equity_vs_random(&hole, &[], 1, 10_000, Some(42)).unwrap();
+    fn test_realized_equity() {
+        let hand = |s: &str| CanonicalHand::parse(s).unwrap();
 
-        // AA vs 1 random should be ~85%
-        assert!(equity > 0.80);
-        assert!(equity < 0.90);
+        // Suited connectors over-realize only in position
+        assert!(realized_equity(0.4, &hand("87s"), true) > 0.4);
+        assert!(realized_equity(0.4, &hand("87s"), false) < 0.4);
+
+        // Trash offsuit under-realizes either way
+        assert!(realized_equity(0.4, &hand("72o"), true) < 0.4);
+        assert!(realized_equity(0.4, &hand("72o"), false) < 0.4);
+
+        // Pairs realize their raw equity in position, and the result stays a probability
+        assert!((realized_equity(0.8, &hand("AA"), true) - 0.8).abs() < 1e-12);
+        assert!(realized_equity(0.95, &hand("AKs"), true) <= 1.0);
     }
 
     #[test]
-    fn test_equity_vs_multiple_random() {
-        let hole = cards("Ah As");
-        let equity = equity_vs_random(&hole, &[], 5, 10_000, Some(42)).unwrap();
+    fn test_equity_cache_hits() {
+        let request = |hero: &str, villain: &str, board: &str| {
+            EquityRequest::new(
+                vec![PlayerHand::new(cards(hero)), PlayerHand::new(cards(villain))],
+                cards(board),
+            )
+            .with_simulations(2000)
+            .with_seed(11)
+        };
+        let mut cache = EquityCache::new();
+
+        let first = cache.get_or_compute(&request("Ah Kh", "Qc Qd", "Qh Jh Th")).unwrap();
+        let again = cache.get_or_compute(&request("Ah Kh", "Qc Qd", "Th Qh Jh")).unwrap();
+        assert_eq!(cache.len(), 1);
+        assert_eq!(first.players[0].win_count, again.players[0].win_count);
+        assert_eq!(first.players[1].win_count, again.players[1].win_count);
+
+        // Hearts <-> spades relabeling is the same situation
+        let rotated = cache.get_or_compute(&request("As Ks", "Qc Qd", "Qs Js Ts")).unwrap();
+        assert_eq!(cache.len(), 1);
+        assert_eq!(first.players[0].win_count, rotated.players[0].win_count);
+        assert_eq!(rotated.players[0].hand_description, "As Ks");
+
+        // Different suits relative to each other are a different situation
+        cache.get_or_compute(&request("As Ks", "Qc Qd", "Qh Jh Th")).unwrap();
+        assert_eq!(cache.len(), 2);
+
+        // Errors are returned but not cached
+        assert!(cache.get_or_compute(&request("Ah Kh", "Ah Qd", "2c 3c 4c")).is_err());
+        assert_eq!(cache.len(), 2);
+    }
 
-        // AA vs 5 random should be ~49%
-        assert!(equity > 0.40);
-        assert!(equity < 0.60);
+    #[test]
+    fn test_board_from_streets() {
+        let flop: [Card; 3] = cards("Ah Kd 7c").try_into().unwrap();
+        let turn = Card::parse("2s").ok();
+        let river = Card::parse("9h").ok();
+
+        assert_eq!(board_from_streets(None, None, None).unwrap(), vec![]);
+        assert_eq!(board_from_streets(Some(flop), None, None).unwrap(), cards("Ah Kd 7c"));
+        assert_eq!(
+            board_from_streets(Some(flop), turn, None).unwrap(),
+            cards("Ah Kd 7c 2s")
+        );
+        assert_eq!(
+            board_from_streets(Some(flop), turn, river).unwrap(),
+            cards("Ah Kd 7c 2s 9h")
+        );
+    }
+
+    #[test]
+    fn test_board_from_streets_invalid() {
+        let flop: [Card; 3] = cards("Ah Kd 7c").try_into().unwrap();
+        let card = Card::parse("9h").ok();
+
+        assert!(matches!(
+            board_from_streets(Some(flop), None, card),
+            Err(HoldemError::InvalidBoard(_))
+        ));
+        assert!(matches!(
+            board_from_streets(None, card, None),
+            Err(HoldemError::InvalidBoard(_))
+        ));
+        assert!(EquityRequest::from_streets(vec![], flop, None, card).is_err());
+    }
+
+    #[test]
+    fn test_round_decimals() {
+        assert!((round_decimals(0.123_456, 2) - 0.12).abs() < 1e-12);
+        assert!((round_decimals(0.987_654, 1) - 1.0).abs() < 1e-12);
+        assert!((round_decimals(0.055, 1) - 0.1).abs() < 1e-12);
+        assert!((round_decimals(-0.125, 2) + 0.13).abs() < 1e-12);
+        assert!(round_decimals(0.4, 0).abs() < 1e-12);
+        assert!((round_decimals(0.123_456, 400) - 0.123_456).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_resolve_board() {
+        let flop: [Card; 3] = cards("Ah Kd 7c").try_into().unwrap();
+        let turn = Card::parse("2s").ok();
+
+        assert_eq!(resolve_board(&cards("Ah Kd 7c"), None, None, None).unwrap(), flop);
+        assert_eq!(resolve_board(&[], Some(flop), turn, None).unwrap(), cards("Ah Kd 7c 2s"));
+        assert!(matches!(
+            resolve_board(&cards("Ah Kd 7c"), None, turn, None),
+            Err(HoldemError::InvalidBoard(_))
+        ));
+        assert!(matches!(resolve_board(&[], None, turn, None), Err(HoldemError::InvalidBoard(_))));
+    }
+
+    #[test]
+    fn test_equity_request_from_streets() {
+        let flop: [Card; 3] = cards("Qh Jh Th").try_into().unwrap();
+        let request = EquityRequest::from_streets(
+            vec![PlayerHand::new(cards("Ah Kh")), PlayerHand::new(cards("7h 2c"))],
+            flop,
+            Card::parse("3d").ok(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(request.board, cards("Qh Jh Th 3d"));
     }
 
     #[test]
@@ -1312,6 +4652,24 @@ mod tests {
         assert!(result.players[0].equity < 0.55);
     }
 
+    #[test]
+    fn test_with_random_opponents() {
+        let request = EquityRequest::new(vec![PlayerHand::new(cards("As Kd"))], vec![])
+            .with_random_opponents(2)
+            .with_simulations(5_000)
+            .with_seed(42);
+
+        assert_eq!(request.players.len(), 3);
+        assert!(request.players[1].is_random && request.players[2].is_random);
+
+        let result = calculate_equity(&request).unwrap();
+        assert_eq!(result.players[1].hand_description, "(Random)");
+        assert!(result.players[0].equity > 0.40);
+        assert!(result.players[0].equity < 0.55);
+        // The two random seats are symmetric
+        assert!((result.players[1].equity - result.players[2].equity).abs() < 0.05);
+    }
+
     // =========================================================================
     // Range-based equity tests
     // =========================================================================
@@ -1378,6 +4736,7 @@ mod tests {
         // 6 * 6 = 36 total combinations, but some will conflict
         // AA and KK don't share cards, so all 36 should be valid
         assert_eq!(result.total_combinations, 36);
+        assert!((result.coverage() - 1.0).abs() < f64::EPSILON);
     }
 
     #[test]
@@ -1492,12 +4851,12 @@ mod tests {
     #[test]
     fn test_strategy_selection_small_range() {
         // Small range (<50 combos) should use Exhaustive with high sims
-        let strategy = select_strategy(30, 1000);
+        let strategy = select_strategy(30, 1000, &StrategyConfig::default());
         match strategy {
             EquityStrategy::Exhaustive { sims_per_combo } => {
                 assert!(sims_per_combo >= 1000, "Small range should have at least 1000 sims");
             }
-            EquityStrategy::ReservoirSampled { .. } | EquityStrategy::BiasedSampled { .. } => {
+            EquityStrategy::ReservoirSampled { .. } | EquityStrategy::IndependentSampled { .. } => {
                 panic!("Small range should use Exhaustive strategy");
             }
         }
@@ -1506,14 +4865,14 @@ mod tests {
     #[test]
     fn test_strategy_selection_medium_range() {
         // Medium range (50-500 combos) should use Exhaustive with reduced sims
-        let strategy = select_strategy(200, 10000);
+        let strategy = select_strategy(200, 10000, &StrategyConfig::default());
         match strategy {
             EquityStrategy::Exhaustive { sims_per_combo } => {
                 // Should reduce sims to control time: 10000 * 50 / 200 = 2500
                 assert!(sims_per_combo < 10000, "Medium range should reduce sims");
                 assert!(sims_per_combo >= MIN_SIMS_PER_COMBO, "Should not go below minimum");
             }
-            EquityStrategy::ReservoirSampled { .. } | EquityStrategy::BiasedSampled { .. } => {
+            EquityStrategy::ReservoirSampled { .. } | EquityStrategy::IndependentSampled { .. } => {
                 panic!("Medium range should use Exhaustive strategy");
             }
         }
@@ -1522,7 +4881,7 @@ mod tests {
     #[test]
     fn test_strategy_selection_large_range() {
         // Large range (500-10000 combos) should use ReservoirSampled (unbiased)
-        let strategy = select_strategy(1000, 5000);
+        let strategy = select_strategy(1000, 5000, &StrategyConfig::default());
         match strategy {
             EquityStrategy::Exhaustive { .. } => {
                 panic!("Large range should use ReservoirSampled strategy");
@@ -1531,8 +4890,8 @@ mod tests {
                 assert_eq!(max_combos, MAX_SAMPLED_COMBOS);
                 assert_eq!(sims_per_combo, 5000);
             }
-            EquityStrategy::BiasedSampled { .. } => {
-                panic!("Medium-large range should use ReservoirSampled, not BiasedSampled");
+            EquityStrategy::IndependentSampled { .. } => {
+                panic!("Medium-large range should use ReservoirSampled, not IndependentSampled");
             }
         }
     }
@@ -1576,6 +4935,46 @@ mod tests {
         assert!(result.total_combinations > 0);
     }
 
+    #[test]
+    fn test_strategy_config_forces_exhaustive() {
+        use crate::CardDistribution;
+
+        // All pairs vs AK: 78 * 16 = 1248 combinations, sampled by default
+        let pairs = CardDistribution::from_range(&["22+".to_string()], &[]).unwrap();
+        let ak =
+            CardDistribution::from_range(&["AKs".to_string(), "AKo".to_string()], &[]).unwrap();
+        let valid = pairs
+            .hands()
+            .iter()
+            .flat_map(|a| ak.hands().iter().map(move |b| (a, b)))
+            .filter(|((a1, a2), (b1, b2))| ![b1, b2].contains(&a1) && ![b1, b2].contains(&a2))
+            .count() as u64;
+
+        let request = RangeEquityRequest::new(
+            vec![RangePlayer::range(pairs), RangePlayer::range(ak)],
+            vec![],
+        )
+        .with_simulations(1)
+        .with_seed(42);
+
+        // Reservoir sampling simulates only MAX_SAMPLED_COMBOS of them
+        let sampled = calculate_equity_with_ranges(&request).unwrap();
+        assert_eq!(sampled.total_combinations, MAX_SAMPLED_COMBOS as u64);
+        assert_eq!(sampled.total_simulations, MAX_SAMPLED_COMBOS as u64);
+        assert!(sampled.coverage() < 1.0, "coverage {}", sampled.coverage());
+
+        let exact = request.with_strategy(StrategyConfig {
+            max_exhaustive_combos: 2_000,
+            min_sims_per_combo: 1,
+            ..StrategyConfig::default()
+        });
+        let result = calculate_equity_with_ranges(&exact).unwrap();
+        assert_eq!(result.total_theoretical_combinations, 1248);
+        assert_eq!(result.total_combinations, valid);
+        // One simulation for every valid combination
+        assert_eq!(result.total_simulations, valid);
+    }
+
     // =========================================================================
     // Validation and error handling tests
     // =========================================================================
@@ -1805,20 +5204,41 @@ mod tests {
     }
 
     #[test]
-    fn test_huge_range_uses_biased_sampling() {
-        // Huge range (>10k combos) should use BiasedSampled strategy
-        let strategy = select_strategy(15_000, 1000);
+    fn test_huge_range_uses_independent_sampling() {
+        // Huge range (>10k combos) should use IndependentSampled strategy
+        let strategy = select_strategy(15_000, 1000, &StrategyConfig::default());
         assert!(
-            matches!(strategy, EquityStrategy::BiasedSampled { .. }),
-            "Huge range should use BiasedSampled, got {:?}",
+            matches!(strategy, EquityStrategy::IndependentSampled { .. }),
+            "Huge range should use IndependentSampled, got {:?}",
             strategy
         );
     }
 
+    #[test]
+    fn test_huge_symmetric_ranges_split_evenly() {
+        // any two vs any two: ~1.76M theoretical combos. Walking the odometer
+        // front-first would hand player 0 its strongest combos.
+        let any_two = crate::range::named("any_two").unwrap();
+        let request = RangeEquityRequest::new(
+            vec![RangePlayer::range(any_two.clone()), RangePlayer::range(any_two)],
+            vec![],
+        )
+        .with_simulations(20)
+        .with_seed(42);
+
+        let result = calculate_equity_with_ranges(&request).unwrap();
+        assert_eq!(result.total_combinations, MAX_SAMPLED_COMBOS as u64);
+        assert_eq!(result.total_theoretical_combinations, 1326 * 1326);
+        assert!(result.coverage() < 1.0);
+        let p0 = result.players[0].equity;
+        assert!((p0 - 0.5).abs() < 0.08, "expected ~50%, got {p0}");
+        assert!((p0 + result.players[1].equity - 1.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_medium_large_range_uses_reservoir_sampling() {
         // Large range (500-10k combos) should use ReservoirSampled strategy
-        let strategy = select_strategy(5_000, 1000);
+        let strategy = select_strategy(5_000, 1000, &StrategyConfig::default());
         assert!(
             matches!(strategy, EquityStrategy::ReservoirSampled { .. }),
             "Large range should use ReservoirSampled, got {:?}",
@@ -25,10 +25,38 @@ pub enum HoldemError {
     #[error("Need at least {0} players")]
     NotEnoughPlayers(usize),
 
+    /// Team member index out of range or listed twice
+    #[error("Invalid team member: player {0}")]
+    InvalidTeamMember(usize),
+
+    /// Range notation that could not be turned into combos
+    #[error("Invalid range: {0}")]
+    InvalidRange(String),
+
     /// Too many board cards
     #[error("Board cannot exceed 5 cards, got {0}")]
     BoardTooLarge(usize),
 
+    /// Every combo in a player's range uses a card another player holds
+    #[error("Player {0}'s range has no combos left once other players' cards are removed")]
+    RangeBlocked(usize),
+
+    /// A player's range has no combos once the board and dead cards are removed
+    #[error("Player {0}'s range has no combos left once the board and dead cards are removed")]
+    PlayerHasNoCombos(usize),
+
+    /// Target precision that is not a positive, finite number
+    #[error("Target precision must be positive and finite, got {0}")]
+    InvalidPrecision(f64),
+
+    /// Equity results from different requests passed to `EquityResult::merge`
+    #[error("Cannot merge equity results: {0}")]
+    MismatchedResults(&'static str),
+
+    /// Board streets given in an inconsistent order (e.g. river without turn)
+    #[error("Invalid board: {0}")]
+    InvalidBoard(&'static str),
+
     /// Not enough cards in deck
     #[error("Cannot deal {requested} cards, only {available} remain")]
     InsufficientCards {
@@ -38,6 +66,15 @@ pub enum HoldemError {
         available: usize,
     },
 
+    /// Players, board and dead cards need more cards than a deck holds
+    #[error("Need {needed} cards for every player and a full board, only {available} in the deck")]
+    TooManyCards {
+        /// Hole cards for every player, a full board and the dead cards
+        needed: usize,
+        /// Cards in a deck
+        available: usize,
+    },
+
     /// Card not found in deck
     #[error("Card {0} not in deck")]
     CardNotInDeck(String),
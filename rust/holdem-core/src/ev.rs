@@ -0,0 +1,52 @@
+//! Expected value of simple betting decisions.
+//!
+//! All amounts are in chips (or big blinds) and EVs are measured from the
+//! decision point: money already in the pot is not counted as a loss.
+
+/// EV of calling `call` into `pot`, where `pot` already includes the bet faced
+///
+/// `equity * pot - (1 - equity) * call`: win the pot with `equity`, lose the
+/// call otherwise. Zero at exactly the pot-odds break-even equity.
+#[must_use]
+pub fn call_ev(equity: f64, pot: f64, call: f64) -> f64 {
+    equity * pot - (1.0 - equity) * call
+}
+
+/// EV of betting `bet` into `pot` as a (semi-)bluff
+///
+/// Villain folds with `fold_prob` and hero takes the pot. Otherwise villain
+/// calls and hero realizes `equity_when_called` of the `pot + 2 * bet` pot
+/// having invested `bet`.
+#[must_use]
+pub fn fold_equity_bluff_ev(fold_prob: f64, pot: f64, bet: f64, equity_when_called: f64) -> f64 {
+    let called_ev = equity_when_called * (pot + 2.0 * bet) - bet;
+    fold_prob * pot + (1.0 - fold_prob) * called_ev
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_call_ev() {
+        // Win 100 40% of the time, lose 50 60% of the time
+        assert!(close(call_ev(0.4, 100.0, 50.0), 10.0));
+        // Pot odds 50 / (100 + 50) = 1/3 is break-even
+        assert!(close(call_ev(1.0 / 3.0, 100.0, 50.0), 0.0));
+        assert!(close(call_ev(0.2, 100.0, 50.0), -20.0));
+    }
+
+    #[test]
+    fn test_fold_equity_bluff_ev() {
+        // Pure bluff: half the time win 100, half the time lose 50
+        assert!(close(fold_equity_bluff_ev(0.5, 100.0, 50.0, 0.0), 25.0));
+        // Break-even pure bluff folds exactly bet / (pot + bet) of the time
+        assert!(close(fold_equity_bluff_ev(1.0 / 3.0, 100.0, 50.0, 0.0), 0.0));
+        // A semi-bluff with 25% equity when called breaks even when called
+        assert!(close(fold_equity_bluff_ev(0.5, 100.0, 50.0, 0.25), 50.0));
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Evaluates 5-7 card hands and determines the best 5-card combination.
 
-use crate::card::{Card, Rank};
+use crate::card::{Card, Rank, FULL_DECK};
 use crate::error::{HoldemError, HoldemResult};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -27,6 +27,36 @@ pub enum HandType {
 }
 
 impl HandType {
+    /// All hand types, weakest first
+    pub const ALL: [HandType; 10] = [
+        HandType::HighCard,
+        HandType::OnePair,
+        HandType::TwoPair,
+        HandType::ThreeOfAKind,
+        HandType::Straight,
+        HandType::Flush,
+        HandType::FullHouse,
+        HandType::FourOfAKind,
+        HandType::StraightFlush,
+        HandType::RoyalFlush,
+    ];
+
+    /// Parse a hand type name, ignoring case, spaces and underscores
+    ///
+    /// Accepts the `name()` form ("Full House"), snake case (`full_house`)
+    /// and the serde variant name (`FullHouse`).
+    #[must_use]
+    pub fn from_name(s: &str) -> Option<Self> {
+        let squash = |s: &str| -> String {
+            s.chars()
+                .filter(|c| !c.is_whitespace() && *c != '_')
+                .map(|c| c.to_ascii_lowercase())
+                .collect()
+        };
+        let wanted = squash(s);
+        Self::ALL.into_iter().find(|t| squash(t.name()) == wanted)
+    }
+
     /// Get human-readable name
     #[must_use]
     pub const fn name(self) -> &'static str {
@@ -70,6 +100,70 @@ impl HandRank {
         Self { hand_type, primary_ranks, kickers }
     }
 
+    /// Pack the rank into a single integer that orders like `HandRank` itself.
+    ///
+    /// Bits 20-23 hold the hand type, followed by the primary ranks and then
+    /// the kickers in 4-bit nibbles, most significant first. Unused nibbles
+    /// are zero.
+    #[must_use]
+    pub fn to_score(&self) -> u32 {
+        pack_score(self.hand_type, self.primary_ranks.iter().chain(&self.kickers).copied())
+    }
+
+    /// Rebuild a hand rank from a score produced by [`HandRank::to_score`].
+    ///
+    /// Returns `None` if the hand type bits are out of range.
+    #[must_use]
+    pub fn from_score(score: u32) -> Option<Self> {
+        let hand_type = *HandType::ALL.get((score >> 20) as usize)?;
+        let (primary, kickers) = match hand_type {
+            HandType::HighCard | HandType::Flush => (5, 0),
+            HandType::OnePair => (1, 3),
+            HandType::TwoPair => (2, 1),
+            HandType::ThreeOfAKind => (1, 2),
+            HandType::FullHouse => (2, 0),
+            HandType::FourOfAKind => (1, 1),
+            HandType::Straight | HandType::StraightFlush | HandType::RoyalFlush => (1, 0),
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let nibble = |i: u32| ((score >> (16 - 4 * i)) & 0xF) as u8;
+        Some(Self::new(
+            hand_type,
+            (0..primary).map(nibble).collect(),
+            (primary..primary + kickers).map(nibble).collect(),
+        ))
+    }
+
+    /// Whether this hand wins a showdown against `other`
+    #[must_use]
+    pub fn beats(&self, other: &HandRank) -> bool {
+        self > other
+    }
+
+    /// Whether this hand splits the pot with `other`
+    #[must_use]
+    pub fn ties_with(&self, other: &HandRank) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+
+    /// Coarse strength bucket for UI coloring, by hand type alone
+    ///
+    /// `"monster"` for a full house or better, `"strong"` for trips, a
+    /// straight or a flush, `"marginal"` for one or two pair, and `"weak"`
+    /// for high card. The board is not considered.
+    #[must_use]
+    pub const fn strength_category(&self) -> &'static str {
+        match self.hand_type {
+            HandType::HighCard => "weak",
+            HandType::OnePair | HandType::TwoPair => "marginal",
+            HandType::ThreeOfAKind | HandType::Straight | HandType::Flush => "strong",
+            HandType::FullHouse
+            | HandType::FourOfAKind
+            | HandType::StraightFlush
+            | HandType::RoyalFlush => "monster",
+        }
+    }
+
     /// Generate human-readable description
     #[must_use]
     pub fn describe(&self) -> String {
@@ -295,6 +389,104 @@ pub fn evaluate_five(cards: &[Card; 5]) -> HandRank {
 /// # Errors
 /// Returns an error if the number of cards is not 5-7.
 pub fn evaluate_hand(cards: &[Card]) -> HoldemResult<HandRank> {
+    evaluate_best_five(cards).map(|(rank, _)| rank)
+}
+
+/// Score of the best 5-card hand in 5-7 distinct cards
+///
+/// Equal to `evaluate_hand(cards)?.to_score()`, but works on rank counts and
+/// bitmasks without allocating, for tight enumeration loops.
+pub(crate) fn hand_score(cards: &[Card]) -> u32 {
+    let mut counts = [0u8; 15];
+    let mut suit_masks = [0u16; 4];
+    for card in cards {
+        let rank = card.rank.value();
+        counts[usize::from(rank)] += 1;
+        suit_masks[card.suit as usize] |= 1 << rank;
+    }
+
+    // With 7 cards a flush rules out quads and full houses
+    if let Some(&mask) = suit_masks.iter().find(|m| m.count_ones() >= 5) {
+        return match straight_high(mask) {
+            Some(14) => pack_score(HandType::RoyalFlush, [14].into_iter()),
+            Some(high) => pack_score(HandType::StraightFlush, [high].into_iter()),
+            None => pack_score(
+                HandType::Flush,
+                (2..=14u8).rev().filter(|&r| mask & (1 << r) != 0),
+            ),
+        };
+    }
+
+    let ranks = || (2..=14u8).rev().filter(|&r| counts[usize::from(r)] > 0);
+    let count = |r: u8| counts[usize::from(r)];
+
+    if let Some(quad) = ranks().find(|&r| count(r) == 4) {
+        let kicker = ranks().filter(|&r| r != quad).take(1);
+        return pack_score(HandType::FourOfAKind, std::iter::once(quad).chain(kicker));
+    }
+
+    let trips = ranks().find(|&r| count(r) == 3);
+    if let Some(trip) = trips
+        && let Some(pair) = ranks().find(|&r| r != trip && count(r) >= 2)
+    {
+        return pack_score(HandType::FullHouse, [trip, pair].into_iter());
+    }
+
+    let rank_mask = suit_masks.iter().fold(0, |acc, m| acc | m);
+    if let Some(high) = straight_high(rank_mask) {
+        return pack_score(HandType::Straight, [high].into_iter());
+    }
+
+    if let Some(trip) = trips {
+        let kickers = ranks().filter(|&r| r != trip).take(2);
+        return pack_score(HandType::ThreeOfAKind, std::iter::once(trip).chain(kickers));
+    }
+
+    let mut pairs = ranks().filter(|&r| count(r) == 2);
+    match (pairs.next(), pairs.next()) {
+        (Some(high), Some(low)) => {
+            let kicker = ranks().filter(|&r| r != high && r != low).take(1);
+            pack_score(HandType::TwoPair, [high, low].into_iter().chain(kicker))
+        }
+        (Some(pair), None) => {
+            let kickers = ranks().filter(|&r| r != pair).take(3);
+            pack_score(HandType::OnePair, std::iter::once(pair).chain(kickers))
+        }
+        _ => pack_score(HandType::HighCard, ranks()),
+    }
+}
+
+/// Highest straight in a rank bitmask (bit `r` set for rank value `r`)
+fn straight_high(mask: u16) -> Option<u8> {
+    // The ace also plays low in the wheel
+    let mask = if mask & (1 << 14) != 0 { mask | 0b10 } else { mask };
+    (5..=14u8).rev().find(|&high| {
+        let run = 0b1_1111 << (high - 4);
+        mask & run == run
+    })
+}
+
+/// Pack a hand type and up to 5 ranks into the [`HandRank::to_score`] layout
+fn pack_score(hand_type: HandType, ranks: impl Iterator<Item = u8>) -> u32 {
+    let mut score = u32::from(hand_type as u8);
+    let mut nibbles = 0;
+    for rank in ranks.take(5) {
+        score = (score << 4) | u32::from(rank & 0xF);
+        nibbles += 1;
+    }
+    score << (4 * (5 - nibbles))
+}
+
+/// Evaluate 5-7 cards and return the best hand along with the 5 cards that make it
+///
+/// When several 5-card subsets make an equal hand, the one whose cards have
+/// the lexicographically smallest sorted `Card::to_index` values is chosen,
+/// so the same cards are picked whatever order they are passed in. The
+/// returned cards keep their input order.
+///
+/// # Errors
+/// Returns an error if the number of cards is not 5-7.
+pub fn evaluate_best_five(cards: &[Card]) -> HoldemResult<(HandRank, [Card; 5])> {
     if !(5..=7).contains(&cards.len()) {
         return Err(HoldemError::InvalidCardCount {
             expected: "5-7",
@@ -304,47 +496,208 @@ pub fn evaluate_hand(cards: &[Card]) -> HoldemResult<HandRank> {
 
     if cards.len() == 5 {
         let arr: [Card; 5] = cards.try_into().unwrap();
-        return Ok(evaluate_five(&arr));
+        return Ok((evaluate_five(&arr), arr));
     }
 
-    // Enumerate all C(n, 5) combinations and find the best
+    let sorted = |arr: &[Card; 5]| {
+        let mut sorted = *arr;
+        sorted.sort_unstable();
+        sorted
+    };
+
+    // Enumerate all C(n, 5) combinations and find the best, breaking ties
+    // toward the smallest sorted card indices
     Ok(cards
         .iter()
         .copied()
         .combinations(5)
         .map(|combo| {
             let arr: [Card; 5] = combo.try_into().unwrap();
-            evaluate_five(&arr)
+            (evaluate_five(&arr), arr)
         })
-        .max()
+        .max_by(|a, b| a.0.cmp(&b.0).then_with(|| sorted(&b.1).cmp(&sorted(&a.1))))
         .unwrap())
 }
 
-/// Find the indices of players with the best hand (handles ties)
+/// Evaluate the board alone ("the board plays")
+///
+/// Every player can use the board as their five cards, so a hand only wins
+/// outright if it strictly beats this rank; a hand equal to it plays the
+/// board and chops with anyone else doing the same. Returns `None` unless
+/// the board has exactly 5 cards.
+#[must_use]
+pub fn board_hand(board: &[Card]) -> Option<HandRank> {
+    let cards: &[Card; 5] = board.try_into().ok()?;
+    Some(evaluate_five(cards))
+}
+
+/// Check that `board` is a flop, turn or river with no card repeated
+///
+/// # Errors
+/// Returns `InvalidCardCount` if the board is not 3-5 cards, or
+/// `DuplicateCard` for the first repeated card.
+pub(crate) fn validate_postflop_board(board: &[Card]) -> HoldemResult<()> {
+    if !(3..=5).contains(&board.len()) {
+        return Err(HoldemError::InvalidCardCount {
+            expected: "3-5 (board)",
+            got: board.len(),
+        });
+    }
+    for (i, card) in board.iter().enumerate() {
+        if board[..i].contains(card) {
+            return Err(HoldemError::DuplicateCard(card.to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// The best hand any two hole cards can make on this board (the nuts)
+///
+/// # Errors
+/// Returns an error if the board is not 3-5 cards or has duplicates.
+pub fn nuts(board: &[Card]) -> HoldemResult<HandRank> {
+    nut_hand(board).map(|(rank, _)| rank)
+}
+
+/// The nuts on this board together with hole cards that make it
+///
+/// Enumerates every pair of cards not on the board; when several pairs
+/// tie for the nuts the first in `FULL_DECK` order is returned.
+///
+/// # Errors
+/// Returns an error if the board is not 3-5 cards or has duplicates.
+pub fn nut_hand(board: &[Card]) -> HoldemResult<(HandRank, [Card; 2])> {
+    validate_postflop_board(board)?;
+
+    let live: Vec<Card> = FULL_DECK.iter().filter(|c| !board.contains(c)).copied().collect();
+    let mut best: Option<(HandRank, [Card; 2])> = None;
+    for hole in live.iter().combinations(2) {
+        let mut hand = vec![*hole[0], *hole[1]];
+        hand.extend_from_slice(board);
+        let rank = evaluate_hand(&hand)?;
+        if best.as_ref().is_none_or(|(b, _)| rank > *b) {
+            best = Some((rank, [*hole[0], *hole[1]]));
+        }
+    }
+    Ok(best.expect("at least 47 live cards remain"))
+}
+
+/// Whether `hole` makes the nuts on this board (ties with other nut hands count)
+///
+/// # Errors
+/// Returns an error if `hole` is not 2 cards, the board is not 3-5 cards,
+/// or a card appears twice.
+pub fn is_nuts(hole: &[Card], board: &[Card]) -> HoldemResult<bool> {
+    if hole.len() != 2 {
+        return Err(HoldemError::InvalidCardCount {
+            expected: "2",
+            got: hole.len(),
+        });
+    }
+    if let Some(card) = hole.iter().find(|c| board.contains(c)) {
+        return Err(HoldemError::DuplicateCard(card.to_string()));
+    }
+
+    let nut = nuts(board)?;
+    let mut hand = hole.to_vec();
+    hand.extend_from_slice(board);
+    Ok(evaluate_hand(&hand)? == nut)
+}
+
+/// Showdown result for a single player
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerResult {
+    /// Player index (0-based, matches the input order)
+    pub index: usize,
+    /// The player's best hand
+    pub rank: HandRank,
+    /// The 5 cards making up the best hand
+    pub best_five: [Card; 5],
+    /// Whether this player wins (or ties for) the pot
+    pub is_winner: bool,
+}
+
+/// Evaluate every player's hand and mark the winners (handles ties)
+///
+/// Returns one `PlayerResult` per input hand, in input order.
 ///
 /// # Errors
 /// Returns an error if:
 /// - `hands` is empty
 /// - Any hand has invalid card count
-pub fn find_winners(hands: &[Vec<Card>]) -> HoldemResult<Vec<usize>> {
+pub fn find_winners_detailed(hands: &[Vec<Card>]) -> HoldemResult<Vec<PlayerResult>> {
     if hands.is_empty() {
         return Err(HoldemError::EmptyHands);
     }
 
-    let ranks: Vec<HandRank> = hands
+    let evaluated: Vec<(HandRank, [Card; 5])> = hands
         .iter()
-        .map(|h| evaluate_hand(h))
+        .map(|h| evaluate_best_five(h))
         .collect::<Result<Vec<_>, _>>()?;
 
-    let best = ranks.iter().max().unwrap();
+    let best = evaluated.iter().map(|(r, _)| r).max().unwrap().clone();
 
-    Ok(ranks
-        .iter()
+    Ok(evaluated
+        .into_iter()
         .enumerate()
-        .filter_map(|(i, r)| if r == best { Some(i) } else { None })
+        .map(|(index, (rank, best_five))| PlayerResult {
+            index,
+            is_winner: rank == best,
+            rank,
+            best_five,
+        })
+        .collect())
+}
+
+/// Find the indices of players with the best hand (handles ties)
+///
+/// # Errors
+/// Returns an error if:
+/// - `hands` is empty
+/// - Any hand has invalid card count
+pub fn find_winners(hands: &[Vec<Card>]) -> HoldemResult<Vec<usize>> {
+    Ok(find_winners_detailed(hands)?
+        .into_iter()
+        .filter(|r| r.is_winner)
+        .map(|r| r.index)
         .collect())
 }
 
+/// Evaluate many hands in one call, in input order
+///
+/// Equivalent to calling `evaluate_hand` on each hand, but lets bindings
+/// such as WASM cross the boundary once for a whole batch.
+///
+/// # Errors
+/// Returns the first error from `evaluate_hand` (a hand without 5-7 cards).
+pub fn evaluate_many(hands: &[Vec<Card>]) -> HoldemResult<Vec<HandRank>> {
+    hands.iter().map(|hand| evaluate_hand(hand)).collect()
+}
+
+/// Rank every player's hand from strongest to weakest
+///
+/// Returns `(index, rank)` pairs giving the full finishing order. The sort is
+/// stable, so tied hands keep their original input order.
+///
+/// # Errors
+/// Returns an error if:
+/// - `hands` is empty
+/// - Any hand has invalid card count
+pub fn rank_all(hands: &[Vec<Card>]) -> HoldemResult<Vec<(usize, HandRank)>> {
+    if hands.is_empty() {
+        return Err(HoldemError::EmptyHands);
+    }
+
+    let mut ranked: Vec<(usize, HandRank)> = hands
+        .iter()
+        .enumerate()
+        .map(|(i, h)| evaluate_hand(h).map(|r| (i, r)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    ranked.sort_by(|a, b| b.1.cmp(&a.1));
+    Ok(ranked)
+}
+
 /// Compare two hands directly
 /// Returns: 1 if hand1 wins, -1 if hand2 wins, 0 if tie
 ///
@@ -361,6 +714,55 @@ pub fn compare_hands(hand1: &[Card], hand2: &[Card]) -> HoldemResult<i8> {
     })
 }
 
+/// Which hand won a two-hand comparison
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Winner {
+    First,
+    Second,
+    Tie,
+}
+
+/// Result of `compare_hands_detailed`: both ranks, the winner and a summary
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Comparison {
+    pub first: HandRank,
+    pub second: HandRank,
+    pub winner: Winner,
+    /// e.g. "Player 1 wins with Flush, A high over Straight, K high"
+    pub summary: String,
+}
+
+/// Compare two hands and explain the result
+///
+/// Builds on `HandRank::describe`. When both descriptions read the same
+/// (e.g. two pairs of aces) the first card that differs is added as the
+/// kicker, so the summary always says why the winner won.
+///
+/// # Errors
+/// Returns an error if either hand has invalid card count.
+pub fn compare_hands_detailed(hand1: &[Card], hand2: &[Card]) -> HoldemResult<Comparison> {
+    let first = evaluate_hand(hand1)?;
+    let second = evaluate_hand(hand2)?;
+
+    let (mut desc1, mut desc2) = (first.describe(), second.describe());
+    if desc1 == desc2 {
+        let ranks1 = first.primary_ranks.iter().chain(&first.kickers);
+        let ranks2 = second.primary_ranks.iter().chain(&second.kickers);
+        if let Some((&r1, &r2)) = ranks1.zip(ranks2).find(|(a, b)| a != b) {
+            let kicker = |r: u8| Rank::from_value(r).map_or('?', Rank::to_char);
+            desc1 = format!("{desc1}, {} kicker", kicker(r1));
+            desc2 = format!("{desc2}, {} kicker", kicker(r2));
+        }
+    }
+
+    let (winner, summary) = match first.cmp(&second) {
+        Ordering::Greater => (Winner::First, format!("Player 1 wins with {desc1} over {desc2}")),
+        Ordering::Less => (Winner::Second, format!("Player 2 wins with {desc2} over {desc1}")),
+        Ordering::Equal => (Winner::Tie, format!("Tie: both players have {desc1}")),
+    };
+    Ok(Comparison { first, second, winner, summary })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -374,6 +776,61 @@ mod tests {
         cards(s).try_into().unwrap()
     }
 
+    #[test]
+    fn test_evaluate_many_matches_evaluate_hand() {
+        let mut rng = crate::rng::DeterministicRng::seed_from_u64(2060);
+        let mut deck = FULL_DECK.to_vec();
+        let hands: Vec<Vec<Card>> = (0..300)
+            .map(|i| {
+                rng.shuffle(&mut deck);
+                deck[..5 + i % 3].to_vec()
+            })
+            .collect();
+
+        let ranks = evaluate_many(&hands).unwrap();
+        assert_eq!(ranks.len(), hands.len());
+        for (hand, rank) in hands.iter().zip(&ranks) {
+            assert_eq!(*rank, evaluate_hand(hand).unwrap());
+        }
+
+        assert!(evaluate_many(&[]).unwrap().is_empty());
+        assert!(evaluate_many(&[hands[0].clone(), cards("Ah Kh")]).is_err());
+    }
+
+    #[test]
+    fn test_hand_type_name_round_trip() {
+        for (i, hand_type) in HandType::ALL.into_iter().enumerate() {
+            assert_eq!(hand_type as usize, i);
+            assert_eq!(HandType::from_name(hand_type.name()), Some(hand_type));
+        }
+        assert_eq!(HandType::from_name("full_house"), Some(HandType::FullHouse));
+        assert_eq!(HandType::from_name("FourOfAKind"), Some(HandType::FourOfAKind));
+        assert_eq!(HandType::from_name(" three of a kind "), Some(HandType::ThreeOfAKind));
+        assert_eq!(HandType::from_name("pair"), None);
+        assert_eq!(HandType::from_name(""), None);
+    }
+
+    #[test]
+    fn test_score_orders_like_hand_rank() {
+        let mut rng = crate::rng::DeterministicRng::seed_from_u64(2050);
+        let mut deck = FULL_DECK.to_vec();
+        let mut ranks = Vec::new();
+        for _ in 0..2000 {
+            rng.shuffle(&mut deck);
+            ranks.push(evaluate_hand(&deck[..7]).unwrap());
+        }
+        ranks.push(evaluate_hand(&cards("Ah Kh Qh Jh Th")).unwrap());
+        ranks.push(evaluate_hand(&cards("5s 4s 3s 2s As")).unwrap());
+
+        for a in &ranks {
+            assert_eq!(HandRank::from_score(a.to_score()).as_ref(), Some(a));
+            for b in ranks.iter().take(200) {
+                assert_eq!(a.to_score().cmp(&b.to_score()), a.cmp(b), "{a} vs {b}");
+            }
+        }
+        assert_eq!(HandRank::from_score(10 << 20), None);
+    }
+
     #[test]
     fn test_royal_flush() {
         let hand = cards5("Ah Kh Qh Jh Th");
@@ -480,6 +937,100 @@ mod tests {
         assert_eq!(rank.hand_type, HandType::Flush);
     }
 
+    #[test]
+    fn test_wheel_across_seven_cards() {
+        let (rank, best) = evaluate_best_five(&cards("Ah 2c 3d 4s 5h Kc Qd")).unwrap();
+        assert_eq!(rank.hand_type, HandType::Straight);
+        assert_eq!(rank.primary_ranks, vec![5]);
+        assert!(best.contains(&Card::parse("Ah").unwrap()));
+
+        // A six on board makes the 6-high straight, not the wheel
+        let rank = evaluate_hand(&cards("Ah 2c 3d 4s 5h 6c Qd")).unwrap();
+        assert_eq!(rank.hand_type, HandType::Straight);
+        assert_eq!(rank.primary_ranks, vec![6]);
+    }
+
+    #[test]
+    fn test_four_to_the_wheel_is_not_a_straight() {
+        let rank = evaluate_hand(&cards("Ah 2c 3d 4s Kh")).unwrap();
+        assert_eq!(rank.hand_type, HandType::HighCard);
+
+        let rank = evaluate_hand(&cards("Ah 2c 3d 4s Kh Qc 9d")).unwrap();
+        assert_eq!(rank.hand_type, HandType::HighCard);
+    }
+
+    #[test]
+    fn test_board_hand_straight_on_board() {
+        let board = cards("5c 6d 7h 8s 9c");
+        let board_rank = board_hand(&board).unwrap();
+        assert_eq!(board_rank.hand_type, HandType::Straight);
+
+        // Ace-king plays the board and chops with deuces
+        let mut ak = cards("Ah Kd");
+        ak.extend(&board);
+        assert_eq!(evaluate_hand(&ak).unwrap(), board_rank);
+        let mut deuces = cards("2h 2d");
+        deuces.extend(&board);
+        assert_eq!(find_winners(&[ak, deuces.clone()]).unwrap(), vec![0, 1]);
+
+        // A ten makes a higher straight and wins outright
+        let mut ten = cards("Th 2c");
+        ten.extend(&board);
+        assert!(evaluate_hand(&ten).unwrap() > board_rank);
+        assert_eq!(find_winners(&[ten, deuces]).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_nuts() {
+        let straight = nuts(&cards("9h 8c 7d 2s Kc")).unwrap();
+        assert_eq!(straight.hand_type, HandType::Straight);
+        assert_eq!(straight.primary_ranks, vec![11]);
+
+        let flush_board = nuts(&cards("Ah 8h 3h")).unwrap();
+        assert_eq!(flush_board.hand_type, HandType::Flush);
+        assert_eq!(flush_board.primary_ranks, vec![14, 13, 12, 8, 3]);
+
+        let connected = nuts(&cards("9h 8h 7h")).unwrap();
+        assert_eq!(connected.hand_type, HandType::StraightFlush);
+        assert_eq!(connected.primary_ranks, vec![11]);
+
+        assert!(nuts(&cards("9h 8c")).is_err());
+        assert!(nuts(&cards("9h 9h 2c")).is_err());
+    }
+
+    #[test]
+    fn test_nut_hand() {
+        let (rank, hole) = nut_hand(&cards("Ah Kh Qh")).unwrap();
+        assert_eq!(rank.hand_type, HandType::RoyalFlush);
+        assert!(hole.contains(&Card::parse("Jh").unwrap()));
+        assert!(hole.contains(&Card::parse("Th").unwrap()));
+
+        let (rank, hole) = nut_hand(&cards("Kc Kd 7s")).unwrap();
+        assert_eq!(rank.hand_type, HandType::FourOfAKind);
+        assert_eq!(hole, [Card::parse("Kh").unwrap(), Card::parse("Ks").unwrap()]);
+    }
+
+    #[test]
+    fn test_is_nuts() {
+        let board = cards("Kc Kd 7s");
+        assert!(is_nuts(&cards("Kh Ks"), &board).unwrap());
+        // A full house is only second best here
+        assert!(!is_nuts(&cards("Kh 7h"), &board).unwrap());
+
+        let flush_board = cards("Ah Qh 4h 8c 2d");
+        assert!(is_nuts(&cards("Kh Jh"), &flush_board).unwrap());
+        assert!(!is_nuts(&cards("Jh Th"), &flush_board).unwrap());
+
+        assert!(is_nuts(&cards("Kc 2s"), &board).is_err());
+        assert!(is_nuts(&cards("Kh"), &board).is_err());
+    }
+
+    #[test]
+    fn test_board_hand_needs_five_cards() {
+        assert!(board_hand(&cards("5c 6d 7h 8s")).is_none());
+        assert!(board_hand(&[]).is_none());
+    }
+
     #[test]
     fn test_find_winners() {
         let hand1 = cards("Ah Kh Qh Jh Th"); // Royal flush
@@ -499,6 +1050,120 @@ mod tests {
         assert_eq!(winners, vec![0, 1]); // Tie
     }
 
+    #[test]
+    fn test_find_winners_detailed_tie() {
+        let board = "Qd Jc Ts 4h 2d";
+        let hand1 = cards(&format!("Ah Kc {board}")); // Broadway
+        let hand2 = cards(&format!("As Kd {board}")); // Same broadway
+        let hand3 = cards(&format!("Qs Qh {board}")); // Set of queens
+
+        let results = find_winners_detailed(&[hand1, hand2, hand3]).unwrap();
+        assert_eq!(results.len(), 3);
+
+        for (i, result) in results.iter().enumerate() {
+            assert_eq!(result.index, i);
+        }
+
+        assert!(results[0].is_winner);
+        assert!(results[1].is_winner);
+        assert!(!results[2].is_winner);
+
+        assert_eq!(results[0].rank.hand_type, HandType::Straight);
+        assert_eq!(results[1].rank, results[0].rank);
+        assert_eq!(results[2].rank.hand_type, HandType::ThreeOfAKind);
+
+        // Each winner's best five contains their own ace and king
+        assert!(results[0].best_five.contains(&Card::parse("Ah").unwrap()));
+        assert!(results[0].best_five.contains(&Card::parse("Kc").unwrap()));
+        assert!(results[1].best_five.contains(&Card::parse("As").unwrap()));
+        assert!(results[1].best_five.contains(&Card::parse("Kd").unwrap()));
+
+        let winners = find_winners(&[
+            cards(&format!("Ah Kc {board}")),
+            cards(&format!("As Kd {board}")),
+            cards(&format!("Qs Qh {board}")),
+        ])
+        .unwrap();
+        assert_eq!(winners, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_evaluate_best_five_cards() {
+        let hand = cards("Ah Kh 9h 5h 2h 3c 4d");
+        let (rank, best) = evaluate_best_five(&hand).unwrap();
+        assert_eq!(rank.hand_type, HandType::Flush);
+        assert!(best.iter().all(|c| c.suit == crate::card::Suit::Hearts));
+        assert_eq!(evaluate_five(&best), rank);
+    }
+
+    #[test]
+    fn test_beats_ties_with_and_strength_category() {
+        let flush = evaluate_hand(&parse_cards("Ah Jh 8h 4h 2h").unwrap()).unwrap();
+        let same_flush = evaluate_hand(&parse_cards("As Js 8s 4s 2s").unwrap()).unwrap();
+        let straight = evaluate_hand(&parse_cards("9c 8d 7h 6s 5c").unwrap()).unwrap();
+
+        assert!(flush.beats(&straight));
+        assert!(!straight.beats(&flush));
+        assert!(flush.ties_with(&same_flush));
+        assert!(!flush.beats(&same_flush) && !flush.ties_with(&straight));
+
+        assert_eq!(flush.strength_category(), "strong");
+        let category =
+            |s: &str| evaluate_hand(&parse_cards(s).unwrap()).unwrap().strength_category();
+        assert_eq!(category("Ah Ad Ac Ks Kd"), "monster");
+        assert_eq!(category("Ah Ad 9c 5s 2d"), "marginal");
+        assert_eq!(category("Ah Qd 9c 5s 2d"), "weak");
+    }
+
+    #[test]
+    fn test_evaluate_best_five_tie_break() {
+        // Either nine completes the straight; the 9c has the lower index
+        let hand = cards("Kc Qh Jd Tc 9d 9c 2s");
+        let (rank, best) = evaluate_best_five(&hand).unwrap();
+        assert_eq!(rank.hand_type, HandType::Straight);
+        assert_eq!(best.to_vec(), cards("Kc Qh Jd Tc 9c"));
+
+        // The same five cards are chosen whatever the input order
+        let mut reversed = hand.clone();
+        reversed.reverse();
+        let (_, best_reversed) = evaluate_best_five(&reversed).unwrap();
+        let mut a = best.to_vec();
+        let mut b = best_reversed.to_vec();
+        a.sort();
+        b.sort();
+        assert_eq!(a, b);
+
+        // Quads on board: either king is the kicker, and the Kc is lower
+        let (_, best) = evaluate_best_five(&cards("Kd Kc 8c 8d 8h 8s 2c")).unwrap();
+        assert_eq!(best.to_vec(), cards("Kc 8c 8d 8h 8s"));
+    }
+
+    #[test]
+    fn test_rank_all_order() {
+        let board = "Kh 9h 7d 4c 2s";
+        let two_pair = cards(&format!("Kd 9c {board}"));
+        let flush = cards("Ah 3h Kh 9h 7d 4c 2h");
+        let pair = cards(&format!("Qc Qd {board}"));
+
+        let ranked = rank_all(&[pair, flush, two_pair]).unwrap();
+        let order: Vec<usize> = ranked.iter().map(|(i, _)| *i).collect();
+        assert_eq!(order, vec![1, 2, 0]);
+        assert_eq!(ranked[0].1.hand_type, HandType::Flush);
+        assert_eq!(ranked[1].1.hand_type, HandType::TwoPair);
+        assert_eq!(ranked[2].1.hand_type, HandType::OnePair);
+    }
+
+    #[test]
+    fn test_rank_all_ties_keep_input_order() {
+        let hand1 = cards("Ah Kd Qc Jh Ts");
+        let hand2 = cards("2c 3d 4h 5s 7c");
+        let hand3 = cards("Ac Ks Qh Jd Tc");
+
+        let ranked = rank_all(&[hand1, hand2, hand3]).unwrap();
+        let order: Vec<usize> = ranked.iter().map(|(i, _)| *i).collect();
+        assert_eq!(order, vec![0, 2, 1]);
+    }
+
     #[test]
     fn test_compare_hands() {
         let hand1 = cards("Ah Kh Qh Jh Th");
@@ -509,6 +1174,28 @@ mod tests {
         assert_eq!(compare_hands(&hand1, &hand1).unwrap(), 0); // Tie
     }
 
+    #[test]
+    fn test_compare_hands_detailed() {
+        let kicker = compare_hands_detailed(&cards("Ah As Kd 7c 3s"), &cards("Ac Ad Qh 7s 3d"))
+            .unwrap();
+        assert_eq!(kicker.winner, Winner::First);
+        assert_eq!(
+            kicker.summary,
+            "Player 1 wins with Pair of Aces, K kicker over Pair of Aces, Q kicker"
+        );
+
+        let flush = compare_hands_detailed(&cards("9c 8d 7h 6s 5c"), &cards("Ah Jh 8h 4h 2h"))
+            .unwrap();
+        assert_eq!(flush.winner, Winner::Second);
+        assert_eq!(flush.summary, "Player 2 wins with Flush, A high over Straight, 9 high");
+        assert_eq!(flush.first.hand_type, HandType::Straight);
+
+        let chop = compare_hands_detailed(&cards("Ah Kd Qc Js Th"), &cards("As Kc Qd Jh Ts"))
+            .unwrap();
+        assert_eq!(chop.winner, Winner::Tie);
+        assert_eq!(chop.summary, "Tie: both players have Straight, A high");
+    }
+
     #[test]
     fn test_hand_rank_ordering() {
         let royal = evaluate_five(&cards5("Ah Kh Qh Jh Th"));
@@ -541,4 +1228,27 @@ mod tests {
 
         assert!(pair_with_a > pair_with_q);
     }
+
+    #[test]
+    fn test_hand_score_matches_evaluate_hand() {
+        let mut rng = crate::rng::DeterministicRng::seed_from_u64(7);
+        let mut deck = crate::card::FULL_DECK.to_vec();
+        for i in 0..3000 {
+            rng.shuffle(&mut deck);
+            let hand = &deck[..5 + i % 3];
+            assert_eq!(hand_score(hand), evaluate_hand(hand).unwrap().to_score(), "{hand:?}");
+        }
+
+        for hand in [
+            "Ah Kh Qh Jh Th 2c 3d",
+            "5s 4s 3s 2s As Kd Kc",
+            "9c 9d 9h 9s 2c 3d 4h",
+            "Kc Kd Kh 7s 7d 7c 2h",
+            "Ac Ad 9h 9s 4c 4d Kh",
+            "As 2d 3h 4c 5s 9d Jh",
+        ] {
+            let hand = parse_cards(hand).unwrap();
+            assert_eq!(hand_score(&hand), evaluate_hand(&hand).unwrap().to_score());
+        }
+    }
 }
@@ -0,0 +1,104 @@
+//! Independent Chip Model (ICM) tournament equity.
+//!
+//! Converts chip stacks into prize-pool equity using the Malmuth-Harville
+//! model: a player's chance of finishing next is their share of the chips
+//! still in play among the players not yet placed.
+
+use std::collections::HashMap;
+
+/// Expected prize money for each player under ICM
+///
+/// `payouts[k]` is the prize for finishing in place `k + 1`; places beyond
+/// the payout list (or beyond the number of players) pay nothing. Players
+/// with no chips finish after everyone with chips, sharing the remaining
+/// places evenly. Work grows with the number of ways to fill the paid
+/// places, so keep the field to tournament-table sizes.
+///
+/// # Panics
+/// Panics if there are more than 64 players.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn icm_equities(stacks: &[u64], payouts: &[f64]) -> Vec<f64> {
+    let n = stacks.len();
+    assert!(n <= 64, "ICM supports at most 64 players");
+
+    let total: u64 = stacks.iter().sum();
+    let mut equities = vec![0.0; n];
+
+    // Probability that exactly the players in each mask took the places so far
+    let mut level: HashMap<u64, f64> = HashMap::from([(0, 1.0)]);
+    for &payout in payouts.iter().take(n) {
+        let mut next: HashMap<u64, f64> = HashMap::new();
+        for (&placed, &prob) in &level {
+            let placed_chips: u64 = (0..n)
+                .filter(|&i| placed & (1 << i) != 0)
+                .map(|i| stacks[i])
+                .sum();
+            let remaining = total - placed_chips;
+            let open: Vec<usize> = (0..n).filter(|&i| placed & (1 << i) == 0).collect();
+
+            for &i in &open {
+                let p = if remaining == 0 {
+                    1.0 / open.len() as f64
+                } else {
+                    stacks[i] as f64 / remaining as f64
+                };
+                if p == 0.0 {
+                    continue;
+                }
+                equities[i] += prob * p * payout;
+                *next.entry(placed | (1 << i)).or_insert(0.0) += prob * p;
+            }
+        }
+        level = next;
+    }
+
+    equities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_equal_stacks_split_evenly() {
+        let eq = icm_equities(&[1000, 1000, 1000], &[50.0, 30.0, 20.0]);
+        for e in eq {
+            assert!(close(e, 100.0 / 3.0));
+        }
+    }
+
+    #[test]
+    fn test_known_three_player_example() {
+        // 5000 / 3000 / 2000 chips, 50 / 30 / 20 payouts
+        let eq = icm_equities(&[5000, 3000, 2000], &[50.0, 30.0, 20.0]);
+        assert!(close(eq[0], 38.392_857_142_857_14), "{eq:?}");
+        assert!(close(eq[1], 32.75), "{eq:?}");
+        assert!(close(eq[2], 28.857_142_857_142_85), "{eq:?}");
+        assert!(close(eq.iter().sum::<f64>(), 100.0));
+
+        // The chip leader holds 50% of the chips but less than 50% of the
+        // prize pool, and the short stack gets more than their 20%
+        assert!(eq[0] < 50.0 && eq[2] > 20.0);
+    }
+
+    #[test]
+    fn test_winner_take_all_is_chip_proportional() {
+        let eq = icm_equities(&[6000, 3000, 1000], &[100.0]);
+        assert!(close(eq[0], 60.0));
+        assert!(close(eq[1], 30.0));
+        assert!(close(eq[2], 10.0));
+    }
+
+    #[test]
+    fn test_busted_player_finishes_last() {
+        let eq = icm_equities(&[1000, 1000, 0], &[50.0, 30.0, 20.0]);
+        assert!(close(eq[0], 40.0));
+        assert!(close(eq[1], 40.0));
+        assert!(close(eq[2], 20.0));
+    }
+}
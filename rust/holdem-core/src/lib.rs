@@ -7,22 +7,38 @@
 //! - Draw analysis (flush draws, straight draws)
 //! - Canonical hand representation (169 starting hands)
 
+pub mod analysis;
 pub mod card;
 pub mod canonize;
 pub mod draws;
 pub mod equity;
+pub mod ev;
 pub mod error;
 pub mod evaluator;
+pub mod icm;
+pub mod math;
+mod preflop_data;
 pub mod range;
+pub mod rng;
 
 // Re-export commonly used types
-pub use card::{Card, Deck, Rank, Suit};
-pub use canonize::{CanonicalHand, get_all_canonical_hands};
-pub use draws::{analyze_draws, DrawAnalysis, DrawType, FlushDraw, StraightDraw};
+pub use card::{Card, CardSet, Deck, HoldemDeal, Rank, Suit};
+pub use canonize::{CanonicalHand, get_all_canonical_hands, hand_strength_order};
+pub use draws::{
+    analyze_draws, analyze_draws_full, analyze_draws_omaha, preflop_flush_potential, DrawAnalysis,
+    DrawType, FlushDraw, ImprovementDraw, StraightDraw,
+};
 pub use equity::{
-    calculate_equity, calculate_equity_with_ranges, EquityRequest, EquityResult, PlayerEquity,
+    calculate_equity, calculate_equity_batched, calculate_equity_breakdown,
+    calculate_equity_breakdown_with_rng, calculate_equity_with_ranges, calculate_equity_with_rng,
+    calculate_range_grid, equity_by_street, exact_heads_up, ComboEquity, EquityCache,
+    EquityRequest, EquityRequestBuilder, EquityResult, EquitySession, MatchupClass, PlayerEquity,
     PlayerHand, RangeEquityRequest, RangeEquityResult, RangePlayer, RangePlayerEquity,
+    ShowdownRow, StrategyConfig, StreetEquities, TeamEquity,
 };
 pub use error::{HoldemError, HoldemResult};
-pub use evaluator::{evaluate_hand, find_winners, HandRank, HandType};
-pub use range::{CardDistribution, Odometer, RangeError};
+pub use evaluator::{
+    evaluate_hand, evaluate_many, find_winners, find_winners_detailed, rank_all, HandRank, HandType,
+    PlayerResult,
+};
+pub use range::{CardDistribution, Odometer, RangeError, RangeMatrix, WeightedSampler};
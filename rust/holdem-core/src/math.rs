@@ -0,0 +1,85 @@
+//! Bet-sizing math for game-theory decisions.
+//!
+//! Turns a bet and pot size into the frequencies a balanced strategy
+//! should continue or bluff with.
+
+use crate::range::CardDistribution;
+
+/// Minimum defense frequency: how often to continue against `bet` into `pot`
+///
+/// `pot / (pot + bet)`, so calling or raising at least this often stops
+/// any two cards from bluffing profitably (pot-sized bet = 50%).
+#[must_use]
+pub fn mdf(bet: f64, pot: f64) -> f64 {
+    pot / (pot + bet)
+}
+
+/// Alpha: how often a bluff of `bet` into `pot` must work to break even
+///
+/// `bet / (pot + bet)`, the complement of `mdf` (pot-sized bet = 50%).
+#[must_use]
+pub fn alpha(bet: f64, pot: f64) -> f64 {
+    bet / (pot + bet)
+}
+
+/// Number of combos from `range` to continue with to defend at `mdf`
+///
+/// Combos count by their weight and the result rounds up, so defending
+/// this many combos meets the frequency. `mdf` is clamped to 0-1.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn combos_to_defend(range: &CardDistribution, mdf: f64) -> usize {
+    let total: f64 = (0..range.len()).map(|i| range.weight(i)).sum();
+    // Shave float noise so e.g. 2/3 of 6 combos is 4, not 5
+    (total * mdf.clamp(0.0, 1.0) - 1e-9).ceil().max(0.0) as usize
+}
+
+/// Fraction of a betting range that can be bluffs when betting `bet` into `pot`
+///
+/// `bet / (pot + 2 * bet)`: the bluff share that gives a caller's pot odds
+/// exactly zero EV (pot-sized bet = 1/3).
+#[must_use]
+pub fn bluff_ratio(bet: f64, pot: f64) -> f64 {
+    bet / (pot + 2.0 * bet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_mdf_textbook_values() {
+        assert!(close(mdf(100.0, 100.0), 0.5));
+        assert!(close(mdf(50.0, 100.0), 2.0 / 3.0));
+        assert!(close(mdf(200.0, 100.0), 1.0 / 3.0));
+        assert!(close(mdf(0.0, 100.0), 1.0));
+    }
+
+    #[test]
+    fn test_alpha_and_combos_to_defend() {
+        assert!(close(alpha(100.0, 100.0), 0.5));
+        assert!(close(alpha(50.0, 100.0), 1.0 / 3.0));
+        assert!(close(alpha(50.0, 100.0) + mdf(50.0, 100.0), 1.0));
+
+        // 12 combos: half of them against a pot-sized bet
+        let range = CardDistribution::from_range(&["AA".to_string(), "KK".to_string()], &[])
+            .unwrap();
+        assert_eq!(combos_to_defend(&range, mdf(100.0, 100.0)), 6);
+        assert_eq!(combos_to_defend(&range, mdf(50.0, 100.0)), 8);
+        assert_eq!(combos_to_defend(&range, 0.3), 4);
+        assert_eq!(combos_to_defend(&range, 1.5), 12);
+        assert_eq!(combos_to_defend(&CardDistribution::new(), 0.5), 0);
+    }
+
+    #[test]
+    fn test_bluff_ratio_textbook_values() {
+        assert!(close(bluff_ratio(100.0, 100.0), 1.0 / 3.0));
+        assert!(close(bluff_ratio(50.0, 100.0), 0.25));
+        assert!(close(bluff_ratio(200.0, 100.0), 0.4));
+        assert!(close(bluff_ratio(0.0, 100.0), 0.0));
+    }
+}
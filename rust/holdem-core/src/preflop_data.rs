@@ -0,0 +1,197 @@
+//! Precomputed preflop equity for the 169 canonical hands.
+//!
+//! Generated from the `precompute` bin output
+//! (`web/frontend/src/data/preflop-equity-{N}.json`); regenerate it when
+//! those files change. Equities are percentages vs `N - 1` random opponents,
+//! in `get_all_canonical_hands()` order (pairs, suited, offsuit; each from
+//! strongest rank down).
+
+/// Smallest player count in `PREFLOP_EQUITY`
+pub(crate) const MIN_PLAYERS: usize = 2;
+/// Largest player count in `PREFLOP_EQUITY`
+pub(crate) const MAX_PLAYERS: usize = 10;
+
+/// `PREFLOP_EQUITY[num_players - MIN_PLAYERS][hand_index]` in percent
+#[rustfmt::skip]
+pub(crate) const PREFLOP_EQUITY: [[f64; 169]; MAX_PLAYERS - MIN_PLAYERS + 1] = [
+    // 2 players
+    [
+        85.2, 82.4, 79.9, 77.5, 75.0, 72.1, 69.1, 66.2, 63.2, 60.2,
+        57.1, 53.6, 50.3, 67.1, 66.2, 65.4, 64.6, 62.7, 61.9, 60.9,
+        59.8, 59.9, 59.0, 58.1, 57.3, 63.4, 62.6, 61.8, 60.0, 58.4,
+        57.5, 56.7, 55.8, 54.9, 54.0, 53.2, 60.3, 59.4, 57.7, 55.9,
+        54.3, 53.6, 52.7, 51.9, 51.0, 50.2, 57.5, 55.7, 53.9, 52.4,
+        50.6, 50.0, 49.1, 48.2, 47.4, 54.0, 52.4, 50.6, 48.9, 47.1,
+        46.6, 45.7, 44.8, 50.8, 49.1, 47.5, 45.7, 43.9, 43.3, 42.5,
+        47.9, 46.1, 44.5, 42.7, 40.9, 40.2, 45.4, 43.7, 41.9, 40.0,
+        38.1, 43.1, 41.4, 39.5, 37.8, 41.5, 39.8, 38.0, 38.6, 36.9,
+        36.0, 65.3, 64.5, 63.6, 62.7, 60.7, 59.9, 58.8, 57.7, 57.7,
+        56.8, 55.8, 54.9, 61.5, 60.6, 59.8, 57.8, 56.0, 55.2, 54.2,
+        53.4, 52.3, 51.4, 50.5, 58.1, 57.3, 55.4, 53.6, 51.8, 51.1,
+        50.1, 49.3, 48.2, 47.3, 55.3, 53.3, 51.6, 49.7, 47.8, 47.1,
+        46.2, 45.3, 44.4, 51.5, 49.7, 47.9, 46.2, 44.2, 43.5, 42.6,
+        41.7, 48.0, 46.3, 44.6, 42.7, 40.6, 40.0, 39.1, 45.1, 43.2,
+        41.3, 39.5, 37.6, 36.8, 42.3, 40.4, 38.5, 36.6, 34.5, 40.0,
+        38.0, 36.1, 34.1, 38.1, 36.3, 34.3, 35.1, 33.2, 32.3,
+    ],
+    // 3 players
+    [
+        73.4, 68.9, 64.9, 61.2, 57.6, 53.7, 50.0, 46.5, 43.2, 40.1,
+        36.7, 33.6, 30.7, 50.8, 49.5, 48.2, 47.2, 44.5, 43.6, 42.4,
+        41.2, 41.4, 40.5, 39.6, 38.7, 47.1, 45.9, 44.8, 42.3, 40.1,
+        39.3, 38.4, 37.3, 36.4, 35.7, 34.9, 44.2, 43.1, 40.6, 38.6,
+        36.5, 35.7, 34.9, 34.1, 33.2, 32.5, 41.9, 39.5, 37.4, 35.3,
+        33.4, 32.8, 31.9, 31.1, 30.4, 38.7, 36.7, 34.6, 32.7, 30.8,
+        30.2, 29.4, 28.6, 36.0, 34.0, 32.1, 30.2, 28.3, 27.8, 27.0,
+        33.8, 31.9, 30.1, 28.2, 26.4, 25.8, 31.9, 30.1, 28.3, 26.5,
+        24.5, 30.3, 28.5, 26.6, 24.8, 29.0, 27.3, 25.5, 26.5, 24.8,
+        23.9, 48.3, 46.8, 45.5, 44.2, 41.6, 40.5, 39.2, 37.9, 38.3,
+        37.2, 36.2, 35.2, 44.3, 43.1, 41.9, 39.2, 36.9, 35.8, 34.9,
+        33.9, 32.9, 32.1, 31.2, 41.3, 40.2, 37.5, 35.3, 33.1, 32.2,
+        31.3, 30.4, 29.5, 28.6, 39.0, 36.4, 34.2, 31.9, 29.8, 29.1,
+        28.2, 27.3, 26.5, 35.7, 33.4, 31.2, 29.1, 27.1, 26.5, 25.6,
+        24.7, 32.7, 30.7, 28.5, 26.5, 24.5, 23.9, 23.1, 30.5, 28.4,
+        26.4, 24.4, 22.4, 21.7, 28.4, 26.5, 24.4, 22.5, 20.5, 26.7,
+        24.8, 22.8, 20.7, 25.3, 23.4, 21.5, 22.5, 20.6, 19.8,
+    ],
+    // 4 players
+    [
+        63.9, 58.3, 53.5, 49.2, 45.2, 41.2, 37.6, 34.4, 31.5, 28.9,
+        26.2, 24.0, 22.0, 41.5, 39.8, 38.4, 37.2, 34.5, 33.4, 32.4,
+        31.4, 31.8, 31.0, 30.2, 29.4, 38.4, 36.8, 35.7, 32.9, 30.7,
+        30.0, 29.0, 28.2, 27.5, 26.9, 26.3, 35.7, 34.5, 31.9, 29.7,
+        27.7, 27.0, 26.3, 25.6, 24.8, 24.3, 33.8, 31.1, 29.2, 27.1,
+        25.2, 24.6, 24.0, 23.3, 22.7, 30.9, 28.8, 26.9, 25.0, 23.3,
+        22.7, 22.1, 21.5, 28.4, 26.6, 24.8, 23.1, 21.3, 20.9, 20.2,
+        26.6, 25.0, 23.2, 21.4, 19.8, 19.3, 25.1, 23.5, 21.8, 20.0,
+        18.5, 23.7, 22.2, 20.4, 18.8, 22.7, 21.2, 19.6, 20.4, 18.9,
+        18.2, 38.4, 36.8, 35.4, 34.0, 31.1, 29.9, 28.8, 27.4, 28.1,
+        27.1, 26.3, 25.4, 35.3, 33.8, 32.4, 29.5, 27.1, 26.2, 25.2,
+        24.4, 23.5, 22.8, 22.2, 32.6, 31.3, 28.4, 26.1, 24.0, 23.2,
+        22.4, 21.5, 20.9, 20.1, 30.7, 27.8, 25.6, 23.5, 21.4, 20.8,
+        20.0, 19.2, 18.6, 27.6, 25.3, 23.2, 21.2, 19.3, 18.7, 18.0,
+        17.3, 25.0, 23.1, 21.1, 19.2, 17.2, 16.7, 16.0, 23.0, 21.2,
+        19.4, 17.5, 15.7, 15.2, 21.4, 19.7, 17.8, 16.0, 14.2, 19.9,
+        18.2, 16.5, 14.7, 18.9, 17.2, 15.4, 16.4, 14.8, 14.0,
+    ],
+    // 5 players
+    [
+        55.9, 49.8, 44.8, 40.3, 36.4, 32.5, 29.5, 26.8, 24.5, 22.4,
+        20.5, 19.0, 17.7, 35.4, 33.6, 32.3, 31.0, 28.3, 27.3, 26.4,
+        25.4, 26.0, 25.4, 24.7, 24.1, 32.5, 31.0, 29.9, 27.2, 25.2,
+        24.4, 23.6, 23.0, 22.4, 21.9, 21.3, 30.2, 29.1, 26.4, 24.4,
+        22.5, 21.8, 21.3, 20.7, 20.1, 19.8, 28.7, 25.9, 24.1, 22.2,
+        20.5, 19.9, 19.4, 19.0, 18.5, 26.0, 23.9, 22.2, 20.4, 18.9,
+        18.4, 18.0, 17.5, 23.6, 22.1, 20.4, 18.8, 17.3, 16.9, 16.4,
+        22.1, 20.5, 19.1, 17.5, 16.0, 15.7, 20.9, 19.4, 17.9, 16.3,
+        15.0, 19.8, 18.3, 16.9, 15.4, 18.8, 17.6, 16.2, 17.0, 15.7,
+        15.0, 32.3, 30.4, 28.8, 27.6, 24.6, 23.5, 22.4, 21.4, 22.0,
+        21.3, 20.6, 19.9, 29.2, 27.7, 26.4, 23.5, 21.3, 20.4, 19.6,
+        18.9, 18.2, 17.6, 17.1, 26.9, 25.7, 22.8, 20.6, 18.6, 17.9,
+        17.2, 16.6, 16.0, 15.4, 25.3, 22.5, 20.3, 18.3, 16.5, 15.9,
+        15.4, 14.7, 14.2, 22.5, 20.4, 18.4, 16.5, 14.8, 14.3, 13.8,
+        13.2, 20.1, 18.3, 16.5, 14.8, 13.2, 12.7, 12.2, 18.4, 16.8,
+        15.1, 13.5, 11.9, 11.5, 17.1, 15.5, 13.9, 12.3, 10.8, 15.9,
+        14.4, 12.8, 11.3, 15.0, 13.6, 12.2, 12.9, 11.5, 10.8,
+    ],
+    // 6 players
+    [
+        49.2, 42.9, 37.9, 33.6, 29.9, 26.6, 24.0, 21.8, 20.1, 18.5,
+        17.3, 16.3, 15.5, 31.0, 29.2, 27.8, 26.7, 24.1, 23.2, 22.4,
+        21.6, 22.2, 21.7, 21.2, 20.6, 28.4, 26.9, 25.8, 23.3, 21.4,
+        20.6, 20.1, 19.4, 19.0, 18.6, 18.2, 26.2, 25.2, 22.6, 20.7,
+        19.0, 18.6, 18.1, 17.6, 17.1, 16.8, 24.9, 22.4, 20.5, 18.8,
+        17.2, 16.9, 16.4, 16.1, 15.7, 22.4, 20.6, 18.9, 17.4, 15.9,
+        15.6, 15.2, 14.9, 20.3, 18.9, 17.3, 15.9, 14.6, 14.3, 14.0,
+        19.0, 17.6, 16.3, 14.9, 13.7, 13.3, 17.9, 16.7, 15.3, 14.0,
+        12.8, 17.0, 15.9, 14.6, 13.3, 16.5, 15.3, 14.1, 14.8, 13.6,
+        13.1, 27.8, 25.9, 24.3, 23.1, 20.2, 19.2, 18.4, 17.5, 18.1,
+        17.5, 16.9, 16.3, 25.1, 23.5, 22.2, 19.5, 17.4, 16.6, 15.8,
+        15.3, 14.8, 14.2, 13.8, 23.0, 21.7, 18.9, 16.9, 15.0, 14.4,
+        13.8, 13.3, 12.9, 12.4, 21.4, 18.8, 16.7, 14.9, 13.3, 12.8,
+        12.3, 11.8, 11.4, 18.8, 16.9, 15.1, 13.3, 11.9, 11.5, 11.0,
+        10.6, 16.6, 15.1, 13.5, 11.9, 10.5, 10.1, 9.7, 15.3, 13.8,
+        12.3, 10.9, 9.5, 9.1, 14.1, 12.8, 11.4, 10.0, 8.6, 13.2,
+        12.0, 10.6, 9.1, 12.7, 11.4, 10.0, 10.8, 9.6, 9.0,
+    ],
+    // 7 players
+    [
+        43.5, 37.5, 32.6, 28.5, 25.2, 22.4, 20.3, 18.6, 17.3, 16.1,
+        15.2, 14.6, 14.1, 27.6, 25.9, 24.5, 23.5, 21.1, 20.2, 19.5,
+        18.8, 19.5, 19.0, 18.7, 18.1, 25.2, 23.8, 22.8, 20.3, 18.6,
+        18.0, 17.4, 17.0, 16.6, 16.3, 16.0, 23.2, 22.2, 19.8, 18.1,
+        16.6, 16.1, 15.7, 15.3, 15.1, 14.8, 22.1, 19.6, 17.9, 16.4,
+        15.0, 14.7, 14.3, 14.0, 13.7, 19.7, 18.1, 16.6, 15.1, 13.9,
+        13.6, 13.4, 13.0, 17.8, 16.6, 15.2, 13.9, 12.8, 12.5, 12.2,
+        16.7, 15.7, 14.3, 13.0, 12.0, 11.7, 15.9, 14.8, 13.6, 12.4,
+        11.3, 15.2, 14.2, 13.0, 11.8, 14.7, 13.7, 12.5, 13.2, 12.3,
+        11.7, 24.4, 22.4, 20.9, 19.7, 17.1, 16.1, 15.3, 14.6, 15.2,
+        14.8, 14.3, 13.7, 21.8, 20.3, 19.1, 16.5, 14.6, 13.8, 13.2,
+        12.7, 12.3, 12.0, 11.6, 19.7, 18.7, 16.0, 14.2, 12.5, 12.0,
+        11.5, 11.0, 10.8, 10.4, 18.6, 16.0, 14.1, 12.4, 11.0, 10.6,
+        10.2, 9.8, 9.5, 16.1, 14.4, 12.8, 11.1, 9.8, 9.5, 9.2,
+        8.9, 14.2, 12.8, 11.3, 9.9, 8.7, 8.4, 8.0, 13.1, 11.8,
+        10.4, 9.0, 7.8, 7.6, 12.1, 11.1, 9.7, 8.3, 7.2, 11.4,
+        10.3, 9.0, 7.8, 11.0, 9.9, 8.6, 9.3, 8.2, 7.7,
+    ],
+    // 8 players
+    [
+        38.7, 32.9, 28.3, 24.7, 21.8, 19.4, 17.7, 16.4, 15.4, 14.5,
+        13.8, 13.4, 13.2, 25.0, 23.3, 22.1, 20.9, 18.8, 17.9, 17.3,
+        16.8, 17.4, 17.1, 16.7, 16.3, 22.6, 21.3, 20.4, 18.0, 16.6,
+        16.0, 15.5, 15.1, 14.8, 14.6, 14.3, 20.8, 19.9, 17.6, 16.1,
+        14.7, 14.3, 13.9, 13.7, 13.4, 13.2, 19.8, 17.5, 15.9, 14.6,
+        13.4, 13.0, 12.7, 12.5, 12.3, 17.7, 16.2, 14.8, 13.4, 12.4,
+        12.1, 11.9, 11.7, 15.9, 14.9, 13.6, 12.4, 11.4, 11.1, 11.0,
+        15.1, 14.0, 12.9, 11.7, 10.8, 10.5, 14.4, 13.4, 12.3, 11.2,
+        10.1, 13.8, 12.9, 11.7, 10.7, 13.5, 12.6, 11.5, 12.0, 11.2,
+        10.7, 21.6, 19.7, 18.3, 17.1, 14.6, 13.7, 13.1, 12.5, 13.1,
+        12.8, 12.4, 11.9, 19.1, 17.7, 16.7, 14.1, 12.4, 11.8, 11.3,
+        10.8, 10.5, 10.2, 10.0, 17.3, 16.3, 13.8, 12.1, 10.6, 10.2,
+        9.8, 9.4, 9.2, 8.9, 16.3, 13.9, 12.1, 10.6, 9.3, 9.0,
+        8.7, 8.3, 8.1, 14.2, 12.5, 10.9, 9.5, 8.3, 8.0, 7.9,
+        7.5, 12.3, 11.1, 9.8, 8.5, 7.4, 7.1, 6.9, 11.4, 10.3,
+        9.1, 7.8, 6.7, 6.4, 10.7, 9.6, 8.5, 7.2, 6.1, 10.1,
+        9.1, 8.0, 6.7, 9.8, 8.9, 7.7, 8.3, 7.3, 6.8,
+    ],
+    // 9 players
+    [
+        34.6, 29.1, 25.1, 21.7, 19.2, 17.2, 15.8, 14.8, 14.0, 13.2,
+        12.9, 12.6, 12.5, 22.7, 21.1, 20.0, 19.0, 16.8, 16.3, 15.7,
+        15.2, 15.8, 15.5, 15.2, 14.8, 20.4, 19.3, 18.5, 16.3, 14.9,
+        14.4, 13.9, 13.6, 13.4, 13.2, 13.0, 18.8, 18.1, 15.9, 14.4,
+        13.3, 12.9, 12.6, 12.4, 12.1, 12.0, 18.0, 15.9, 14.4, 13.2,
+        12.1, 11.8, 11.6, 11.4, 11.2, 16.1, 14.7, 13.4, 12.2, 11.2,
+        11.0, 10.8, 10.6, 14.5, 13.5, 12.4, 11.3, 10.3, 10.1, 9.9,
+        13.8, 12.9, 11.8, 10.6, 9.7, 9.6, 13.2, 12.4, 11.3, 10.2,
+        9.2, 12.8, 11.8, 10.9, 9.8, 12.5, 11.6, 10.6, 11.2, 10.3,
+        9.9, 19.3, 17.4, 16.1, 15.0, 12.7, 12.0, 11.4, 10.9, 11.5,
+        11.1, 10.8, 10.4, 17.0, 15.7, 14.7, 12.3, 10.8, 10.2, 9.8,
+        9.4, 9.1, 8.9, 8.7, 15.3, 14.4, 12.1, 10.6, 9.2, 8.7,
+        8.4, 8.2, 7.9, 7.7, 14.5, 12.2, 10.7, 9.2, 8.0, 7.7,
+        7.4, 7.3, 7.1, 12.6, 11.0, 9.6, 8.3, 7.2, 7.0, 6.8,
+        6.5, 11.0, 9.8, 8.6, 7.4, 6.4, 6.1, 5.9, 10.2, 9.2,
+        8.0, 6.9, 5.8, 5.6, 9.6, 8.7, 7.5, 6.4, 5.4, 9.2,
+        8.3, 7.1, 6.0, 8.9, 8.0, 6.9, 7.5, 6.6, 6.1,
+    ],
+    // 10 players
+    [
+        31.1, 26.1, 22.2, 19.3, 17.1, 15.6, 14.6, 13.6, 13.1, 12.4,
+        12.1, 12.0, 12.0, 20.7, 19.2, 18.2, 17.3, 15.4, 14.8, 14.2,
+        13.9, 14.4, 14.2, 14.0, 13.6, 18.6, 17.7, 16.9, 14.8, 13.6,
+        13.1, 12.8, 12.5, 12.3, 12.1, 11.9, 17.2, 16.6, 14.6, 13.2,
+        12.0, 11.8, 11.5, 11.3, 11.2, 11.1, 16.6, 14.5, 13.2, 12.0,
+        11.0, 10.8, 10.6, 10.4, 10.3, 14.8, 13.5, 12.3, 11.2, 10.2,
+        10.0, 9.9, 9.7, 13.4, 12.4, 11.4, 10.3, 9.4, 9.2, 9.1,
+        12.8, 11.9, 10.9, 9.8, 8.9, 8.7, 12.2, 11.5, 10.4, 9.4,
+        8.6, 11.9, 11.0, 10.1, 9.0, 11.7, 10.9, 9.8, 10.4, 9.6,
+        9.2, 17.2, 15.5, 14.3, 13.4, 11.2, 10.5, 9.9, 9.5, 10.1,
+        9.9, 9.6, 9.2, 15.2, 13.9, 13.1, 10.8, 9.5, 8.9, 8.5,
+        8.2, 8.0, 7.8, 7.6, 13.8, 12.9, 10.7, 9.2, 8.0, 7.6,
+        7.4, 7.1, 7.0, 6.8, 13.1, 10.9, 9.4, 8.1, 7.1, 6.8,
+        6.5, 6.3, 6.2, 11.3, 9.9, 8.6, 7.4, 6.4, 6.1, 5.9,
+        5.8, 9.9, 8.9, 7.6, 6.6, 5.6, 5.4, 5.2, 9.2, 8.3,
+        7.2, 6.1, 5.1, 4.9, 8.8, 7.9, 6.8, 5.7, 4.8, 8.4,
+        7.6, 6.5, 5.4, 8.2, 7.4, 6.3, 6.9, 6.0, 5.6,
+    ],
+];
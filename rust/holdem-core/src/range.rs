@@ -3,15 +3,27 @@
 //! Implements pokerstove-style range enumeration for accurate equity calculation
 //! when players have range-based hands rather than specific cards.
 
-use crate::canonize::{get_combos_excluding, CanonicalHand, CanonizeError};
-use crate::card::Card;
-use std::collections::HashSet;
+use crate::canonize::{
+    canonize_hole_cards, get_all_canonical_hands, get_combos_excluding, hand_strength_order,
+    CanonicalHand, CanonizeError,
+};
+use crate::card::{Card, FULL_DECK, Rank};
+use crate::equity::{calculate_equity_with_ranges, PlayerHand, RangeEquityRequest, RangePlayer};
+use crate::error::{HoldemError, HoldemResult};
+use crate::evaluator::{evaluate_hand, hand_score, nuts, HandType};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
 /// A player's hand distribution representing all possible hole card combinations.
 ///
 /// Similar to pokerstove's CardDistribution, this allows calculating equity
 /// against ranges by enumerating all valid combinations.
-#[derive(Clone, Debug)]
+///
+/// Serializes as a list of weighted combos, e.g.
+/// `[{"cards":"AhKh","weight":1.0}]`; a missing weight reads as 1.0.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(into = "Vec<WeightedCombo>", try_from = "Vec<WeightedCombo>")]
 pub struct CardDistribution {
     /// All possible 2-card combinations in this distribution
     hands: Vec<(Card, Card)>,
@@ -38,9 +50,14 @@ impl CardDistribution {
         }
     }
 
-    /// Parse a range from canonical hand strings (e.g., ["AA", "AKs", "QQ"])
+    /// Parse a range from hand strings (e.g., ["AA", "AKs", "QQ+", "A5s-A2s"])
+    ///
+    /// See `validate_range` for the accepted notation. Excludes any combos
+    /// that use cards in the `excluded` set.
     ///
-    /// Excludes any combos that use cards in the `excluded` set.
+    /// # Errors
+    /// Returns an error if the range is empty, a token is invalid, or no
+    /// combos remain after exclusions.
     pub fn from_range(range: &[String], excluded: &[Card]) -> Result<Self, RangeError> {
         if range.is_empty() {
             return Err(RangeError::EmptyRange);
@@ -50,13 +67,12 @@ impl CardDistribution {
         let mut weights = Vec::new();
 
         for notation in range {
-            let canonical = CanonicalHand::parse(notation)
-                .map_err(|e| RangeError::InvalidHand(notation.clone(), e))?;
-
-            let combos = get_combos_excluding(&canonical, excluded);
-            for combo in combos {
-                hands.push(combo);
-                weights.push(1.0);
+            for canonical in parse_range_token(notation)? {
+                let combos = get_combos_excluding(&canonical, excluded);
+                for combo in combos {
+                    hands.push(combo);
+                    weights.push(1.0);
+                }
             }
         }
 
@@ -112,6 +128,259 @@ impl CardDistribution {
 
         Self { hands, weights }
     }
+
+    /// Draw one combo with probability proportional to its weight
+    ///
+    /// Linear in the range size with no setup cost, so it suits one-off
+    /// draws; build a `WeightedSampler` when drawing repeatedly from the
+    /// same range. Returns `None` if no combo has a positive weight.
+    pub fn sample(&self, rng: &mut impl RngCore) -> Option<(Card, Card)> {
+        self.sample_where(rng, |_| true)
+    }
+
+    /// Like `sample`, but skipping combos that use any excluded card
+    pub fn sample_excluding(
+        &self,
+        excluded: &HashSet<Card>,
+        rng: &mut impl RngCore,
+    ) -> Option<(Card, Card)> {
+        self.sample_where(rng, |&(c1, c2)| {
+            !excluded.contains(&c1) && !excluded.contains(&c2)
+        })
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn sample_where(
+        &self,
+        rng: &mut impl RngCore,
+        keep: impl Fn(&(Card, Card)) -> bool,
+    ) -> Option<(Card, Card)> {
+        let candidates = || {
+            self.hands
+                .iter()
+                .zip(&self.weights)
+                .filter(|&(hand, &w)| w > 0.0 && keep(hand))
+        };
+        let total: f64 = candidates().map(|(_, w)| w).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut target = (rng.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64) * total;
+        let mut last = None;
+        for (&hand, &w) in candidates() {
+            if target < w {
+                return Some(hand);
+            }
+            target -= w;
+            last = Some(hand);
+        }
+        // Rounding can leave `target` just past the final weight
+        last
+    }
+
+    /// Combos in either distribution (weights: max of the two)
+    ///
+    /// Combos are compared order-insensitively, so `AhKh` and `KhAh` are the
+    /// same combo. Self's combos come first, followed by new combos from
+    /// `other` in their original order.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        let mut index = self.combo_index();
+
+        for (i, &hand) in other.hands.iter().enumerate() {
+            let weight = other.weight(i);
+            if let Some(&j) = index.get(&normalize_combo(hand)) {
+                result.weights[j] = result.weights[j].max(weight);
+            } else {
+                index.insert(normalize_combo(hand), result.hands.len());
+                result.hands.push(hand);
+                result.weights.push(weight);
+            }
+        }
+        result
+    }
+
+    /// Combos present in both distributions (weights: min of the two)
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let other_index = other.combo_index();
+        let mut result = Self::new();
+
+        for (i, &hand) in self.hands.iter().enumerate() {
+            if let Some(&j) = other_index.get(&normalize_combo(hand)) {
+                result.hands.push(hand);
+                result.weights.push(self.weight(i).min(other.weight(j)));
+            }
+        }
+        result
+    }
+
+    /// Combos in self that are not in `other` (weights unchanged)
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let other_index = other.combo_index();
+        let mut result = Self::new();
+
+        for (i, &hand) in self.hands.iter().enumerate() {
+            if !other_index.contains_key(&normalize_combo(hand)) {
+                result.hands.push(hand);
+                result.weights.push(self.weight(i));
+            }
+        }
+        result
+    }
+
+    /// Collapse the distribution into the 13x13 canonical hand grid
+    ///
+    /// Each cell holds the fraction of that hand's combos present and the
+    /// summed weight of those combos, at `matrix_row`/`matrix_col`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_matrix(&self) -> RangeMatrix {
+        let mut matrix = RangeMatrix::default();
+        let mut seen: HashSet<(Card, Card)> = HashSet::new();
+
+        for (i, &(c1, c2)) in self.hands.iter().enumerate() {
+            if !seen.insert(normalize_combo((c1, c2))) {
+                continue;
+            }
+            let hand = canonize_hole_cards(&[c1, c2]);
+            let cell = &mut matrix.cells[hand.matrix_row()][hand.matrix_col()];
+            cell.fraction += 1.0 / hand.num_combos() as f64;
+            cell.weight += self.weight(i);
+        }
+        matrix
+    }
+
+    /// Map from normalized combo to its index in this distribution
+    fn combo_index(&self) -> HashMap<(Card, Card), usize> {
+        self.hands
+            .iter()
+            .enumerate()
+            .map(|(i, &hand)| (normalize_combo(hand), i))
+            .collect()
+    }
+}
+
+/// One cell of a `RangeMatrix`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RangeCell {
+    /// Fraction of the canonical hand's combos present (0.0 - 1.0)
+    pub fraction: f64,
+    /// Summed weight of the combos present
+    pub weight: f64,
+}
+
+/// A range collapsed into the classic 13x13 grid
+///
+/// Indexed by `CanonicalHand::matrix_row` and `matrix_col`: pairs on the
+/// diagonal, suited hands above it, offsuit hands below.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RangeMatrix {
+    /// Cells by `[row][col]`
+    pub cells: [[RangeCell; 13]; 13],
+}
+
+impl RangeMatrix {
+    /// Cell for a canonical hand
+    #[must_use]
+    pub fn cell(&self, hand: &CanonicalHand) -> RangeCell {
+        self.cells[hand.matrix_row()][hand.matrix_col()]
+    }
+}
+
+/// Weighted index sampler using Vose's alias method
+///
+/// Construction is O(n); each `sample` is O(1) and consumes exactly two
+/// `u64`s from the RNG, so with `DeterministicRng` the draws are stable
+/// across `rand` versions.
+#[derive(Clone, Debug)]
+pub struct WeightedSampler {
+    /// Probability of keeping column `i` rather than jumping to its alias
+    prob: Vec<f64>,
+    /// Alias index for each column
+    alias: Vec<usize>,
+}
+
+impl WeightedSampler {
+    /// Build a sampler over indices `0..weights.len()`
+    ///
+    /// # Errors
+    /// Returns an error if `weights` is empty, or any weight is negative or
+    /// not finite, or all weights are zero.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(weights: &[f64]) -> Result<Self, RangeError> {
+        if weights.is_empty() {
+            return Err(RangeError::EmptyRange);
+        }
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err(RangeError::InvalidWeights);
+        }
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return Err(RangeError::InvalidWeights);
+        }
+
+        let n = weights.len();
+        let mut scaled: Vec<f64> = weights.iter().map(|w| w * n as f64 / total).collect();
+        let mut prob = vec![1.0; n];
+        let mut alias: Vec<usize> = (0..n).collect();
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|&i| scaled[i] < 1.0);
+        while let (Some(s), Some(&l)) = (small.pop(), large.last()) {
+            prob[s] = scaled[s];
+            alias[s] = l;
+            scaled[l] -= 1.0 - scaled[s];
+            if scaled[l] < 1.0 {
+                large.pop();
+                small.push(l);
+            }
+        }
+        // Leftovers are 1.0 up to rounding error
+
+        Ok(Self { prob, alias })
+    }
+
+    /// Build a sampler over a distribution's combos, using its weights
+    ///
+    /// # Errors
+    /// Returns an error if the distribution is empty or its weights are invalid.
+    pub fn from_distribution(dist: &CardDistribution) -> Result<Self, RangeError> {
+        Self::new(&dist.weights)
+    }
+
+    /// Number of indices this sampler draws from
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.prob.len()
+    }
+
+    /// Always false: a sampler has at least one index
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.prob.is_empty()
+    }
+
+    /// Draw an index with probability proportional to its weight
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn sample(&self, rng: &mut impl RngCore) -> usize {
+        let n = self.prob.len() as u64;
+        let column = ((u128::from(rng.next_u64()) * u128::from(n)) >> 64) as usize;
+        let coin = (rng.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64);
+        if coin < self.prob[column] {
+            column
+        } else {
+            self.alias[column]
+        }
+    }
+}
+
+/// Order a two-card combo so that `AhKh` and `KhAh` compare equal
+pub(crate) fn normalize_combo((c1, c2): (Card, Card)) -> (Card, Card) {
+    if c1 <= c2 { (c1, c2) } else { (c2, c1) }
 }
 
 impl Default for CardDistribution {
@@ -124,6 +393,16 @@ impl Default for CardDistribution {
 ///
 /// Iterates through all combinations of indices, one from each range.
 /// Similar to pokerstove's Odometer class.
+///
+/// # Ordering
+///
+/// Combinations are yielded in lexicographic order with the rightmost
+/// position changing fastest, like the digits of a mechanical odometer:
+/// `[0, 0]`, `[0, 1]`, ..., `[0, n-1]`, `[1, 0]`, ... This order is a
+/// stability guarantee: the exhaustive and sampled equity paths rely on it
+/// for reproducible results with a fixed seed.
+///
+/// Once exhausted, the odometer keeps returning `None`.
 #[derive(Clone, Debug)]
 pub struct Odometer {
     /// Size of each range
@@ -197,6 +476,260 @@ impl Iterator for Odometer {
     }
 }
 
+/// Expand one range token into the canonical hands it covers
+///
+/// Accepts a single hand ("AKs", "QQ"), a plus ("QQ+" = QQ-AA, "ATs+" =
+/// ATs-AKs) or a dash span from strong to weak ("KK-TT", "A5s-A2s").
+fn parse_range_token(token: &str) -> Result<Vec<CanonicalHand>, RangeError> {
+    let parse = |s: &str| {
+        CanonicalHand::parse(s).map_err(|e| RangeError::InvalidHand(token.to_string(), e))
+    };
+    let trimmed = token.trim();
+
+    if let Some(base) = trimmed.strip_suffix('+') {
+        let hand = parse(base)?;
+        let top = if hand.is_pair() {
+            Rank::Ace
+        } else {
+            // Kicker goes up to one below the high card
+            Rank::from_value(hand.high_rank.value() - 1).unwrap_or(hand.high_rank)
+        };
+        return Ok(span(hand, top));
+    }
+
+    if let Some((from, to)) = trimmed.split_once('-') {
+        let (strong, weak) = (parse(from)?, parse(to)?);
+        let same_shape = if strong.is_pair() {
+            weak.is_pair()
+        } else {
+            !weak.is_pair() && strong.high_rank == weak.high_rank && strong.suited == weak.suited
+        };
+        if !same_shape || strong.low_rank < weak.low_rank {
+            return Err(RangeError::InvalidSpan(token.to_string()));
+        }
+        return Ok(span(weak, strong.low_rank));
+    }
+
+    Ok(vec![parse(trimmed)?])
+}
+
+/// Hands from `start` up to `top`, raising the pair rank or the kicker
+fn span(start: CanonicalHand, top: Rank) -> Vec<CanonicalHand> {
+    Rank::ALL
+        .iter()
+        .filter(|&&r| r >= start.low_rank && r <= top)
+        .map(|&r| {
+            if start.is_pair() {
+                CanonicalHand::new(r, r, false)
+            } else {
+                CanonicalHand::new(start.high_rank, r, start.suited)
+            }
+        })
+        .collect()
+}
+
+/// Validate range notation without generating any combos
+///
+/// Cheaper than `CardDistribution::from_range` for live input feedback.
+/// Each token is a single hand ("AKs", "QQ"), a plus ("QQ+", "ATs+") or a
+/// dash span written from strong to weak ("KK-TT", "A5s-A2s").
+///
+/// # Errors
+/// Returns an error if the range is empty, a hand has a bad rank or
+/// suitedness marker, or a span is reversed or mixes hand shapes.
+pub fn validate_range(tokens: &[String]) -> Result<(), RangeError> {
+    if tokens.is_empty() {
+        return Err(RangeError::EmptyRange);
+    }
+    for token in tokens {
+        parse_range_token(token)?;
+    }
+    Ok(())
+}
+
+/// Number of distinct two-card starting hands, C(52, 2)
+const TOTAL_COMBOS: usize = 1326;
+
+/// Build a range from the top `pct` percent of starting hands
+///
+/// Canonical hands are added in `hand_strength_order(num_players)` order,
+/// strongest first, until their combos cover `pct` percent of all 1326
+/// combos. The hand that crosses the cutoff is included in full.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn top_percent(pct: f64, num_players: usize) -> CardDistribution {
+    let target = pct.clamp(0.0, 100.0) / 100.0 * TOTAL_COMBOS as f64;
+
+    let mut dist = CardDistribution::new();
+    for hand in hand_strength_order(num_players) {
+        if dist.len() as f64 >= target {
+            break;
+        }
+        for combo in get_combos_excluding(hand, &[]) {
+            dist.hands.push(combo);
+            dist.weights.push(1.0);
+        }
+    }
+    dist
+}
+
+/// Look up a built-in composite range by name
+///
+/// Names: `any_two` (all 1326 combos), `any_pair` (22-AA), `any_suited`,
+/// `any_offsuit`, `broadway` (both cards ten or higher, pairs included) and
+/// `suited_connectors` (32s-AKs). Returns `None` for an unknown name.
+#[must_use]
+pub fn named(name: &str) -> Option<CardDistribution> {
+    let keep: fn(&CanonicalHand) -> bool = match name {
+        "any_two" => |_| true,
+        "any_pair" => CanonicalHand::is_pair,
+        "any_suited" => |h| h.suited,
+        "any_offsuit" => |h| !h.suited && !h.is_pair(),
+        "broadway" => |h| h.low_rank >= Rank::Ten,
+        "suited_connectors" => |h| h.suited && h.gap() == 1,
+        _ => return None,
+    };
+
+    let mut dist = CardDistribution::new();
+    for hand in get_all_canonical_hands().iter().filter(|h| keep(h)) {
+        for combo in get_combos_excluding(hand, &[]) {
+            dist.hands.push(combo);
+            dist.weights.push(1.0);
+        }
+    }
+    Some(dist)
+}
+
+/// Hero's range equity against villain's range for every possible turn card
+///
+/// Runs `calculate_equity_with_ranges` on each flop + turn board with `sims`
+/// as its `num_simulations`, so each turn gets that function's budget: it
+/// is spread over the combo matchups according to the range-size strategy,
+/// not run `sims` times per matchup. Turn cards that block every combo of
+/// either range, or every matchup between them, are omitted. Results are
+/// in deck order.
+///
+/// # Errors
+/// Returns an error if the flop is not exactly 3 cards or contains a
+/// duplicate, or if an equity calculation fails for another reason.
+pub fn range_turn_map(
+    hero: &CardDistribution,
+    villain: &CardDistribution,
+    flop: &[Card],
+    sims: u32,
+) -> HoldemResult<Vec<(Card, f64)>> {
+    if flop.len() != 3 {
+        return Err(HoldemError::InvalidCardCount {
+            expected: "3 (flop)",
+            got: flop.len(),
+        });
+    }
+
+    let mut map = Vec::new();
+    for turn in FULL_DECK.iter().copied().filter(|c| !flop.contains(c)) {
+        let mut board = flop.to_vec();
+        board.push(turn);
+        let blocks = |dist: &CardDistribution| {
+            !dist.is_empty()
+                && dist.hands().iter().all(|(c1, c2)| board.contains(c1) || board.contains(c2))
+        };
+        if blocks(hero) || blocks(villain) {
+            continue;
+        }
+        let request = RangeEquityRequest::new(
+            vec![
+                RangePlayer::range(hero.clone()),
+                RangePlayer::range(villain.clone()),
+            ],
+            board,
+        )
+        .with_simulations(sims);
+
+        match calculate_equity_with_ranges(&request) {
+            Ok(result) => map.push((turn, result.players[0].equity)),
+            // Every hero combo clashes with every villain combo on this board
+            Err(HoldemError::NoValidCombinations) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(map)
+}
+
+/// Number of combos in `dist` that make exactly the nuts on `board`
+///
+/// Combos that share a card with the board are skipped. Weights are
+/// ignored; each remaining combo counts once.
+///
+/// # Errors
+/// Returns an error if the board is not 3-5 cards or has duplicates.
+pub fn nut_combo_count(dist: &CardDistribution, board: &[Card]) -> HoldemResult<usize> {
+    let nut_rank = nuts(board)?;
+
+    let mut count = 0;
+    for &(c1, c2) in &dist.hands {
+        if board.contains(&c1) || board.contains(&c2) {
+            continue;
+        }
+        let mut hand = vec![c1, c2];
+        hand.extend_from_slice(board);
+        if evaluate_hand(&hand)? == nut_rank {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// How often each made hand type shows up across `dist` on a complete board
+///
+/// Combos that share a card with the board are skipped, and the rest count
+/// by their weight, so the fractions sum to 1 (or the map is empty when no
+/// weighted combo fits the board). Hand types nobody makes are left out.
+#[must_use]
+pub fn made_hand_distribution(
+    dist: &CardDistribution,
+    board: &[Card; 5],
+) -> HashMap<HandType, f64> {
+    let mut totals: HashMap<HandType, f64> = HashMap::new();
+    let mut total_weight = 0.0;
+    for (&(c1, c2), &weight) in dist.hands.iter().zip(&dist.weights) {
+        if board.contains(&c1) || board.contains(&c2) {
+            continue;
+        }
+        let mut cards = [c1; 7];
+        cards[1] = c2;
+        cards[2..].copy_from_slice(board);
+        // The hand type sits above the five rank nibbles of the score
+        let hand_type = HandType::ALL[(hand_score(&cards) >> 20) as usize];
+        *totals.entry(hand_type).or_insert(0.0) += weight;
+        total_weight += weight;
+    }
+    if total_weight > 0.0 {
+        totals.retain(|_, w| *w > 0.0);
+        for w in totals.values_mut() {
+            *w /= total_weight;
+        }
+    } else {
+        totals.clear();
+    }
+    totals
+}
+
+/// Every combo of a range as a specific-card `PlayerHand`
+///
+/// Combos using a dead card are left out, in the same order as
+/// `CardDistribution::hands`. Weights are dropped.
+///
+/// # Errors
+/// Returns an error if the range is empty, a token is invalid, or no
+/// combos remain after removing dead cards.
+pub fn expand_to_player_hands(
+    range: &[String],
+    dead: &[Card],
+) -> Result<Vec<PlayerHand>, RangeError> {
+    let dist = CardDistribution::from_range(range, dead)?;
+    Ok(dist.hands().iter().map(|&(c1, c2)| PlayerHand::new(vec![c1, c2])).collect())
+}
+
 /// Check if a set of hands has any card conflicts
 #[must_use]
 pub fn hands_are_disjoint(hands: &[(Card, Card)]) -> bool {
@@ -220,6 +753,48 @@ pub fn collect_cards(hands: &[(Card, Card)]) -> HashSet<Card> {
     cards
 }
 
+/// Serialized form of one `CardDistribution` combo
+#[derive(Serialize, Deserialize)]
+struct WeightedCombo {
+    cards: String,
+    #[serde(default = "default_weight")]
+    weight: f64,
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+impl From<CardDistribution> for Vec<WeightedCombo> {
+    fn from(dist: CardDistribution) -> Self {
+        dist.hands
+            .iter()
+            .zip(dist.weights)
+            .map(|(&(c1, c2), weight)| WeightedCombo { cards: format!("{c1}{c2}"), weight })
+            .collect()
+    }
+}
+
+impl TryFrom<Vec<WeightedCombo>> for CardDistribution {
+    type Error = RangeError;
+
+    fn try_from(combos: Vec<WeightedCombo>) -> Result<Self, RangeError> {
+        let mut dist = Self::new();
+        for combo in combos {
+            let (c1, c2) = match crate::card::parse_cards(&combo.cards).as_deref() {
+                Ok(&[c1, c2]) if c1 != c2 => (c1, c2),
+                _ => return Err(RangeError::InvalidCombo(combo.cards)),
+            };
+            if !combo.weight.is_finite() || combo.weight < 0.0 {
+                return Err(RangeError::InvalidWeights);
+            }
+            dist.hands.push((c1, c2));
+            dist.weights.push(combo.weight);
+        }
+        Ok(dist)
+    }
+}
+
 /// Error type for range operations
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RangeError {
@@ -227,8 +802,14 @@ pub enum RangeError {
     EmptyRange,
     /// Invalid hand notation in range
     InvalidHand(String, CanonizeError),
+    /// Dash span that is reversed or mixes hand shapes (e.g. "22-55", "AKs-KQs")
+    InvalidSpan(String),
     /// No valid combos after excluding dead cards
     NoCombosAvailable,
+    /// Weights are negative, not finite, or all zero
+    InvalidWeights,
+    /// A serialized combo is not exactly two distinct cards
+    InvalidCombo(String),
 }
 
 impl std::fmt::Display for RangeError {
@@ -238,9 +819,22 @@ impl std::fmt::Display for RangeError {
             RangeError::InvalidHand(notation, e) => {
                 write!(f, "invalid hand '{}': {}", notation, e)
             }
+            RangeError::InvalidSpan(token) => {
+                write!(
+                    f,
+                    "invalid span '{}': write spans from strong to weak with one shape",
+                    token
+                )
+            }
             RangeError::NoCombosAvailable => {
                 write!(f, "no valid combos available after excluding dead cards")
             }
+            RangeError::InvalidWeights => {
+                write!(f, "weights must be finite, non-negative and not all zero")
+            }
+            RangeError::InvalidCombo(cards) => {
+                write!(f, "invalid combo '{cards}': expected two distinct cards")
+            }
         }
     }
 }
@@ -279,6 +873,374 @@ mod tests {
         assert_eq!(dist.len(), 12); // 6 + 6
     }
 
+    fn strings(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_range_plus_and_dash() {
+        let combos = |tokens: &[&str]| {
+            CardDistribution::from_range(&strings(tokens), &[])
+                .unwrap()
+                .len()
+        };
+
+        assert_eq!(combos(&["QQ+"]), 18); // QQ, KK, AA
+        assert_eq!(combos(&["ATs+"]), 16); // ATs, AJs, AQs, AKs
+        assert_eq!(combos(&["KTo+"]), 36); // KTo, KJo, KQo
+        assert_eq!(combos(&["AA+"]), 6);
+        assert_eq!(combos(&["KK-TT"]), 24);
+        assert_eq!(combos(&["A5s-A2s"]), 16);
+        assert_eq!(combos(&["JJ-JJ"]), 6);
+    }
+
+    #[test]
+    fn test_validate_range_valid() {
+        assert!(
+            validate_range(&strings(&["AA", "AKs", "KQo", "QQ+", "A5s-A2s", " 99-77 "])).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_range_errors() {
+        assert_eq!(validate_range(&[]), Err(RangeError::EmptyRange));
+
+        assert!(matches!(
+            validate_range(&strings(&["AA", "AXs"])),
+            Err(RangeError::InvalidHand(t, CanonizeError::InvalidRank('X'))) if t == "AXs"
+        ));
+        assert!(matches!(
+            validate_range(&strings(&["AKx"])),
+            Err(RangeError::InvalidHand(
+                _,
+                CanonizeError::InvalidSuited('x')
+            ))
+        ));
+        assert!(matches!(
+            validate_range(&strings(&["AK+"])),
+            Err(RangeError::InvalidHand(_, CanonizeError::MissingSuited))
+        ));
+
+        // Reversed and mixed-shape spans
+        for token in ["22-55", "A2s-A5s", "AKs-KQs", "A5s-A2o", "QQ-AKs"] {
+            assert_eq!(
+                validate_range(&strings(&[token])),
+                Err(RangeError::InvalidSpan(token.to_string())),
+                "{token}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_top_percent() {
+        assert_eq!(top_percent(100.0, 2).len(), 1326);
+        assert_eq!(top_percent(0.0, 2).len(), 0);
+
+        let aa = top_percent(0.45, 2);
+        assert_eq!(aa.len(), 6);
+        assert!(
+            aa.hands()
+                .iter()
+                .all(|(c1, c2)| c1.rank == Rank::Ace && c2.rank == Rank::Ace)
+        );
+
+        // Roughly 15% of combos, always a prefix of the stronger cutoff
+        let top15 = top_percent(15.0, 6);
+        assert!(top15.len() >= 199 && top15.len() < 199 + 12);
+        let top30 = top_percent(30.0, 6);
+        assert!(top15.hands().iter().all(|h| top30.hands().contains(h)));
+    }
+
+    #[test]
+    fn test_range_set_algebra() {
+        let dist = |tokens: &[&str]| CardDistribution::from_range(&strings(tokens), &[]).unwrap();
+
+        assert_eq!(dist(&["AA"]).union(&dist(&["KK"])).len(), 12);
+        assert!(dist(&["AKs"]).intersection(&dist(&["AKo"])).is_empty());
+
+        let open = dist(&["QQ+", "AKs"]);
+        let four_bet = dist(&["KK+", "AKs"]);
+        let flat = open.difference(&four_bet);
+        assert_eq!(flat.len(), 6);
+        assert!(
+            flat.hands()
+                .iter()
+                .all(|(c1, c2)| c1.rank == Rank::Queen && c2.rank == Rank::Queen)
+        );
+
+        // Union with an overlapping range does not duplicate combos
+        assert_eq!(open.union(&four_bet).len(), open.len());
+        assert_eq!(open.intersection(&four_bet).len(), four_bet.len());
+    }
+
+    #[test]
+    fn test_range_set_algebra_order_and_weights() {
+        let ah = Card::new(Rank::Ace, Suit::Hearts);
+        let kh = Card::new(Rank::King, Suit::Hearts);
+        let forward = CardDistribution::from_hand(ah, kh);
+        let mut reversed = CardDistribution::from_hand(kh, ah);
+        reversed.weights[0] = 0.25;
+
+        assert_eq!(forward.union(&reversed).len(), 1);
+        assert!((forward.union(&reversed).weight(0) - 1.0).abs() < f64::EPSILON);
+        assert!((forward.intersection(&reversed).weight(0) - 0.25).abs() < f64::EPSILON);
+        assert!(forward.difference(&reversed).is_empty());
+    }
+
+    #[test]
+    fn test_card_distribution_serde_round_trip() {
+        let mut dist = CardDistribution::from_range(&strings(&["AKs"]), &[]).unwrap();
+        dist.weights[1] = 0.5;
+
+        let json = serde_json::to_string(&dist).unwrap();
+        assert!(json.starts_with(r#"[{"cards":"AcKc","weight":1.0}"#), "{json}");
+        let back: CardDistribution = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.hands, dist.hands);
+        assert_eq!(back.weights, dist.weights);
+
+        let parsed: CardDistribution = serde_json::from_str(r#"[{"cards":"Ah Kd"}]"#).unwrap();
+        let (ah, kd) = (Card::new(Rank::Ace, Suit::Hearts), Card::new(Rank::King, Suit::Diamonds));
+        assert_eq!(parsed.get(0), Some((ah, kd)));
+        assert!((parsed.weight(0) - 1.0).abs() < f64::EPSILON);
+
+        // A sampled player keeps its distribution through serde
+        let player = crate::equity::PlayerHand::from_range(dist.clone());
+        let json = serde_json::to_string(&player).unwrap();
+        let back: crate::equity::PlayerHand = serde_json::from_str(&json).unwrap();
+        let sampled = back.sampled_range.unwrap();
+        assert_eq!(sampled.hands, dist.hands);
+        assert_eq!(sampled.weights, dist.weights);
+    }
+
+    #[test]
+    fn test_card_distribution_serde_rejects_bad_combos() {
+        for json in [r#"[{"cards":"AhAh"}]"#, r#"[{"cards":"AhKhQh"}]"#, r#"[{"cards":"Ah"}]"#] {
+            let err = serde_json::from_str::<CardDistribution>(json).unwrap_err();
+            assert!(err.to_string().contains("invalid combo"), "{json}: {err}");
+        }
+        let negative = r#"[{"cards":"AhKh","weight":-1.0}]"#;
+        assert!(serde_json::from_str::<CardDistribution>(negative).is_err());
+    }
+
+    #[test]
+    fn test_to_matrix() {
+        let aa = CanonicalHand::parse("AA").unwrap();
+        let full = CardDistribution::from_range(&strings(&["AA"]), &[]).unwrap();
+        let cell = full.to_matrix().cells[0][0];
+        assert!((cell.fraction - 1.0).abs() < f64::EPSILON);
+        assert!((cell.weight - 6.0).abs() < f64::EPSILON);
+
+        let partial =
+            CardDistribution::from_range(&strings(&["AA"]), &[Card::new(Rank::Ace, Suit::Hearts)])
+                .unwrap();
+        assert!((partial.to_matrix().cell(&aa).fraction - 0.5).abs() < f64::EPSILON);
+
+        // Suited above the diagonal, offsuit below, untouched cells empty
+        let matrix = CardDistribution::from_range(&strings(&["AKs", "AKo"]), &[])
+            .unwrap()
+            .to_matrix();
+        assert!((matrix.cells[0][1].fraction - 1.0).abs() < f64::EPSILON);
+        assert!((matrix.cells[1][0].fraction - 1.0).abs() < f64::EPSILON);
+        assert_eq!(matrix.cells[0][0], RangeCell::default());
+    }
+
+    #[test]
+    fn test_weighted_sampler_frequencies() {
+        use crate::rng::DeterministicRng;
+
+        let weights = [1.0, 0.0, 3.0, 6.0];
+        let sampler = WeightedSampler::new(&weights).unwrap();
+        let mut rng = DeterministicRng::seed_from_u64(3);
+
+        let draws = 100_000;
+        let mut counts = [0u32; 4];
+        for _ in 0..draws {
+            counts[sampler.sample(&mut rng)] += 1;
+        }
+
+        assert_eq!(counts[1], 0);
+        for (count, weight) in counts.iter().zip(weights) {
+            let freq = f64::from(*count) / f64::from(draws);
+            assert!((freq - weight / 10.0).abs() < 0.01, "{counts:?}");
+        }
+    }
+
+    #[test]
+    fn test_weighted_sampler_from_distribution() {
+        use crate::rng::DeterministicRng;
+
+        let dist = CardDistribution::from_range(&strings(&["AA", "KK"]), &[]).unwrap();
+        let sampler = WeightedSampler::from_distribution(&dist).unwrap();
+        assert_eq!(sampler.len(), 12);
+
+        let mut rng = DeterministicRng::seed_from_u64(9);
+        assert!((0..1000).all(|_| sampler.sample(&mut rng) < 12));
+    }
+
+    #[test]
+    fn test_weighted_sampler_invalid() {
+        assert_eq!(
+            WeightedSampler::new(&[]).unwrap_err(),
+            RangeError::EmptyRange
+        );
+        assert_eq!(
+            WeightedSampler::new(&[0.0, 0.0]).unwrap_err(),
+            RangeError::InvalidWeights
+        );
+        assert_eq!(
+            WeightedSampler::new(&[1.0, -1.0]).unwrap_err(),
+            RangeError::InvalidWeights
+        );
+        assert_eq!(
+            WeightedSampler::new(&[f64::NAN]).unwrap_err(),
+            RangeError::InvalidWeights
+        );
+    }
+
+    #[test]
+    fn test_sample_matches_weights() {
+        use crate::rng::DeterministicRng;
+
+        let mut dist = CardDistribution::from_range(&strings(&["AA"]), &[]).unwrap();
+        dist.weights = vec![1.0, 2.0, 3.0, 0.0, 0.0, 4.0];
+        let mut rng = DeterministicRng::seed_from_u64(11);
+
+        let draws = 50_000;
+        let mut counts = [0u32; 6];
+        for _ in 0..draws {
+            let combo = dist.sample(&mut rng).unwrap();
+            let i = dist.hands.iter().position(|&h| h == combo).unwrap();
+            counts[i] += 1;
+        }
+
+        for (count, weight) in counts.iter().zip(&dist.weights) {
+            let freq = f64::from(*count) / f64::from(draws);
+            assert!((freq - weight / 10.0).abs() < 0.01, "{counts:?}");
+        }
+    }
+
+    #[test]
+    fn test_sample_excluding() {
+        use crate::rng::DeterministicRng;
+
+        let dist = CardDistribution::from_range(&strings(&["AA", "KK"]), &[]).unwrap();
+        let mut rng = DeterministicRng::seed_from_u64(5);
+
+        let excluded: HashSet<Card> = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::King, Suit::Spades),
+        ]
+        .into_iter()
+        .collect();
+        for _ in 0..500 {
+            let (c1, c2) = dist.sample_excluding(&excluded, &mut rng).unwrap();
+            assert!(!excluded.contains(&c1) && !excluded.contains(&c2));
+        }
+
+        let all_dead: HashSet<Card> = Suit::ALL
+            .iter()
+            .flat_map(|&s| [Card::new(Rank::Ace, s), Card::new(Rank::King, s)])
+            .collect();
+        assert!(dist.sample_excluding(&all_dead, &mut rng).is_none());
+        assert!(CardDistribution::new().sample(&mut rng).is_none());
+    }
+
+    #[test]
+    fn test_range_turn_map() {
+        use crate::card::parse_cards;
+
+        // One combo each keeps the per-turn enumeration cheap
+        let hero =
+            CardDistribution::from_hand(Card::parse("As").unwrap(), Card::parse("Ah").unwrap());
+        let villain =
+            CardDistribution::from_hand(Card::parse("Ks").unwrap(), Card::parse("Kh").unwrap());
+        let flop = parse_cards("2c 7d 9h").unwrap();
+
+        let map = range_turn_map(&hero, &villain, &flop, 200).unwrap();
+        assert_eq!(map.len(), 45);
+
+        let equity_on = |s: &str| {
+            let turn = Card::parse(s).unwrap();
+            map.iter().find(|(c, _)| *c == turn).map(|&(_, e)| e)
+        };
+        // Turns that collide with a hole card are skipped
+        assert!(equity_on("As").is_none());
+        // A king turn gives villain a set
+        assert!(equity_on("Kc").unwrap() < 0.1);
+        assert!(equity_on("3c").unwrap() > 0.9);
+        assert!(range_turn_map(&hero, &villain, &flop[..2], 200).is_err());
+
+        // A range that is empty before any turn is dealt is an error, not a
+        // map with every turn skipped
+        let err = range_turn_map(&CardDistribution::new(), &villain, &flop, 200).unwrap_err();
+        assert!(matches!(err, HoldemError::PlayerHasNoCombos(0)), "{err:?}");
+    }
+
+    #[test]
+    fn test_nut_combo_count() {
+        use crate::card::parse_cards;
+
+        // Only jack-ten makes the jack-high straight
+        let board = parse_cards("9h 8c 7d 2s Kc").unwrap();
+        let dist = CardDistribution::from_range(&strings(&["JTs", "JTo", "T6s", "KK", "AA"]), &[])
+            .unwrap();
+        assert_eq!(nut_combo_count(&dist, &board).unwrap(), 16);
+
+        let no_nuts = CardDistribution::from_range(&strings(&["T6s", "AA"]), &[]).unwrap();
+        assert_eq!(nut_combo_count(&no_nuts, &board).unwrap(), 0);
+
+        assert!(nut_combo_count(&dist, &board[..2]).is_err());
+    }
+
+    #[test]
+    fn test_made_hand_distribution() {
+        use crate::card::parse_cards;
+
+        let board: [Card; 5] = parse_cards("Kh 8h 4h 2c 7d").unwrap().try_into().unwrap();
+        let broadway = ["AKs", "AQs", "AJs", "ATs", "KQs", "KJs", "KTs", "QJs", "QTs", "JTs"];
+        let mut dist = CardDistribution::from_range(&strings(&broadway), &[]).unwrap();
+
+        // The four Kh combos are blocked; six of the other 36 are hearts
+        let made = made_hand_distribution(&dist, &board);
+        assert!((made[&HandType::Flush] - 6.0 / 36.0).abs() < 1e-12, "{made:?}");
+        assert!((made.values().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(!made.contains_key(&HandType::Straight));
+
+        // Weighting the heart combos up raises the flush share accordingly
+        for (i, &(c1, _)) in dist.hands.clone().iter().enumerate() {
+            if c1.suit == Suit::Hearts {
+                dist.weights[i] = 4.0;
+            }
+        }
+        let made = made_hand_distribution(&dist, &board);
+        assert!((made[&HandType::Flush] - 24.0 / 54.0).abs() < 1e-12, "{made:?}");
+
+        let blocked = CardDistribution::from_hand(board[0], board[1]);
+        assert!(made_hand_distribution(&blocked, &board).is_empty());
+    }
+
+    #[test]
+    fn test_named_ranges() {
+        assert_eq!(named("any_two").unwrap().len(), TOTAL_COMBOS);
+        assert_eq!(named("any_pair").unwrap().len(), 78);
+        assert_eq!(named("any_suited").unwrap().len(), 312);
+        assert_eq!(named("any_offsuit").unwrap().len(), 936);
+        assert_eq!(named("suited_connectors").unwrap().len(), 48);
+        assert!(named("premium").is_none());
+
+        let broadway = named("broadway").unwrap();
+        let expected = CardDistribution::from_range(
+            &strings(&[
+                "TT+", "ATs+", "KTs+", "QTs+", "JTs", "ATo+", "KTo+", "QTo+", "JTo",
+            ]),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(broadway.len(), 190);
+        assert_eq!(broadway.difference(&expected).len(), 0);
+        assert_eq!(expected.difference(&broadway).len(), 0);
+    }
+
     #[test]
     fn test_odometer_basic() {
         let odom = Odometer::new(vec![2, 3]);
@@ -313,6 +1275,74 @@ mod tests {
         assert!(combos.is_empty());
     }
 
+    #[test]
+    fn test_odometer_three_extents_order() {
+        let odom = Odometer::new(vec![2, 2, 3]);
+        let combos: Vec<_> = odom.collect();
+        assert_eq!(combos.len(), 12);
+        assert_eq!(combos[0], vec![0, 0, 0]);
+        assert_eq!(combos[1], vec![0, 0, 1]);
+        assert_eq!(combos[2], vec![0, 0, 2]);
+        assert_eq!(combos[3], vec![0, 1, 0]);
+        assert_eq!(combos[6], vec![1, 0, 0]);
+        assert_eq!(combos[11], vec![1, 1, 2]);
+
+        // Rightmost-fastest means the sequence is strictly lexicographic
+        for pair in combos.windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_odometer_four_extents_count() {
+        let odom = Odometer::new(vec![3, 1, 4, 2]);
+        assert_eq!(odom.total_combinations(), 24);
+        let combos: Vec<_> = odom.collect();
+        assert_eq!(combos.len(), 24);
+        assert_eq!(combos.first(), Some(&vec![0, 0, 0, 0]));
+        assert_eq!(combos.last(), Some(&vec![2, 0, 3, 1]));
+    }
+
+    #[test]
+    fn test_odometer_stays_exhausted() {
+        let mut odom = Odometer::new(vec![2, 1]);
+        assert_eq!(odom.next(), Some(vec![0, 0]));
+        assert_eq!(odom.next(), Some(vec![1, 0]));
+        assert_eq!(odom.next(), None);
+        assert_eq!(odom.next(), None);
+        assert_eq!(odom.next(), None);
+    }
+
+    #[test]
+    fn test_odometer_indices_track_current() {
+        let mut odom = Odometer::new(vec![2, 2]);
+        assert_eq!(odom.indices(), &[0, 0]);
+        odom.next();
+        assert_eq!(odom.indices(), &[0, 0]);
+        odom.next();
+        assert_eq!(odom.indices(), &[0, 1]);
+
+        // Empty odometer is exhausted before it starts
+        let mut empty = Odometer::new(vec![0]);
+        assert_eq!(empty.next(), None);
+        assert_eq!(empty.next(), None);
+    }
+
+    #[test]
+    fn test_expand_to_player_hands() {
+        let hands = expand_to_player_hands(&["AA".to_string()], &[]).unwrap();
+        assert_eq!(hands.len(), 6);
+        for hand in &hands {
+            assert_eq!(hand.cards.len(), 2);
+            assert!(hand.cards.iter().all(|c| c.rank == Rank::Ace));
+            assert_ne!(hand.cards[0], hand.cards[1]);
+        }
+
+        let dead = [Card::new(Rank::Ace, Suit::Spades)];
+        assert_eq!(expand_to_player_hands(&["AA".to_string()], &dead).unwrap().len(), 3);
+        assert!(expand_to_player_hands(&[], &[]).is_err());
+    }
+
     #[test]
     fn test_hands_are_disjoint() {
         let ah = Card::new(Rank::Ace, Suit::Hearts);
@@ -3,6 +3,9 @@
 //! This crate provides WASM-compatible functions that can be called from JavaScript.
 //! All functions use JSON serialization via `serde-wasm-bindgen` for data exchange.
 
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
 use wasm_bindgen::prelude::*;
 
 use holdem_core::{
@@ -59,16 +62,23 @@ pub fn wasm_calculate_equity(request: JsValue) -> Result<JsValue, JsValue> {
     let req: EquityRequestInput = serde_wasm_bindgen::from_value(request)
         .map_err(|e| JsValue::from_str(&format!("Failed to parse request: {e}")))?;
 
-    let result = calculate_equity_impl(req)
+    // Use js_sys::Date for timing in WASM
+    let start = js_sys::Date::now();
+    let precision = req.precision;
+    let mut result = calculate_equity_impl(req)
         .map_err(|e| JsValue::from_str(&e))?;
+    result.elapsed_ms = js_sys::Date::now() - start;
+    if let Some(decimals) = precision {
+        result = result.round_to(decimals);
+    }
 
     serde_wasm_bindgen::to_value(&result)
         .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {e}")))
 }
 
 fn calculate_equity_impl(request: EquityRequestInput) -> Result<EquityResultOutput, String> {
-    // Parse board
-    let board = parse_card_strings(&request.board)?;
+    // Parse board (flat list or flop/turn/river)
+    let board = request.resolve_board()?;
 
     // Parse dead cards
     let dead_cards = parse_card_strings(&request.dead_cards)?;
@@ -85,17 +95,19 @@ fn calculate_equity_impl(request: EquityRequestInput) -> Result<EquityResultOutp
     }
 }
 
-/// Calculate equity using the new range enumeration algorithm
-fn calculate_equity_with_ranges_impl(
+/// Build range-engine players from the request inputs.
+///
+/// Returns the players together with their display descriptions.
+fn build_range_players(
     request: &EquityRequestInput,
-    board: Vec<Card>,
-    dead_cards: Vec<Card>,
-) -> Result<EquityResultOutput, String> {
+    board: &[Card],
+    dead_cards: &[Card],
+) -> Result<(Vec<RangePlayer>, Vec<String>), String> {
     let mut range_players: Vec<RangePlayer> = Vec::new();
     let mut hand_descriptions: Vec<String> = Vec::new();
 
     // Build excluded cards (board + dead)
-    let mut excluded: Vec<Card> = dead_cards.clone();
+    let mut excluded: Vec<Card> = dead_cards.to_vec();
     excluded.extend(board.iter().cloned());
 
     for (i, player_input) in request.players.iter().enumerate() {
@@ -138,16 +150,24 @@ fn calculate_equity_with_ranges_impl(
         return Err("Need at least 2 players".to_string());
     }
 
+    Ok((range_players, hand_descriptions))
+}
+
+/// Calculate equity using the new range enumeration algorithm
+fn calculate_equity_with_ranges_impl(
+    request: &EquityRequestInput,
+    board: Vec<Card>,
+    dead_cards: Vec<Card>,
+) -> Result<EquityResultOutput, String> {
+    let (range_players, hand_descriptions) = build_range_players(request, &board, &dead_cards)?;
+
     // Build range equity request
     let eq_request = RangeEquityRequest::new(range_players, board)
         .with_simulations(request.num_simulations)
         .with_dead_cards(dead_cards);
 
-    // Use js_sys::Date for timing in WASM
-    let start = js_sys::Date::now();
     let result = equity::calculate_equity_with_ranges(&eq_request)
         .map_err(|e| e.to_string())?;
-    let elapsed_ms = js_sys::Date::now() - start;
 
     // Convert to output format
     Ok(EquityResultOutput {
@@ -165,19 +185,18 @@ fn calculate_equity_with_ranges_impl(
             })
             .collect(),
         total_simulations: result.total_simulations,
-        elapsed_ms,
+        elapsed_ms: result.elapsed_ms,
     })
 }
 
-/// Calculate equity using the original algorithm (specific cards and random only)
-fn calculate_equity_simple_impl(
+/// Build specific/random players for the simulation engine.
+///
+/// Returns the players with their display descriptions.
+fn build_simple_players(
     request: &EquityRequestInput,
-    board: Vec<Card>,
-    dead_cards: Vec<Card>,
-) -> Result<EquityResultOutput, String> {
+) -> Result<(Vec<PlayerHand>, Vec<String>), String> {
     let mut players: Vec<PlayerHand> = Vec::new();
     let mut hand_descriptions: Vec<String> = Vec::new();
-    let mut combo_counts: Vec<usize> = Vec::new();
 
     for (i, player_input) in request.players.iter().enumerate() {
         if let Some(cards) = &player_input.cards {
@@ -191,12 +210,10 @@ fn calculate_equity_simple_impl(
                     ));
                 }
                 hand_descriptions.push(format!("{}{}", parsed[0], parsed[1]));
-                combo_counts.push(1);
                 players.push(PlayerHand::new(parsed));
             }
         } else if player_input.random {
             hand_descriptions.push("Random".to_string());
-            combo_counts.push(1326);
             players.push(PlayerHand::random());
         } else {
             return Err(format!(
@@ -210,19 +227,236 @@ fn calculate_equity_simple_impl(
         return Err("Need at least 2 players".to_string());
     }
 
+    Ok((players, hand_descriptions))
+}
+
+/// Convert a simulation result to the output format
+fn simple_output(
+    result: &equity::EquityResult,
+    hand_descriptions: &[String],
+) -> EquityResultOutput {
+    EquityResultOutput {
+        players: result
+            .players
+            .iter()
+            .enumerate()
+            .map(|(i, p)| PlayerEquityOutput {
+                index: p.index,
+                hand_description: hand_descriptions.get(i).cloned().unwrap_or_default(),
+                equity: p.equity,
+                win_rate: p.win_rate,
+                tie_rate: p.tie_rate,
+                combos: p.combos,
+            })
+            .collect(),
+        total_simulations: result.total_simulations,
+        elapsed_ms: result.elapsed_ms,
+    }
+}
+
+/// Calculate equity using the original algorithm (specific cards and random only)
+fn calculate_equity_simple_impl(
+    request: &EquityRequestInput,
+    board: Vec<Card>,
+    dead_cards: Vec<Card>,
+) -> Result<EquityResultOutput, String> {
+    let (players, hand_descriptions) = build_simple_players(request)?;
+
     // Build equity request
     let eq_request = equity::EquityRequest::new(players, board)
         .with_simulations(request.num_simulations)
         .with_dead_cards(dead_cards);
 
+    let result = equity::calculate_equity(&eq_request)
+        .map_err(|e| e.to_string())?;
+
+    Ok(simple_output(&result, &hand_descriptions))
+}
+
+// ============================================================================
+// Resumable Equity Sessions
+// ============================================================================
+
+/// An equity calculation kept alive between `wasm_equity_step` calls
+struct EquitySessionState {
+    session: equity::EquitySession,
+    hand_descriptions: Vec<String>,
+    precision: Option<u32>,
+}
+
+impl EquitySessionState {
+    fn output(&self, result: &equity::EquityResult) -> EquityResultOutput {
+        let output = simple_output(result, &self.hand_descriptions);
+        match self.precision {
+            Some(decimals) => output.round_to(decimals),
+            None => output,
+        }
+    }
+}
+
+thread_local! {
+    static SESSIONS: RefCell<HashMap<u32, EquitySessionState>> = RefCell::new(HashMap::new());
+    static NEXT_SESSION: Cell<u32> = const { Cell::new(1) };
+}
+
+/// Start a resumable equity calculation.
+///
+/// Only specific and random players are supported; use `wasm_calculate_equity`
+/// for ranges.
+///
+/// # Arguments
+/// * `request` - JsValue containing `EquityRequest`
+///
+/// # Returns
+/// A numeric session handle for `wasm_equity_step` and `wasm_equity_finish`
+#[wasm_bindgen]
+pub fn wasm_equity_start(request: JsValue) -> Result<u32, JsValue> {
+    let req: EquityRequestInput = serde_wasm_bindgen::from_value(request)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse request: {e}")))?;
+
+    equity_start_impl(&req).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Run up to `batch` more simulations of a session.
+///
+/// # Returns
+/// JsValue containing `EquityProgressResponse` (cumulative equities so far)
+#[wasm_bindgen]
+pub fn wasm_equity_step(handle: u32, batch: u32) -> Result<JsValue, JsValue> {
+    let result = equity_step_impl(handle, batch).map_err(|e| JsValue::from_str(&e))?;
+
+    serde_wasm_bindgen::to_value(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {e}")))
+}
+
+/// End a session and release it.
+///
+/// # Returns
+/// JsValue containing `EquityResponse` for the simulations run so far
+#[wasm_bindgen]
+pub fn wasm_equity_finish(handle: u32) -> Result<JsValue, JsValue> {
+    let result = equity_finish_impl(handle).map_err(|e| JsValue::from_str(&e))?;
+
+    serde_wasm_bindgen::to_value(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {e}")))
+}
+
+/// Discard a session without computing its result.
+///
+/// Call this for sessions that will never be finished (e.g. the user
+/// cancelled), so they do not stay in memory.
+///
+/// # Returns
+/// Whether a session with this handle existed
+#[wasm_bindgen]
+pub fn wasm_equity_drop(handle: u32) -> bool {
+    equity_drop_impl(handle)
+}
+
+fn equity_start_impl(request: &EquityRequestInput) -> Result<u32, String> {
+    if request.players.iter().any(|p| p.range.is_some()) {
+        return Err("Range players are not supported in equity sessions".to_string());
+    }
+
+    let board = request.resolve_board()?;
+    let dead_cards = parse_card_strings(&request.dead_cards)?;
+    let (players, hand_descriptions) = build_simple_players(request)?;
+
+    let eq_request = equity::EquityRequest::new(players, board)
+        .with_simulations(request.num_simulations)
+        .with_dead_cards(dead_cards);
+    let session = equity::EquitySession::new(eq_request).map_err(|e| e.to_string())?;
+
+    let handle = NEXT_SESSION.with(|next| {
+        let handle = next.get();
+        next.set(handle.wrapping_add(1));
+        handle
+    });
+    let state = EquitySessionState {
+        session,
+        hand_descriptions,
+        precision: request.precision,
+    };
+    SESSIONS.with(|sessions| sessions.borrow_mut().insert(handle, state));
+    Ok(handle)
+}
+
+fn equity_step_impl(handle: u32, batch: u32) -> Result<EquityProgressOutput, String> {
+    SESSIONS.with(|sessions| {
+        let mut sessions = sessions.borrow_mut();
+        let state = sessions
+            .get_mut(&handle)
+            .ok_or_else(|| format!("Unknown equity session {handle}"))?;
+
+        let result = state.session.step(batch).map_err(|e| e.to_string())?;
+        let output = state.output(&result);
+        Ok(EquityProgressOutput {
+            players: output.players,
+            total_simulations: output.total_simulations,
+            remaining_simulations: state.session.remaining(),
+            done: state.session.is_finished(),
+        })
+    })
+}
+
+fn equity_finish_impl(handle: u32) -> Result<EquityResultOutput, String> {
+    let state = SESSIONS
+        .with(|sessions| sessions.borrow_mut().remove(&handle))
+        .ok_or_else(|| format!("Unknown equity session {handle}"))?;
+
+    Ok(state.output(&state.session.results()))
+}
+
+fn equity_drop_impl(handle: u32) -> bool {
+    SESSIONS.with(|sessions| sessions.borrow_mut().remove(&handle).is_some())
+}
+
+// ============================================================================
+// Range Equity Calculation
+// ============================================================================
+
+/// Calculate equity with the full range engine.
+///
+/// Every player is resolved to a specific hand, a random hand, or a range
+/// distribution, and all combinations are enumerated (or sampled for large
+/// ranges) by `calculate_equity_with_ranges`.
+///
+/// # Arguments
+/// * `request` - JsValue containing `EquityRequest` (players, board, dead_cards, num_simulations)
+///
+/// # Returns
+/// JsValue containing `RangeEquityResponse` (per-player equity plus combination counts)
+#[wasm_bindgen]
+pub fn wasm_calculate_range_equity(request: JsValue) -> Result<JsValue, JsValue> {
+    let req: EquityRequestInput = serde_wasm_bindgen::from_value(request)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse request: {e}")))?;
+
     // Use js_sys::Date for timing in WASM
     let start = js_sys::Date::now();
-    let result = equity::calculate_equity(&eq_request)
+    let mut result = calculate_range_equity_impl(&req)
+        .map_err(|e| JsValue::from_str(&e))?;
+    result.elapsed_ms = js_sys::Date::now() - start;
+
+    serde_wasm_bindgen::to_value(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {e}")))
+}
+
+fn calculate_range_equity_impl(
+    request: &EquityRequestInput,
+) -> Result<RangeEquityResultOutput, String> {
+    let board = request.resolve_board()?;
+    let dead_cards = parse_card_strings(&request.dead_cards)?;
+
+    let (range_players, hand_descriptions) = build_range_players(request, &board, &dead_cards)?;
+
+    let eq_request = RangeEquityRequest::new(range_players, board)
+        .with_simulations(request.num_simulations)
+        .with_dead_cards(dead_cards);
+
+    let result = equity::calculate_equity_with_ranges(&eq_request)
         .map_err(|e| e.to_string())?;
-    let elapsed_ms = js_sys::Date::now() - start;
 
-    // Convert to output format
-    Ok(EquityResultOutput {
+    Ok(RangeEquityResultOutput {
         players: result
             .players
             .iter()
@@ -233,11 +467,12 @@ fn calculate_equity_simple_impl(
                 equity: p.equity,
                 win_rate: p.win_rate,
                 tie_rate: p.tie_rate,
-                combos: combo_counts.get(i).copied().unwrap_or(1),
+                combos: p.combos,
             })
             .collect(),
+        total_combinations: result.total_combinations,
         total_simulations: result.total_simulations,
-        elapsed_ms,
+        elapsed_ms: result.elapsed_ms,
     })
 }
 
@@ -303,7 +538,7 @@ fn analyze_draws_impl(
             .iter()
             .map(|d| FlushDrawOutput {
                 suit: d.suit.to_char().to_string(),
-                suit_symbol: suit_symbol(d.suit),
+                suit_symbol: d.suit.to_symbol().to_string(),
                 cards_held: d.cards_held,
                 outs: d.outs.iter().map(ToString::to_string).collect(),
                 out_count: d.out_count(),
@@ -365,12 +600,59 @@ fn evaluate_hand_impl(card_strings: Vec<String>) -> Result<types::EvaluateOutput
     let rank = evaluator::evaluate_hand(&cards)
         .map_err(|e| e.to_string())?;
 
-    Ok(types::EvaluateOutput {
+    Ok(evaluate_output(&rank))
+}
+
+/// Evaluate many hands in one call.
+///
+/// # Arguments
+/// * `hands` - JsValue array of card string arrays (e.g., [["Ah", "Kh", "Qh", "Jh", "Th"], ...])
+///
+/// # Returns
+/// JsValue containing an array of `EvaluateResponse`, in input order
+#[wasm_bindgen]
+pub fn wasm_evaluate_many(hands: JsValue) -> Result<JsValue, JsValue> {
+    let hands: Vec<Vec<String>> = serde_wasm_bindgen::from_value(hands)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse hands: {e}")))?;
+
+    let result = evaluate_many_impl(&hands)
+        .map_err(|e| JsValue::from_str(&e))?;
+
+    serde_wasm_bindgen::to_value(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {e}")))
+}
+
+fn evaluate_many_impl(hands: &[Vec<String>]) -> Result<Vec<types::EvaluateOutput>, String> {
+    let parsed = hands
+        .iter()
+        .enumerate()
+        .map(|(i, card_strings)| {
+            let cards = types::parse_card_strings(card_strings)
+                .map_err(|e| format!("Hand {}: {e}", i + 1))?;
+            if cards.len() < 5 || cards.len() > 7 {
+                return Err(format!(
+                    "Hand {}: need 5-7 cards for evaluation, got {}",
+                    i + 1,
+                    cards.len()
+                ));
+            }
+            Ok(cards)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let ranks = evaluator::evaluate_many(&parsed)
+        .map_err(|e| e.to_string())?;
+
+    Ok(ranks.iter().map(evaluate_output).collect())
+}
+
+fn evaluate_output(rank: &evaluator::HandRank) -> types::EvaluateOutput {
+    types::EvaluateOutput {
         hand_type: rank.hand_type.name().to_string(),
         description: rank.describe(),
         primary_ranks: rank.primary_ranks.clone(),
         kickers: rank.kickers.clone(),
-    })
+    }
 }
 
 // ============================================================================
@@ -447,4 +729,227 @@ mod tests {
     fn test_health() {
         // Can't test JsValue in regular tests, just ensure it compiles
     }
+
+    fn player_range(range: &[&str]) -> PlayerInput {
+        PlayerInput {
+            cards: None,
+            range: Some(range.iter().map(ToString::to_string).collect()),
+            random: false,
+        }
+    }
+
+    fn player_cards(cards: &[&str]) -> PlayerInput {
+        PlayerInput {
+            cards: Some(cards.iter().map(ToString::to_string).collect()),
+            range: None,
+            random: false,
+        }
+    }
+
+    fn request(players: Vec<PlayerInput>) -> EquityRequestInput {
+        EquityRequestInput {
+            players,
+            board: Vec::new(),
+            flop: None,
+            turn: None,
+            river: None,
+            dead_cards: Vec::new(),
+            num_simulations: 500,
+            precision: None,
+        }
+    }
+
+    #[test]
+    fn test_round_to() {
+        let result = EquityResultOutput {
+            players: vec![PlayerEquityOutput {
+                index: 0,
+                hand_description: "AhKh".to_string(),
+                equity: 0.123_456,
+                win_rate: 0.987_654,
+                tie_rate: 0.055,
+                combos: 1,
+            }],
+            total_simulations: 1000,
+            elapsed_ms: 1.234_5,
+        };
+
+        // Rounding itself is covered by holdem-core's `round_decimals` test
+        let two = result.round_to(2);
+        assert!((two.players[0].equity - 0.12).abs() < 1e-12);
+        assert!((two.players[0].win_rate - 0.99).abs() < 1e-12);
+        assert!((two.players[0].tie_rate - 0.06).abs() < 1e-12);
+        assert_eq!(two.players[0].hand_description, "AhKh");
+        assert_eq!(two.total_simulations, 1000);
+
+        // The original keeps full precision
+        assert!((result.players[0].equity - 0.123_456).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_range_equity_aa_vs_kk() {
+        let req = request(vec![player_range(&["AA"]), player_range(&["KK"])]);
+        let result = calculate_range_equity_impl(&req).unwrap();
+
+        assert_eq!(result.players.len(), 2);
+        assert_eq!(result.players[0].combos, 6);
+        assert_eq!(result.players[1].combos, 6);
+        assert_eq!(result.total_combinations, 36);
+        assert!(result.players[0].equity > 0.75, "AA equity {}", result.players[0].equity);
+        assert!(result.players[0].equity < 0.90, "AA equity {}", result.players[0].equity);
+    }
+
+    #[test]
+    fn test_equity_impl_uses_full_ranges() {
+        // Each range must be enumerated, not collapsed to its first combo
+        let req = request(vec![player_range(&["AA"]), player_range(&["KK"])]);
+        let result = calculate_equity_impl(req).unwrap();
+
+        assert_eq!(result.players[0].combos, 6);
+        assert_eq!(result.players[1].combos, 6);
+        // AA vs KK is ~82% once all suit combinations are averaged
+        assert!(result.players[0].equity > 0.78, "AA equity {}", result.players[0].equity);
+        assert!(result.players[0].equity < 0.86, "AA equity {}", result.players[0].equity);
+    }
+
+    #[test]
+    fn test_equity_impl_range_combos_filtered_by_board() {
+        let mut req = request(vec![player_cards(&["Ah", "Kh"]), player_range(&["QQ"])]);
+        req.board = vec!["Qd".to_string(), "7c".to_string(), "2s".to_string()];
+        let result = calculate_equity_impl(req).unwrap();
+
+        assert_eq!(result.players[0].combos, 1);
+        assert_eq!(result.players[1].combos, 3);
+    }
+
+    #[test]
+    fn test_range_equity_mixed_players() {
+        let mut req = request(vec![player_cards(&["Ah", "Kh"]), player_range(&["QQ", "JJ"])]);
+        req.board = vec!["Qd".to_string(), "7c".to_string(), "2s".to_string()];
+        let result = calculate_range_equity_impl(&req).unwrap();
+
+        assert_eq!(result.players[0].combos, 1);
+        // QQ loses the Qd combos on this board: 3 + 6 = 9
+        assert_eq!(result.players[1].combos, 9);
+        assert_eq!(result.players[0].hand_description, "AhKh");
+    }
+
+    fn strings(cards: &[&str]) -> Vec<String> {
+        cards.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_resolve_board_streets() {
+        let mut req = request(vec![]);
+        req.flop = Some(["Ah".to_string(), "Kd".to_string(), "7c".to_string()]);
+        req.turn = Some("2s".to_string());
+        let board = req.resolve_board().unwrap();
+        assert_eq!(board, parse_card_strings(&strings(&["Ah", "Kd", "7c", "2s"])).unwrap());
+
+        req.river = Some("9h".to_string());
+        assert_eq!(req.resolve_board().unwrap().len(), 5);
+
+        // Flat board still works on its own
+        let mut flat = request(vec![]);
+        flat.board = strings(&["Ah", "Kd", "7c"]);
+        assert_eq!(flat.resolve_board().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_resolve_board_invalid_streets() {
+        let mut req = request(vec![]);
+        req.flop = Some(["Ah".to_string(), "Kd".to_string(), "7c".to_string()]);
+        req.river = Some("9h".to_string());
+        let err = req.resolve_board().unwrap_err();
+        assert!(err.contains("river"), "{err}");
+
+        let mut both = request(vec![]);
+        both.board = strings(&["Ah", "Kd", "7c"]);
+        both.turn = Some("2s".to_string());
+        assert!(both.resolve_board().is_err());
+    }
+
+    #[test]
+    fn test_range_equity_invalid_range() {
+        let req = request(vec![player_range(&["AX"]), player_range(&["KK"])]);
+        let err = calculate_range_equity_impl(&req).unwrap_err();
+        assert!(err.contains("Player 1 range error"), "{err}");
+    }
+
+    #[test]
+    fn test_range_equity_not_enough_players() {
+        let req = request(vec![player_range(&["AA"])]);
+        let err = calculate_range_equity_impl(&req).unwrap_err();
+        assert_eq!(err, "Need at least 2 players");
+    }
+
+    #[test]
+    fn test_equity_session_steps_accumulate() {
+        let req = request(vec![player_cards(&["Ah", "Ad"]), player_cards(&["Kh", "Kd"])]);
+        let handle = equity_start_impl(&req).unwrap();
+
+        let first = equity_step_impl(handle, 200).unwrap();
+        assert_eq!(first.total_simulations, 200);
+        assert_eq!(first.remaining_simulations, 300);
+        assert!(!first.done);
+        assert_eq!(first.players[0].hand_description, "AhAd");
+
+        // The last step is capped at the requested simulation count
+        let second = equity_step_impl(handle, 1000).unwrap();
+        assert_eq!(second.total_simulations, 500);
+        assert_eq!(second.remaining_simulations, 0);
+        assert!(second.done);
+
+        let result = equity_finish_impl(handle).unwrap();
+        assert_eq!(result.total_simulations, 500);
+        assert!(result.players[0].equity > 0.7, "AA equity {}", result.players[0].equity);
+
+        // Finishing releases the session
+        assert!(equity_step_impl(handle, 10).is_err());
+        assert!(equity_finish_impl(handle).is_err());
+    }
+
+    #[test]
+    fn test_equity_session_drop() {
+        let req = request(vec![player_cards(&["Ah", "Ad"]), player_cards(&["Kh", "Kd"])]);
+        let handle = equity_start_impl(&req).unwrap();
+        equity_step_impl(handle, 100).unwrap();
+
+        assert!(equity_drop_impl(handle));
+        assert!(!equity_drop_impl(handle));
+        assert!(equity_step_impl(handle, 10).is_err());
+    }
+
+    #[test]
+    fn test_equity_session_rejects_ranges() {
+        let req = request(vec![player_range(&["AA"]), player_cards(&["Kh", "Kd"])]);
+        let err = equity_start_impl(&req).unwrap_err();
+        assert!(err.contains("not supported"), "{err}");
+    }
+
+    #[test]
+    fn test_evaluate_many_matches_single_evaluation() {
+        let hands: Vec<Vec<String>> = [
+            "Ah Kh Qh Jh Th",
+            "As Ad Kc Kd 2h 3s",
+            "9c 8d 7h 6s 2c 3d 4h",
+        ]
+        .iter()
+        .map(|h| h.split(' ').map(ToString::to_string).collect())
+        .collect();
+
+        let batch = evaluate_many_impl(&hands).unwrap();
+        assert_eq!(batch.len(), 3);
+        for (hand, output) in hands.iter().zip(&batch) {
+            let single = evaluate_hand_impl(hand.clone()).unwrap();
+            assert_eq!(output.description, single.description);
+            assert_eq!(output.primary_ranks, single.primary_ranks);
+            assert_eq!(output.kickers, single.kickers);
+        }
+        assert_eq!(batch[0].hand_type, "Royal Flush");
+
+        let bad = vec![hands[0].clone(), vec!["Ah".to_string(), "Kh".to_string()]];
+        let err = evaluate_many_impl(&bad).unwrap_err();
+        assert!(err.starts_with("Hand 2:"), "{err}");
+    }
 }
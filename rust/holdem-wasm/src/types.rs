@@ -6,7 +6,8 @@
 use holdem_core::{
     canonize::CanonicalHand,
     draws::DrawType,
-    Card, Suit,
+    equity::{resolve_board, round_decimals},
+    Card,
 };
 use serde::{Deserialize, Serialize};
 
@@ -32,10 +33,49 @@ pub struct EquityRequestInput {
     pub players: Vec<PlayerInput>,
     #[serde(default)]
     pub board: Vec<String>,
+    /// Structured alternative to `board`: the three flop cards
+    #[serde(default)]
+    pub flop: Option<[String; 3]>,
+    /// Structured alternative to `board`: the turn card
+    #[serde(default)]
+    pub turn: Option<String>,
+    /// Structured alternative to `board`: the river card
+    #[serde(default)]
+    pub river: Option<String>,
     #[serde(default)]
     pub dead_cards: Vec<String>,
     #[serde(default = "default_simulations")]
     pub num_simulations: u32,
+    /// Round equity percentages in the response to this many decimals
+    #[serde(default)]
+    pub precision: Option<u32>,
+}
+
+impl EquityRequestInput {
+    /// Resolve the board from either the flat `board` list or the
+    /// `flop`/`turn`/`river` fields (not both).
+    pub fn resolve_board(&self) -> Result<Vec<Card>, String> {
+        let flop = match &self.flop {
+            Some(flop) => {
+                let parsed = parse_card_strings(flop)?;
+                Some([parsed[0], parsed[1], parsed[2]])
+            }
+            None => None,
+        };
+        let parse_one = |s: &Option<String>| -> Result<Option<Card>, String> {
+            s.as_deref()
+                .map(|c| Card::parse(c).map_err(|e| e.to_string()))
+                .transpose()
+        };
+
+        resolve_board(
+            &parse_card_strings(&self.board)?,
+            flop,
+            parse_one(&self.turn)?,
+            parse_one(&self.river)?,
+        )
+        .map_err(|e| e.to_string())
+    }
 }
 
 fn default_simulations() -> u32 {
@@ -43,15 +83,26 @@ fn default_simulations() -> u32 {
 }
 
 /// Equity result output (matches TypeScript `EquityResponse`)
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct EquityResultOutput {
     pub players: Vec<PlayerEquityOutput>,
     pub total_simulations: u64,
     pub elapsed_ms: f64,
 }
 
+impl EquityResultOutput {
+    /// Copy with every player's rates rounded to `decimals` places
+    #[must_use]
+    pub fn round_to(&self, decimals: u32) -> Self {
+        Self {
+            players: self.players.iter().map(|p| p.round_to(decimals)).collect(),
+            ..self.clone()
+        }
+    }
+}
+
 /// Per-player equity result (matches TypeScript `PlayerEquityResult`)
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PlayerEquityOutput {
     pub index: usize,
     pub hand_description: String,
@@ -61,6 +112,39 @@ pub struct PlayerEquityOutput {
     pub combos: usize,
 }
 
+impl PlayerEquityOutput {
+    /// Copy with `equity`, `win_rate` and `tie_rate` rounded to `decimals` places
+    #[must_use]
+    pub fn round_to(&self, decimals: u32) -> Self {
+        Self {
+            equity: round_decimals(self.equity, decimals),
+            win_rate: round_decimals(self.win_rate, decimals),
+            tie_rate: round_decimals(self.tie_rate, decimals),
+            ..self.clone()
+        }
+    }
+}
+
+/// Progress of a resumable equity session (matches TypeScript `EquityProgressResponse`)
+#[derive(Debug, Serialize)]
+pub struct EquityProgressOutput {
+    pub players: Vec<PlayerEquityOutput>,
+    pub total_simulations: u64,
+    /// Simulations still to run before the session is done
+    pub remaining_simulations: u32,
+    pub done: bool,
+}
+
+/// Range equity result output (matches TypeScript `RangeEquityResponse`)
+#[derive(Debug, Serialize)]
+pub struct RangeEquityResultOutput {
+    pub players: Vec<PlayerEquityOutput>,
+    /// Valid (conflict-free) combinations evaluated
+    pub total_combinations: u64,
+    pub total_simulations: u64,
+    pub elapsed_ms: f64,
+}
+
 // ============================================================================
 // Draw Analysis Types
 // ============================================================================
@@ -158,7 +242,7 @@ impl From<Card> for CardInfoOutput {
             notation: card.to_string(),
             rank: card.rank.to_char().to_string(),
             suit: card.suit.to_char().to_string(),
-            suit_symbol: suit_symbol(card.suit),
+            suit_symbol: card.suit.to_symbol().to_string(),
         }
     }
 }
@@ -200,16 +284,6 @@ pub struct HealthOutput {
 // Helper Functions
 // ============================================================================
 
-/// Get Unicode suit symbol
-pub fn suit_symbol(suit: Suit) -> String {
-    match suit {
-        Suit::Hearts => "\u{2665}".to_string(),   // ♥
-        Suit::Diamonds => "\u{2666}".to_string(), // ♦
-        Suit::Clubs => "\u{2663}".to_string(),    // ♣
-        Suit::Spades => "\u{2660}".to_string(),   // ♠
-    }
-}
-
 /// Convert draw type to string
 pub fn draw_type_string(dt: DrawType) -> String {
     match dt {
@@ -39,8 +39,12 @@ export interface PlayerHandInput {
 export interface EquityRequest {
   players: PlayerHandInput[]
   board?: string[]
+  flop?: [string, string, string]
+  turn?: string
+  river?: string
   dead_cards?: string[]
   num_simulations?: number
+  precision?: number
 }
 
 export interface PlayerEquityResult {
@@ -58,6 +62,20 @@ export interface EquityResponse {
   elapsed_ms: number
 }
 
+export interface EquityProgressResponse {
+  players: PlayerEquityResult[]
+  total_simulations: number
+  remaining_simulations: number
+  done: boolean
+}
+
+export interface RangeEquityResponse {
+  players: PlayerEquityResult[]
+  total_combinations: number
+  total_simulations: number
+  elapsed_ms: number
+}
+
 // Draws types
 export interface DrawsRequest {
   hole_cards: string[]
@@ -10,6 +10,8 @@ import type {
   ParseCardsResponse,
   EquityRequest,
   EquityResponse,
+  EquityProgressResponse,
+  RangeEquityResponse,
   DrawsRequest,
   DrawsResponse,
   HealthResponse,
@@ -22,6 +24,11 @@ interface WasmModule {
   default: () => Promise<void>
   wasm_health: () => HealthResponse
   wasm_calculate_equity: (request: EquityRequest) => EquityResponse
+  wasm_calculate_range_equity: (request: EquityRequest) => RangeEquityResponse
+  wasm_equity_start: (request: EquityRequest) => number
+  wasm_equity_step: (handle: number, batch: number) => EquityProgressResponse
+  wasm_equity_finish: (handle: number) => EquityResponse
+  wasm_equity_drop: (handle: number) => boolean
   wasm_analyze_draws: (
     hole_cards: string[],
     board: string[],
@@ -30,6 +37,7 @@ interface WasmModule {
   wasm_get_canonical_hands: () => CanonicalHandsResponse
   wasm_parse_cards: (input: string) => ParseCardsResponse
   wasm_evaluate_hand: (cards: string[]) => EvaluateResponse
+  wasm_evaluate_many: (hands: string[][]) => EvaluateResponse[]
 }
 
 // Lazy-loaded WASM module
@@ -23,6 +23,42 @@ declare module '@/wasm/holdem_wasm' {
    */
   export function wasm_calculate_equity(request: unknown): unknown
 
+  /**
+   * Calculate equity with the full range engine.
+   * @param request - EquityRequest object
+   * @returns RangeEquityResponse object
+   */
+  export function wasm_calculate_range_equity(request: unknown): unknown
+
+  /**
+   * Start a resumable equity calculation (specific and random players only).
+   * @param request - EquityRequest object
+   * @returns Session handle
+   */
+  export function wasm_equity_start(request: unknown): number
+
+  /**
+   * Run up to `batch` more simulations of a session.
+   * @param handle - Session handle from wasm_equity_start
+   * @param batch - Maximum simulations to run
+   * @returns EquityProgressResponse object
+   */
+  export function wasm_equity_step(handle: number, batch: number): unknown
+
+  /**
+   * Finish a session and release it.
+   * @param handle - Session handle from wasm_equity_start
+   * @returns EquityResponse object
+   */
+  export function wasm_equity_finish(handle: number): unknown
+
+  /**
+   * Discard a session without computing its result.
+   * @param handle - Session handle from wasm_equity_start
+   * @returns Whether the session existed
+   */
+  export function wasm_equity_drop(handle: number): boolean
+
   /**
    * Analyze draws for hole cards and board.
    * @param hole_cards - Array of card strings
@@ -48,4 +84,11 @@ declare module '@/wasm/holdem_wasm' {
    * @returns ParseCardsResponse object
    */
   export function wasm_parse_cards(input: string): unknown
+
+  /**
+   * Evaluate many hands in one call.
+   * @param hands - Array of card string arrays (5-7 cards each)
+   * @returns Array of EvaluateResponse objects, in input order
+   */
+  export function wasm_evaluate_many(hands: unknown): unknown
 }